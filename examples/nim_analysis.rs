@@ -0,0 +1,95 @@
+use std::ops::RangeInclusive;
+
+use abstract_game::{
+  complete_solver::CompleteSolver, solvers::MinimaxSolver, test_games::Nim, Game,
+};
+
+/// The solved outcome of a game of Nim.
+struct NimAnalysis {
+  sticks: u32,
+  first_player_wins: bool,
+  /// The number of sticks the first player should take, if there are any
+  /// sticks left to take.
+  best_move: Option<u32>,
+}
+
+impl NimAnalysis {
+  /// P-positions are those which are lost for the player to move.
+  fn is_p_position(&self) -> bool {
+    !self.first_player_wins
+  }
+}
+
+fn analyze(sticks: RangeInclusive<u32>) -> Vec<NimAnalysis> {
+  let mut solver = MinimaxSolver::new();
+  sticks
+    .map(|sticks| {
+      let game = Nim::new(sticks);
+      if game.finished().is_finished() {
+        // The previous player took the last stick.
+        return NimAnalysis {
+          sticks,
+          first_player_wins: false,
+          best_move: None,
+        };
+      }
+
+      let (score, best_move) = solver.best_move_determined(&game, sticks);
+      NimAnalysis {
+        sticks,
+        first_player_wins: score.into_score().is_winning(),
+        best_move,
+      }
+    })
+    .collect()
+}
+
+fn main() {
+  println!(
+    "Nim, taking at most {} sticks per turn:",
+    Nim::DEFAULT_MAX_TAKE
+  );
+  for analysis in analyze(0..=20) {
+    let outcome = if analysis.first_player_wins {
+      "first player wins"
+    } else {
+      "first player loses"
+    };
+    let best_move = analysis
+      .best_move
+      .map(|m| format!(", take {m}"))
+      .unwrap_or_default();
+    let p_position = if analysis.is_p_position() {
+      " (P-position)"
+    } else {
+      ""
+    };
+    println!(
+      "{:>3} sticks: {outcome}{best_move}{p_position}",
+      analysis.sticks
+    );
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::analyze;
+
+  #[gtest]
+  fn test_p_positions() {
+    for analysis in analyze(0..=10) {
+      expect_eq!(
+        analysis.is_p_position(),
+        analysis.sticks % 3 == 0,
+        "{} sticks",
+        analysis.sticks
+      );
+      if !analysis.is_p_position() {
+        // The winning move leaves a multiple of 3.
+        expect_that!(analysis.best_move, some(eq(analysis.sticks % 3)));
+      }
+    }
+  }
+}
@@ -9,7 +9,7 @@ fn main() {
   let player2 = HumanTermPlayer::new("Player 2".to_owned(), TicTacToePlayer);
   let game = TicTacToe::new();
 
-  let result = TermInterface::new(game, player1, player2).map(TermInterface::play);
+  let result = TermInterface::new(game, player1, player2).and_then(TermInterface::play);
   if let Err(err) = result {
     println!("{err}");
   }
@@ -1,4 +1,42 @@
-use crate::{determined_score::DeterminedScore, Game, Solver};
+use std::{error::Error, fmt::Display};
+
+use crate::{determined_score::DeterminedScore, Game, GameResult, Score, Solver};
+
+/// The depth `CompleteSolver::solve` searches to. A tie discovered to
+/// `Score::MAX_TIE_DEPTH` is a guaranteed tie, so this must be shallower to
+/// tell undecided games apart from tied ones.
+pub const SOLVE_DEPTH: u32 = if Score::MAX_WIN_DEPTH < Score::MAX_TIE_DEPTH {
+  Score::MAX_WIN_DEPTH
+} else {
+  Score::MAX_TIE_DEPTH - 1
+};
+
+/// Errors from solving a game to completion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolveError {
+  /// The game has already finished, so there's nothing to solve.
+  GameFinished,
+  /// The outcome of the game wasn't decided within the given number of moves,
+  /// which is the deepest search a `Score` can represent.
+  DepthExceeded(u32),
+}
+
+impl Error for SolveError {}
+
+/// The outcome of a solved game and the best move, as returned by
+/// `CompleteSolver::solve`.
+pub type SolveResult<M> = Result<(DeterminedScore, Option<M>), SolveError>;
+
+impl Display for SolveError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::GameFinished => write!(f, "The game has already finished"),
+      Self::DepthExceeded(depth) => {
+        write!(f, "The game is not decided within {depth} moves")
+      }
+    }
+  }
+}
 
 /// Complete solvers find the true optimal moves (e.g. highest-valued `Score`),
 /// which differs from "optimal" solvers (e.g. "never loses") in that the
@@ -14,7 +52,171 @@ pub trait CompleteSolver: Solver {
   ) -> (DeterminedScore, Option<<Self::Game as Game>::Move>) {
     let (score, m) = Solver::best_move(self, game, depth);
     let score = DeterminedScore::from_score(score)
-      .expect(&format!("Expected a determined score, got {score}"));
+      .unwrap_or_else(|| panic!("Expected a determined score, got {score}"));
     (score, m)
   }
+
+  /// Solves `game` with no practical depth limit, returning its proven
+  /// outcome and the best move. Fails if the outcome isn't decided within
+  /// `SOLVE_DEPTH` moves, since deeper results can't be represented.
+  fn solve(&mut self, game: &Self::Game) -> SolveResult<<Self::Game as Game>::Move> {
+    if game.finished().is_finished() {
+      return Err(SolveError::GameFinished);
+    }
+
+    let (score, m) = self.best_move(game, SOLVE_DEPTH);
+    match DeterminedScore::from_score(score) {
+      Some(score) if score.into_score().is_solved() => Ok((score, m)),
+      _ => Err(SolveError::DepthExceeded(SOLVE_DEPTH)),
+    }
+  }
+
+  /// Returns the moves from `game` which force a win in exactly `n` moves,
+  /// counting both players' moves as in `Score`, with the positions after
+  /// each move searched `depth - 1` moves deep. Since only the current
+  /// player's moves can win, `n` must be odd for any move to qualify, unless
+  /// the game has extra turns.
+  fn children_mate_in(
+    &mut self,
+    game: &Self::Game,
+    n: u32,
+    depth: u32,
+  ) -> Vec<<Self::Game as Game>::Move> {
+    debug_assert!(depth > 0);
+    game
+      .each_move()
+      .filter(|&m| {
+        let child = game.with_move(m);
+        match child.finished() {
+          GameResult::Win(player) => player == game.current_player() && n == 1,
+          GameResult::NotFinished if n > 1 => {
+            // After an extra turn, the current player must still be winning.
+            let expected = if child.current_player() == game.current_player() {
+              DeterminedScore::win(n - 1)
+            } else {
+              DeterminedScore::lose(n - 1)
+            };
+            self.best_move_determined(&child, depth - 1).0 == expected
+          }
+          GameResult::Tie | GameResult::NotFinished => false,
+        }
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    complete_solver::{CompleteSolver, SolveError, SOLVE_DEPTH},
+    determined_score::DeterminedScore,
+    solvers::MinimaxSolver,
+    test_games::{ConnectMove, ConnectN, Nim, TicTacToe},
+    test_util::NegamaxSolver,
+    Game, GameMoveIterator, GamePlayer, GameResult, GameSerialize,
+  };
+
+  /// A game which never ends, with a single move each turn.
+  #[derive(Clone, Debug)]
+  struct Endless {
+    moves_made: u32,
+  }
+
+  struct EndlessMoveGen {
+    done: bool,
+  }
+
+  impl GameMoveIterator for EndlessMoveGen {
+    type Game = Endless;
+
+    fn next(&mut self, _game: &Endless) -> Option<u32> {
+      (!std::mem::replace(&mut self.done, true)).then_some(0)
+    }
+  }
+
+  impl Game for Endless {
+    type Move = u32;
+    type MoveGenerator = EndlessMoveGen;
+
+    fn move_generator(&self) -> EndlessMoveGen {
+      EndlessMoveGen { done: false }
+    }
+
+    fn make_move(&mut self, _m: u32) {
+      self.moves_made += 1;
+    }
+
+    fn current_player(&self) -> GamePlayer {
+      if self.moves_made.is_multiple_of(2) {
+        GamePlayer::Player1
+      } else {
+        GamePlayer::Player2
+      }
+    }
+
+    fn finished(&self) -> GameResult {
+      GameResult::NotFinished
+    }
+  }
+
+  #[gtest]
+  fn test_solve() {
+    let mut solver = NegamaxSolver::new();
+    expect_that!(
+      solver.solve(&Nim::new(10)),
+      ok(eq((DeterminedScore::win(7), Some(1))))
+    );
+    expect_that!(
+      solver.solve(&Nim::new(9)),
+      ok(eq((DeterminedScore::lose(6), Some(1))))
+    );
+    expect_that!(
+      MinimaxSolver::new().solve(&TicTacToe::new()),
+      ok((eq(DeterminedScore::guaranteed_tie()), some(anything())))
+    );
+  }
+
+  #[gtest]
+  fn test_solve_errors() {
+    expect_that!(
+      NegamaxSolver::new().solve(&Nim::new(0)),
+      err(eq(SolveError::GameFinished))
+    );
+    expect_that!(
+      MinimaxSolver::new().solve(&Endless { moves_made: 0 }),
+      err(eq(SolveError::DepthExceeded(SOLVE_DEPTH)))
+    );
+  }
+
+  #[gtest]
+  fn test_children_mate_in() {
+    // Extending X's piece to either side threatens to complete the bottom row
+    // at both ends, which O can't block at once. Extending it by a gap only
+    // makes one threat.
+    let game = ConnectN::from_notation("5x3:3:...../..O../..X..").unwrap();
+    let mut solver = NegamaxSolver::new();
+    expect_that!(solver.children_mate_in(&game, 1, 5), is_empty());
+    expect_that!(
+      solver.children_mate_in(&game, 3, 5),
+      unordered_elements_are![eq(&ConnectMove { col: 1 }), eq(&ConnectMove { col: 3 })]
+    );
+    // Only the current player's moves can win.
+    expect_that!(solver.children_mate_in(&game, 2, 5), is_empty());
+  }
+
+  #[gtest]
+  fn test_children_mate_in_zero() {
+    // No move wins in 0 moves, whether or not it finishes the game.
+    let game = ConnectN::from_notation("5x3:3:...../..O../..X..").unwrap();
+    expect_that!(
+      NegamaxSolver::new().children_mate_in(&game, 0, 5),
+      is_empty()
+    );
+    expect_that!(
+      NegamaxSolver::new().children_mate_in(&Nim::new(1), 0, 5),
+      is_empty()
+    );
+  }
 }
@@ -1,4 +1,7 @@
-use std::fmt::{Debug, Display};
+use std::{
+  cmp::Ordering,
+  fmt::{Debug, Display},
+};
 
 use crate::{Score, ScoreValue};
 
@@ -55,6 +58,34 @@ impl DeterminedScore {
     }
   }
 
+  /// The depth a tie is discovered to, with guaranteed ties being discovered
+  /// to every depth.
+  fn tie_depth(&self) -> u32 {
+    debug_assert!(self.value.is_tied());
+    if self.moves_to_win == 0 {
+      u32::MAX
+    } else {
+      self.moves_to_win
+    }
+  }
+
+  /// Converts this score into the most informative packed `Score` with the
+  /// same outcome, i.e. wins/losses are optimal and ties are discovered to
+  /// exactly their depth.
+  pub fn into_score(self) -> Score {
+    match self.value {
+      ScoreValue::CurrentPlayerWins => Score::optimal_win(self.moves_to_win),
+      ScoreValue::OtherPlayerWins => Score::optimal_lose(self.moves_to_win),
+      ScoreValue::Tie => {
+        if self.moves_to_win == 0 {
+          Score::guaranteed_tie()
+        } else {
+          Score::tie(self.moves_to_win)
+        }
+      }
+    }
+  }
+
   pub fn from_score(score: Score) -> Option<Self> {
     if score == Score::NO_INFO {
       None
@@ -74,6 +105,26 @@ impl DeterminedScore {
   }
 }
 
+impl PartialOrd for DeterminedScore {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+/// Orders scores by how good they are for the current player, matching
+/// `Score::better`: wins rank above ties, which rank above losses. Faster wins
+/// beat slower wins, slower losses beat faster losses, and deeper ties beat
+/// shallower ties.
+impl Ord for DeterminedScore {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.value.cmp(&other.value).then_with(|| match self.value {
+      ScoreValue::CurrentPlayerWins => other.moves_to_win.cmp(&self.moves_to_win),
+      ScoreValue::OtherPlayerWins => self.moves_to_win.cmp(&other.moves_to_win),
+      ScoreValue::Tie => self.tie_depth().cmp(&other.tie_depth()),
+    })
+  }
+}
+
 impl Debug for DeterminedScore {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(f, "{self}")
@@ -98,10 +149,44 @@ impl Display for DeterminedScore {
 
 #[cfg(test)]
 mod tests {
+  use std::cmp::Ordering;
+
   use googletest::{gtest, prelude::*};
 
   use crate::{determined_score::DeterminedScore, Score};
 
+  #[gtest]
+  fn test_into_score() {
+    expect_eq!(DeterminedScore::win(3).into_score(), Score::optimal_win(3));
+    expect_eq!(
+      DeterminedScore::lose(3).into_score(),
+      Score::optimal_lose(3)
+    );
+    expect_eq!(DeterminedScore::tie(3).into_score(), Score::tie(3));
+    expect_eq!(
+      DeterminedScore::guaranteed_tie().into_score(),
+      Score::guaranteed_tie()
+    );
+  }
+
+  #[gtest]
+  fn test_into_score_round_trip() {
+    for ds in [
+      DeterminedScore::win(1),
+      DeterminedScore::win(7),
+      DeterminedScore::win(Score::MAX_WIN_DEPTH),
+      DeterminedScore::lose(1),
+      DeterminedScore::lose(7),
+      DeterminedScore::lose(Score::MAX_WIN_DEPTH),
+      DeterminedScore::tie(1),
+      DeterminedScore::tie(7),
+      DeterminedScore::tie(Score::MAX_TIE_DEPTH - 1),
+      DeterminedScore::guaranteed_tie(),
+    ] {
+      expect_that!(DeterminedScore::from_score(ds.into_score()), some(eq(ds)));
+    }
+  }
+
   #[gtest]
   fn test_truncated() {
     expect_eq!(
@@ -169,4 +254,60 @@ mod tests {
     );
     expect_that!(DeterminedScore::from_score(Score::lose(6)), none());
   }
+
+  #[gtest]
+  fn test_ord() {
+    // Winning is better than losing.
+    expect_gt!(DeterminedScore::win(1), DeterminedScore::lose(1));
+    expect_gt!(DeterminedScore::win(100), DeterminedScore::lose(1));
+
+    // Winning is better than tying.
+    expect_gt!(DeterminedScore::win(1), DeterminedScore::tie(1));
+    expect_gt!(DeterminedScore::win(1), DeterminedScore::guaranteed_tie());
+
+    // Winning in fewer moves is better than more moves.
+    expect_gt!(DeterminedScore::win(5), DeterminedScore::win(10));
+
+    // Tying is better than losing.
+    expect_gt!(DeterminedScore::tie(1), DeterminedScore::lose(1));
+    expect_gt!(DeterminedScore::tie(10), DeterminedScore::lose(1));
+    expect_gt!(DeterminedScore::tie(1), DeterminedScore::lose(10));
+    expect_gt!(DeterminedScore::guaranteed_tie(), DeterminedScore::lose(10));
+
+    // Given two ties, prefer the one with a deeper discovered depth.
+    expect_gt!(DeterminedScore::tie(10), DeterminedScore::tie(5));
+    expect_gt!(DeterminedScore::guaranteed_tie(), DeterminedScore::tie(10));
+
+    // Given two losing scores, prefer the deeper one.
+    expect_gt!(DeterminedScore::lose(10), DeterminedScore::lose(5));
+
+    expect_eq!(
+      DeterminedScore::win(3).cmp(&DeterminedScore::win(3)),
+      Ordering::Equal
+    );
+  }
+
+  #[gtest]
+  fn test_sort() {
+    let mut scores = vec![
+      DeterminedScore::lose(3),
+      DeterminedScore::win(5),
+      DeterminedScore::tie(4),
+      DeterminedScore::lose(7),
+      DeterminedScore::guaranteed_tie(),
+      DeterminedScore::win(1),
+    ];
+    scores.sort_by(|a, b| b.cmp(a));
+    expect_eq!(
+      scores,
+      vec![
+        DeterminedScore::win(1),
+        DeterminedScore::win(5),
+        DeterminedScore::guaranteed_tie(),
+        DeterminedScore::tie(4),
+        DeterminedScore::lose(7),
+        DeterminedScore::lose(3),
+      ]
+    );
+  }
 }
@@ -3,6 +3,19 @@ use std::{error::Error, fmt::Display};
 #[derive(Debug)]
 pub enum GameInterfaceError {
   Quit,
+  /// The user asked to take back the last move.
+  Undo,
+  /// The user asked to make the last undone move again.
+  Redo,
+  /// The user asked to save the game to the given path.
+  Save(String),
+  /// The user asked for a suggested move.
+  Hint,
+  /// No input arrived within the time allowed.
+  Timeout,
+  /// The reader had no usable input, e.g. a mouse click outside of the board,
+  /// but another reader may.
+  NoInput,
   MalformedMove(String),
   IoError(String),
   InternalError(String),
@@ -14,6 +27,12 @@ impl Display for GameInterfaceError {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     match self {
       Self::Quit => write!(f, "The user quit"),
+      Self::Undo => write!(f, "The user asked to undo the last move"),
+      Self::Redo => write!(f, "The user asked to redo the last undone move"),
+      Self::Save(path) => write!(f, "The user asked to save the game to {path}"),
+      Self::Hint => write!(f, "The user asked for a hint"),
+      Self::Timeout => write!(f, "Timed out waiting for input"),
+      Self::NoInput => write!(f, "No usable input"),
       Self::MalformedMove(error) => write!(f, "Malformed move: {error}"),
       Self::IoError(error) => write!(f, "IO error: {error}"),
       Self::InternalError(error) => write!(f, "Internal error: {error}"),
@@ -1,4 +1,12 @@
-use std::fmt::Debug;
+use std::{
+  collections::HashMap,
+  fmt::{Debug, Write},
+  iter,
+};
+
+use rand::Rng;
+
+use crate::ScoreValue;
 
 /// Arbitrary labels to assign to each of the two players of a game. `Player1`
 /// does not need to be the first player.
@@ -23,6 +31,24 @@ impl GamePlayer {
       Self::Player2 => Self::Player1,
     }
   }
+
+  /// The index of the player in per-player arrays, 0 for `Player1` and 1 for
+  /// `Player2`.
+  pub fn index(&self) -> usize {
+    match self {
+      Self::Player1 => 0,
+      Self::Player2 => 1,
+    }
+  }
+
+  /// The inverse of `index`, returning `None` for indices other than 0 and 1.
+  pub fn from_index(index: usize) -> Option<Self> {
+    match index {
+      0 => Some(Self::Player1),
+      1 => Some(Self::Player2),
+      _ => None,
+    }
+  }
 }
 
 pub trait GameMoveIterator: Sized {
@@ -33,6 +59,36 @@ pub trait GameMoveIterator: Sized {
   fn to_iter(self, game: &Self::Game) -> GameIterator<'_, Self, Self::Game> {
     GameIterator { game, game_iter: self }
   }
+
+  /// Skips the moves for which `pred` returns false.
+  fn filter_moves<P>(self, pred: P) -> FilterMoves<Self, P>
+  where
+    P: FnMut(&Self::Game, <Self::Game as Game>::Move) -> bool,
+  {
+    FilterMoves { game_iter: self, pred }
+  }
+}
+
+pub struct FilterMoves<I, P> {
+  game_iter: I,
+  pred: P,
+}
+
+impl<I, P> GameMoveIterator for FilterMoves<I, P>
+where
+  I: GameMoveIterator,
+  P: FnMut(&I::Game, <I::Game as Game>::Move) -> bool,
+{
+  type Game = I::Game;
+
+  fn next(&mut self, game: &I::Game) -> Option<<I::Game as Game>::Move> {
+    while let Some(m) = self.game_iter.next(game) {
+      if (self.pred)(game, m) {
+        return Some(m);
+      }
+    }
+    None
+  }
 }
 
 pub struct GameIterator<'a, I, G> {
@@ -63,12 +119,59 @@ impl GameResult {
   pub fn is_finished(&self) -> bool {
     !matches!(self, Self::NotFinished)
   }
+
+  pub fn is_tie(&self) -> bool {
+    matches!(self, Self::Tie)
+  }
+
+  /// The player who won, if the game is finished and wasn't a tie.
+  pub fn winner(&self) -> Option<GamePlayer> {
+    match self {
+      Self::Win(player) => Some(*player),
+      Self::NotFinished | Self::Tie => None,
+    }
+  }
+
+  /// The player who lost, if the game is finished and wasn't a tie.
+  pub fn loser(&self) -> Option<GamePlayer> {
+    self.winner().map(|player| player.opposite())
+  }
+}
+
+/// A 128-bit FNV-1a hasher, used to fingerprint game states. This is stable
+/// across runs and platforms, unlike `std`'s `DefaultHasher`.
+struct Fnv1a128 {
+  hash: u128,
+}
+
+impl Fnv1a128 {
+  const OFFSET_BASIS: u128 = 0x6c62272e_07bb0142_62b82175_6295c58d;
+  const PRIME: u128 = 0x00000000_01000000_00000000_0000013b;
+
+  fn new() -> Self {
+    Self { hash: Self::OFFSET_BASIS }
+  }
+}
+
+impl Write for Fnv1a128 {
+  fn write_str(&mut self, s: &str) -> std::fmt::Result {
+    for byte in s.bytes() {
+      self.hash = (self.hash ^ byte as u128).wrapping_mul(Self::PRIME);
+    }
+    Ok(())
+  }
 }
 
 pub trait Game: Clone + Debug + Sized {
   type Move: Copy + Debug + Eq;
   type MoveGenerator: GameMoveIterator<Game = Self>;
 
+  /// An upper bound on the number of legal moves from any position, for
+  /// sizing stack-allocated move buffers. Games whose number of moves has no
+  /// fixed bound (e.g. boards with a size chosen at runtime) leave this as
+  /// `usize::MAX`.
+  const MAX_MOVES: usize = usize::MAX;
+
   fn move_generator(&self) -> Self::MoveGenerator;
 
   /// Returns an iterator over the moves that can be made from this position.
@@ -78,6 +181,14 @@ pub trait Game: Clone + Debug + Sized {
 
   fn make_move(&mut self, m: Self::Move);
 
+  /// Resolves any pending random event (e.g. a die roll that happens before
+  /// the next player moves) using `rng`. This is called before generating moves
+  /// during interactive and random play, and is a no-op by default.
+  ///
+  /// Only pre-resolved chance is supported: solvers never call this, and
+  /// evaluating chance nodes by expectation is out of scope.
+  fn resolve_chance(&mut self, _rng: &mut impl Rng) {}
+
   /// Returns the which player is to make the next move.
   fn current_player(&self) -> GamePlayer;
 
@@ -91,11 +202,714 @@ pub trait Game: Clone + Debug + Sized {
     copy
   }
 
+  /// Returns an iterator over the moves that can be made from this position,
+  /// paired with the position after making them. By default, each position is
+  /// built with `with_move`.
+  fn successors(&self) -> impl Iterator<Item = (Self::Move, Self)> {
+    self.each_move().map(|m| (m, self.with_move(m)))
+  }
+
+  /// Returns the canonical encoding of `m`, for games where several encodings
+  /// of a move make the same action. Moves making the same action must
+  /// normalize to equal moves. By default, every move is its own canonical
+  /// encoding.
+  fn normalize_move(&self, m: Self::Move) -> Self::Move {
+    m
+  }
+
+  /// A readable description of `m` as played from this position, e.g. for
+  /// move logs. By default, this is the `Debug` representation of the move.
+  fn format_move(&self, m: Self::Move) -> String {
+    format!("{m:?}")
+  }
+
+  /// Returns true if `m`, under any encoding, is a legal move from this
+  /// position. This scans every move, so games which can check a move
+  /// directly should override it.
+  fn is_legal_move(&self, m: Self::Move) -> bool {
+    let m = self.normalize_move(m);
+    self
+      .each_move()
+      .any(|legal| self.normalize_move(legal) == m)
+  }
+
+  /// Returns true if this position can be reached in a real game, e.g. the
+  /// players have made a consistent number of moves and no pieces are in
+  /// impossible places. Positions built with `make_move` are always legal, so
+  /// this is only needed to validate positions built by other means, like
+  /// `GameSerialize::from_notation`.
+  fn is_legal_state(&self) -> bool {
+    true
+  }
+
+  /// Returns true if the game has finished. This is equivalent to
+  /// `finished().is_finished()`, but games where finding the winner is
+  /// expensive can override it with a cheaper check.
+  fn is_terminal(&self) -> bool {
+    self.finished().is_finished()
+  }
+
+  /// Returns the outcome of the finished game from `player`'s perspective:
+  /// `CurrentPlayerWins` if `player` won, `OtherPlayerWins` if they lost, or
+  /// `Tie`.
+  ///
+  /// Panics if the game hasn't finished.
+  fn outcome_for(&self, player: GamePlayer) -> ScoreValue {
+    match self.finished() {
+      GameResult::Win(winner) if winner == player => ScoreValue::CurrentPlayerWins,
+      GameResult::Win(_) => ScoreValue::OtherPlayerWins,
+      GameResult::Tie => ScoreValue::Tie,
+      GameResult::NotFinished => panic!("Can't take the outcome of an unfinished game:\n{self:?}"),
+    }
+  }
+
+  /// Returns the result of the game after making move `m`.
+  fn result_after(&self, m: Self::Move) -> GameResult {
+    self.with_move(m).finished()
+  }
+
+  /// A wide hash of this position, for tracking visited positions without
+  /// storing them, e.g. in a `HashSet<u128>` or a Bloom filter. Equal
+  /// positions always have equal fingerprints, and fingerprints are stable
+  /// across runs.
+  ///
+  /// By default, this hashes the `Debug` representation of the game, which
+  /// must then uniquely describe the position. Distinct positions collide with
+  /// probability around `2^-128` per pair, which is negligible next to the
+  /// false positive rate of any practically sized Bloom filter.
+  fn fingerprint(&self) -> u128 {
+    let mut hasher = Fnv1a128::new();
+    write!(hasher, "{self:?}").expect("Writing to a hasher can't fail");
+    hasher.hash
+  }
+
+  /// A 64-bit key for this position, for use in transposition tables. Equal
+  /// positions must have equal keys, and distinct positions should rarely
+  /// collide.
+  ///
+  /// By default, this truncates `fingerprint`, which formats the whole game
+  /// and is slow. Games used with transposition tables should override it,
+  /// e.g. with Zobrist hashing.
+  fn position_key(&self) -> u64 {
+    self.fingerprint() as u64
+  }
+
+  /// Returns an iterator over the positions equivalent to this one under the
+  /// game's symmetries (e.g. rotations and reflections of the board),
+  /// including this position itself. By default, games have no symmetries.
+  fn symmetries(&self) -> impl Iterator<Item = Self> {
+    iter::once(self.clone())
+  }
+
+  /// Returns a representative of this position's equivalence class under
+  /// `symmetries`, so that equivalent positions have the same canonical form.
+  ///
+  /// Solvers which key transposition tables by the canonical form must map
+  /// moves found in the canonical position back through the symmetry relating
+  /// it to the original position before playing them.
+  fn canonical_form(&self) -> Self {
+    self
+      .symmetries()
+      .min_by_key(Self::fingerprint)
+      .unwrap_or_else(|| self.clone())
+  }
+
+  /// Counts the leaves of the game tree rooted at this position, cut off at
+  /// `depth` moves: each sequence of `depth` moves counts once, as does each
+  /// shorter sequence which finishes the game. Interior positions aren't
+  /// counted. Comparing these counts against known values checks that a move
+  /// generator is neither missing nor inventing moves.
+  fn perft(&self, depth: u32) -> u64 {
+    if depth == 0 || self.is_terminal() {
+      return 1;
+    }
+    self
+      .successors()
+      .map(|(_, next)| next.perft(depth - 1))
+      .sum()
+  }
+
+  /// Like `perft`, but counts the distinct leaves after collapsing positions
+  /// with equal `canonical_form`, which measures how many positions a search
+  /// to `depth` really has to consider. Positions are told apart by the
+  /// `position_key` of their canonical form, so colliding keys undercount.
+  fn unique_positions(&self, depth: u32) -> usize {
+    let canonical = self.canonical_form();
+    let mut frontier = HashMap::from([(canonical.position_key(), canonical)]);
+    for _ in 0..depth {
+      let mut next_frontier = HashMap::new();
+      for (key, game) in frontier {
+        if game.is_terminal() {
+          next_frontier.insert(key, game);
+          continue;
+        }
+        for (_, next) in game.successors() {
+          let next = next.canonical_form();
+          next_frontier.insert(next.position_key(), next);
+        }
+      }
+      frontier = next_frontier;
+    }
+    frontier.len()
+  }
+
   /// Checks each possible move of this game, and returns any move that is an
   /// immediate win for the current player, or `None` if no such move exists.
   fn search_immediate_win(&self) -> Option<Self::Move> {
     self
       .each_move()
-      .find(|&m| self.with_move(m).finished() == GameResult::Win(self.current_player()))
+      .find(|&m| self.result_after(m) == GameResult::Win(self.current_player()))
+  }
+
+  /// Returns an iterator over the moves which finish the game, paired with the
+  /// result of the game after making them. Unlike `search_immediate_win`, this
+  /// includes moves which lose or tie the game.
+  fn terminal_moves(&self) -> impl Iterator<Item = (Self::Move, GameResult)> {
+    self
+      .each_move()
+      .map(|m| (m, self.result_after(m)))
+      .filter(|(_, result)| result.is_finished())
+  }
+}
+
+/// Games which can undo moves in place, letting solvers explore child states
+/// with `make_move`/`undo_move` instead of cloning the game with `with_move`.
+pub trait UndoableGame: Game {
+  /// Reverts `m`, which must be the last move made.
+  fn undo_move(&mut self, m: Self::Move);
+}
+
+/// Games which can be written to and read back from a compact text notation.
+pub trait GameSerialize: Game {
+  /// Returns the notation for this position, which `from_notation` parses
+  /// back into an equal game.
+  fn to_notation(&self) -> String;
+
+  /// Parses a position from its notation, failing on malformed notation or
+  /// positions which aren't `is_legal_state`.
+  fn from_notation(notation: &str) -> Result<Self, String>;
+
+  /// Returns a Rust expression which reconstructs this exact position, e.g.
+  /// `ConnectN::from_notation("...").unwrap()`, for pasting positions from bug
+  /// reports into tests.
+  fn repro_code(&self) -> String {
+    format!(
+      "{}::from_notation({:?}).unwrap()",
+      short_type_name(std::any::type_name::<Self>()),
+      self.to_notation()
+    )
+  }
+}
+
+/// Games played by placing pieces on the cells of a grid, whose positions can
+/// be set up directly, e.g. for building puzzles and test positions.
+pub trait GridSetup: Game {
+  /// Returns the piece on each cell of the board, indexed by `[y][x]`.
+  fn as_grid(&self) -> Vec<Vec<Option<GamePlayer>>>;
+
+  /// Places a piece of `piece`'s player on the cell at `pos`, or clears the
+  /// cell if `piece` is `None`. The resulting position may not be
+  /// `is_legal_state`.
+  fn set_cell(&mut self, pos: (u32, u32), piece: Option<GamePlayer>);
+
+  /// Makes `player` the player to move.
+  fn set_current_player(&mut self, player: GamePlayer);
+}
+
+/// Strips the module paths from a type name returned by
+/// `std::any::type_name`, including those of any generic parameters.
+fn short_type_name(type_name: &str) -> String {
+  let mut short_name = String::new();
+  let mut segment = String::new();
+  let mut chars = type_name.chars().peekable();
+  while let Some(c) = chars.next() {
+    if c == ':' && chars.peek() == Some(&':') {
+      chars.next();
+      segment.clear();
+    } else if c.is_alphanumeric() || c == '_' {
+      segment.push(c);
+    } else {
+      short_name.push_str(&segment);
+      short_name.push(c);
+      segment.clear();
+    }
+  }
+  short_name + &segment
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashSet;
+
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+  use rand::{rngs::StdRng, Rng, SeedableRng};
+
+  use crate::{
+    game::short_type_name,
+    test_games::{ConnectN, Kalah, Nim, Othello, TTTMove, TicTacToe},
+    test_util::{
+      deterministic_random_playout, generate_deterministic_random_walks,
+      make_deterministic_random_move,
+    },
+    Game, GameMoveIterator, GamePlayer, GameResult, MoveLimited, ScoreValue,
+  };
+
+  /// A game of Nim where the maximum number of sticks that can be taken each
+  /// turn is decided by a die roll before the player moves.
+  #[derive(Clone, Debug)]
+  struct DiceNim {
+    sticks: u32,
+    max_take: Option<u32>,
+    player1: bool,
+  }
+
+  struct DiceNimMoveIter {
+    sticks: u32,
+  }
+
+  impl GameMoveIterator for DiceNimMoveIter {
+    type Game = DiceNim;
+
+    fn next(&mut self, game: &DiceNim) -> Option<u32> {
+      let max_take = game.max_take.unwrap_or(0).min(game.sticks);
+      if self.sticks >= max_take {
+        None
+      } else {
+        self.sticks += 1;
+        Some(self.sticks)
+      }
+    }
+  }
+
+  impl Game for DiceNim {
+    type Move = u32;
+    type MoveGenerator = DiceNimMoveIter;
+
+    fn move_generator(&self) -> DiceNimMoveIter {
+      DiceNimMoveIter { sticks: 0 }
+    }
+
+    fn make_move(&mut self, sticks: u32) {
+      self.sticks -= sticks;
+      self.max_take = None;
+      self.player1 = !self.player1;
+    }
+
+    fn resolve_chance(&mut self, rng: &mut impl Rng) {
+      if self.max_take.is_none() {
+        self.max_take = Some(rng.random_range(1..=3));
+      }
+    }
+
+    fn current_player(&self) -> GamePlayer {
+      if self.player1 {
+        GamePlayer::Player1
+      } else {
+        GamePlayer::Player2
+      }
+    }
+
+    fn finished(&self) -> GameResult {
+      if self.sticks == 0 {
+        GameResult::Win(self.current_player().opposite())
+      } else {
+        GameResult::NotFinished
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_resolve_chance_constrains_moves() {
+    let mut rng = StdRng::seed_from_u64(1256);
+    let mut game = DiceNim {
+      sticks: 10,
+      max_take: None,
+      player1: true,
+    };
+    expect_that!(game.each_move().collect_vec(), is_empty());
+
+    game.resolve_chance(&mut rng);
+    let max_take = game.max_take.unwrap();
+    expect_eq!(game.each_move().collect_vec(), (1..=max_take).collect_vec());
+
+    // Resolving again keeps the pending roll.
+    game.resolve_chance(&mut rng);
+    expect_that!(game.max_take, some(eq(max_take)));
+  }
+
+  #[gtest]
+  fn test_random_moves_resolve_chance() {
+    let mut rng = StdRng::seed_from_u64(1256);
+    let mut game = DiceNim {
+      sticks: 30,
+      max_take: None,
+      player1: true,
+    };
+    while !game.finished().is_finished() {
+      let before = game.sticks;
+      let m = make_deterministic_random_move(&mut game, &mut rng);
+      expect_that!(m, some(le(3)));
+      expect_eq!(game.sticks, before - m.unwrap());
+    }
+  }
+
+  #[gtest]
+  fn test_terminal_moves() {
+    // Taking 1 stick leaves 1 for the opponent, so only taking both ends the
+    // game.
+    expect_that!(
+      Nim::new(2).terminal_moves().collect_vec(),
+      unordered_elements_are![eq(&(2, GameResult::Win(GamePlayer::Player1)))]
+    );
+
+    let mut game = Nim::new(3);
+    expect_that!(game.terminal_moves().collect_vec(), is_empty());
+    game.make_move(2);
+    expect_that!(
+      game.terminal_moves().collect_vec(),
+      unordered_elements_are![eq(&(1, GameResult::Win(GamePlayer::Player2)))]
+    );
+  }
+
+  /// Checks that no position along random games from `initial_state` has more
+  /// than `G::MAX_MOVES` legal moves.
+  fn check_max_moves<G: Game>(initial_state: &G, rng: &mut StdRng)
+  where
+    G::Move: Ord,
+  {
+    for walk in generate_deterministic_random_walks(initial_state, 20, rng).unwrap() {
+      let mut game = initial_state.clone();
+      for m in walk {
+        expect_le!(game.each_move().count(), G::MAX_MOVES, "{game:?}");
+        game.make_move(m);
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_max_moves() {
+    let mut rng = StdRng::seed_from_u64(1289);
+    check_max_moves(&TicTacToe::new(), &mut rng);
+    check_max_moves(&ConnectN::new(7, 6, 4), &mut rng);
+    check_max_moves(&ConnectN::new(ConnectN::MAX_WIDTH, 4, 4), &mut rng);
+    check_max_moves(&Othello::new(), &mut rng);
+    check_max_moves(&Kalah::new(), &mut rng);
+
+    expect_eq!(
+      TicTacToe::with_size(TicTacToe::MAX_SIZE, 3)
+        .each_move()
+        .count(),
+      TicTacToe::MAX_MOVES
+    );
+    expect_eq!(MoveLimited::<Kalah>::MAX_MOVES, Kalah::MAX_MOVES);
+  }
+
+  #[gtest]
+  fn test_successors() {
+    let mut rng = StdRng::seed_from_u64(1286);
+    for _ in 0..20 {
+      let mut game = ConnectN::new(5, 4, 3);
+      let num_moves = rng.random_range(0..8);
+      deterministic_random_playout(&mut game, num_moves, &mut rng);
+
+      let successors = game.successors().collect_vec();
+      expect_eq!(
+        successors.iter().map(|(m, _)| *m).collect_vec(),
+        game.each_move().collect_vec()
+      );
+      for (m, child) in successors {
+        expect_eq!(child, game.with_move(m), "{m:?} from {game:?}");
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_fingerprint_stable() {
+    let mut game1 = TicTacToe::new();
+    game1.make_move(TTTMove::new((0, 0)));
+    game1.make_move(TTTMove::new((1, 1)));
+    game1.make_move(TTTMove::new((2, 2)));
+
+    let mut game2 = TicTacToe::new();
+    game2.make_move(TTTMove::new((2, 2)));
+    game2.make_move(TTTMove::new((1, 1)));
+    game2.make_move(TTTMove::new((0, 0)));
+
+    expect_eq!(game1.fingerprint(), game1.fingerprint());
+    expect_eq!(game1.fingerprint(), game2.fingerprint());
+    expect_eq!(game1.fingerprint(), game1.clone().fingerprint());
+  }
+
+  #[gtest]
+  fn test_fingerprint_distinct() {
+    let mut rng = StdRng::seed_from_u64(1266);
+    let states = (0..500)
+      .map(|_| {
+        let mut game = TicTacToe::new();
+        let num_moves = rng.random_range(0..9);
+        deterministic_random_playout(&mut game, num_moves, &mut rng);
+        game
+      })
+      .collect::<HashSet<_>>();
+    let fingerprints = states.iter().map(Game::fingerprint).collect::<HashSet<_>>();
+
+    expect_gt!(states.len(), 100);
+    expect_eq!(fingerprints.len(), states.len());
+  }
+
+  #[gtest]
+  fn test_short_type_name() {
+    expect_eq!(
+      short_type_name(std::any::type_name::<Nim>()),
+      "Nim".to_owned()
+    );
+    expect_eq!(
+      short_type_name(std::any::type_name::<MoveLimited<Nim>>()),
+      "MoveLimited<Nim>".to_owned()
+    );
+    expect_eq!(short_type_name("u32"), "u32".to_owned());
+  }
+
+  /// A token on a ring of `RING_SIZE` cells, which players take turns moving
+  /// clockwise. A move of `n` steps is the same as a move of `n + RING_SIZE`.
+  /// The player who lands the token on cell 0 wins.
+  #[derive(Clone, Debug)]
+  struct Ring {
+    cell: u32,
+    player1: bool,
+  }
+
+  impl Ring {
+    const RING_SIZE: u32 = 5;
+  }
+
+  struct RingMoveIter {
+    steps: u32,
+  }
+
+  impl GameMoveIterator for RingMoveIter {
+    type Game = Ring;
+
+    fn next(&mut self, _game: &Ring) -> Option<u32> {
+      // Move 1 or 2 steps, encoded redundantly.
+      self.steps += 1;
+      match self.steps {
+        1 | 2 => Some(self.steps),
+        3 | 4 => Some(self.steps - 2 + Ring::RING_SIZE),
+        _ => None,
+      }
+    }
+  }
+
+  impl Game for Ring {
+    type Move = u32;
+    type MoveGenerator = RingMoveIter;
+
+    fn move_generator(&self) -> RingMoveIter {
+      RingMoveIter { steps: 0 }
+    }
+
+    fn make_move(&mut self, steps: u32) {
+      self.cell = (self.cell + steps) % Self::RING_SIZE;
+      self.player1 = !self.player1;
+    }
+
+    fn normalize_move(&self, steps: u32) -> u32 {
+      steps % Self::RING_SIZE
+    }
+
+    fn current_player(&self) -> GamePlayer {
+      if self.player1 {
+        GamePlayer::Player1
+      } else {
+        GamePlayer::Player2
+      }
+    }
+
+    fn finished(&self) -> GameResult {
+      if self.cell == 0 {
+        GameResult::Win(self.current_player().opposite())
+      } else {
+        GameResult::NotFinished
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_player_index() {
+    for player in [GamePlayer::Player1, GamePlayer::Player2] {
+      expect_eq!(GamePlayer::from_index(player.index()), Some(player));
+    }
+    expect_eq!(GamePlayer::Player1.index(), 0);
+    expect_eq!(GamePlayer::Player2.index(), 1);
+    expect_eq!(GamePlayer::from_index(2), None);
+  }
+
+  #[gtest]
+  fn test_perft_tic_tac_toe() {
+    let game = TicTacToe::new();
+    expect_eq!(game.perft(0), 1);
+    expect_eq!(game.perft(1), 9);
+    expect_eq!(game.perft(2), 9 * 8);
+    expect_eq!(game.perft(5), 9 * 8 * 7 * 6 * 5);
+    // 1440 games are won by the 5th move, and the rest continue.
+    expect_eq!(game.perft(6), 1440 + (15120 - 1440) * 4);
+    // The number of distinct complete games.
+    expect_eq!(game.perft(9), 255168);
+    expect_eq!(game.perft(20), 255168);
+  }
+
+  #[gtest]
+  fn test_perft_nim() {
+    // Orderings of takes of 1 and 2 sticks.
+    expect_eq!(Nim::new(4).perft(1), 2);
+    expect_eq!(Nim::new(4).perft(2), 4);
+    // Every game ends within 4 moves, and there are 5 ways to take 4 sticks.
+    expect_eq!(Nim::new(4).perft(4), 5);
+    expect_eq!(Nim::new(4).perft(10), 5);
+    expect_eq!(Nim::new(0).perft(3), 1);
+  }
+
+  #[gtest]
+  fn test_unique_positions_tic_tac_toe() {
+    let game = TicTacToe::new();
+    expect_eq!(game.unique_positions(0), 1);
+    // A corner, edge, or center opening.
+    expect_eq!(game.unique_positions(1), 3);
+    expect_eq!(game.perft(1), 9);
+    expect_eq!(game.unique_positions(2), 12);
+    expect_eq!(game.unique_positions(3), 38);
+  }
+
+  #[gtest]
+  fn test_unique_positions_without_symmetries() {
+    // Nim has no symmetries, but transpositions still collapse: taking 1 then
+    // 2 sticks and taking 2 then 1 both leave 1 stick.
+    expect_eq!(Nim::new(4).unique_positions(2), 3);
+    expect_eq!(Nim::new(4).perft(2), 4);
+  }
+
+  #[gtest]
+  fn test_game_result_accessors() {
+    let win = GameResult::Win(GamePlayer::Player2);
+    expect_eq!(win.winner(), Some(GamePlayer::Player2));
+    expect_eq!(win.loser(), Some(GamePlayer::Player1));
+    expect_false!(win.is_tie());
+
+    expect_eq!(GameResult::Tie.winner(), None);
+    expect_eq!(GameResult::Tie.loser(), None);
+    expect_true!(GameResult::Tie.is_tie());
+
+    expect_eq!(GameResult::NotFinished.winner(), None);
+    expect_eq!(GameResult::NotFinished.loser(), None);
+    expect_false!(GameResult::NotFinished.is_tie());
+  }
+
+  #[gtest]
+  fn test_outcome_for() {
+    // Player 1 takes the last stick.
+    let nim = Nim::new(2).with_move(2);
+    expect_eq!(
+      nim.outcome_for(GamePlayer::Player1),
+      ScoreValue::CurrentPlayerWins
+    );
+    expect_eq!(
+      nim.outcome_for(GamePlayer::Player2),
+      ScoreValue::OtherPlayerWins
+    );
+
+    // Player 2 completes the middle column.
+    let ttt: TicTacToe = "X../.OX/XO.".parse().unwrap();
+    let ttt = ttt.with_move(TTTMove::new((1, 2)));
+    expect_eq!(
+      ttt.outcome_for(GamePlayer::Player2),
+      ScoreValue::CurrentPlayerWins
+    );
+    expect_eq!(
+      ttt.outcome_for(GamePlayer::Player1),
+      ScoreValue::OtherPlayerWins
+    );
+
+    let tie: TicTacToe = "XOX/XOO/OXX".parse().unwrap();
+    expect_eq!(tie.outcome_for(GamePlayer::Player1), ScoreValue::Tie);
+    expect_eq!(tie.outcome_for(GamePlayer::Player2), ScoreValue::Tie);
+  }
+
+  #[gtest]
+  #[should_panic]
+  fn test_outcome_for_unfinished() {
+    Nim::new(2).outcome_for(GamePlayer::Player1);
+  }
+
+  #[gtest]
+  fn test_filter_moves() {
+    let game = Nim::with_max_take(10, 5);
+    let even_moves = game.move_generator().filter_moves(|_, m| m % 2 == 0);
+    expect_eq!(even_moves.to_iter(&game).collect_vec(), vec![2, 4]);
+
+    // The predicate sees the position being searched.
+    let game = Nim::with_max_take(3, 5);
+    let leaving_some = game
+      .move_generator()
+      .filter_moves(|game: &Nim, m| m < game.sticks());
+    expect_eq!(leaving_some.to_iter(&game).collect_vec(), vec![1, 2]);
+  }
+
+  #[gtest]
+  fn test_normalize_move() {
+    let game = Ring { cell: 1, player1: true };
+    expect_eq!(
+      game.normalize_move(1),
+      game.normalize_move(1 + Ring::RING_SIZE)
+    );
+    expect_ne!(game.normalize_move(1), game.normalize_move(2));
+    expect_eq!(
+      game
+        .each_move()
+        .map(|m| game.normalize_move(m))
+        .unique()
+        .collect_vec(),
+      vec![1, 2]
+    );
+
+    expect_true!(game.is_legal_move(2));
+    expect_true!(game.is_legal_move(2 + 2 * Ring::RING_SIZE));
+    expect_false!(game.is_legal_move(3));
+
+    // Games without redundant encodings use moves as they are.
+    let nim = Nim::new(3);
+    expect_eq!(nim.normalize_move(2), 2);
+    expect_true!(nim.is_legal_move(2));
+    expect_false!(nim.is_legal_move(3));
+  }
+
+  fn check_is_terminal<G: Game>(game: &G, rng: &mut StdRng)
+  where
+    G::Move: Ord,
+  {
+    for _ in 0..50 {
+      let mut game = game.clone();
+      loop {
+        expect_eq!(
+          game.is_terminal(),
+          game.finished().is_finished(),
+          "{game:?}"
+        );
+        if game.is_terminal() || make_deterministic_random_move(&mut game, rng).is_none() {
+          break;
+        }
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_is_terminal() {
+    let mut rng = StdRng::seed_from_u64(1280);
+    check_is_terminal(&Nim::new(20), &mut rng);
+    check_is_terminal(&TicTacToe::new(), &mut rng);
+    check_is_terminal(&ConnectN::new(7, 6, 4), &mut rng);
+    check_is_terminal(&ConnectN::new(4, 4, 3), &mut rng);
+    check_is_terminal(&ConnectN::new(3, 3, 3), &mut rng);
   }
 }
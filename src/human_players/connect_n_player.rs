@@ -1,11 +1,11 @@
-use std::io::BufRead;
-
 use itertools::Itertools;
 
 use crate::{
   error::{GameInterfaceError, GameInterfaceResult},
   interactive::{
-    human_player::HumanPlayer, line_reader::GameMoveLineReader, player::MakeMoveControl,
+    human_player::HumanPlayer,
+    input_reader::{InputReader, MouseButtonPress},
+    player::{MakeMoveControl, Player},
   },
   test_games::{ConnectMove, ConnectN},
   Game, GamePlayer,
@@ -27,15 +27,140 @@ impl HumanPlayer for ConnectNPlayer {
     )
   }
 
-  fn parse_move<I: BufRead>(
+  fn parse_move<R: InputReader<Input = String>>(
     &self,
-    mut move_reader: GameMoveLineReader<I>,
+    move_reader: &mut R,
     _game: &ConnectN,
   ) -> GameInterfaceResult<MakeMoveControl<ConnectMove>> {
-    let move_text = move_reader.next_line()?;
+    let move_text = move_reader.next_input()?;
     let col = move_text
       .parse()
       .map_err(|_| GameInterfaceError::MalformedMove(format!("{move_text} is not a number.")))?;
     Ok(MakeMoveControl::Done(ConnectMove { col }))
   }
 }
+
+/// A player who drops pieces by clicking on the board with the mouse.
+///
+/// Clicks are mapped to columns assuming the board is drawn by `ConnectN`'s
+/// `Display` starting at the left edge of the terminal, as `TermInterface`
+/// does, which puts column `c` at terminal column `2c + 1` followed by a
+/// space. A click on either character, in any row, drops a piece in column
+/// `c`. Clicks to the right of the board or on a full column are ignored.
+pub struct ConnectNMousePlayer<R> {
+  name: String,
+  input: R,
+}
+
+impl<R: InputReader<Input = MouseButtonPress>> ConnectNMousePlayer<R> {
+  pub fn new(name: String, input: R) -> Self {
+    Self { name, input }
+  }
+
+  /// The column drawn at one-based terminal column `x`.
+  fn column_at(x: u16) -> u32 {
+    (x.saturating_sub(1) / 2) as u32
+  }
+}
+
+impl<R: InputReader<Input = MouseButtonPress>> Player for ConnectNMousePlayer<R> {
+  type Game = ConnectN;
+
+  fn display_name(&self) -> String {
+    self.name.clone()
+  }
+
+  fn prompt_move_text(&self, _game: &ConnectN) -> Option<String> {
+    Some(format!(
+      "{} to move (click the column you'd like to play in):",
+      self.name
+    ))
+  }
+
+  fn make_move(&mut self, game: &ConnectN) -> GameInterfaceResult<MakeMoveControl<ConnectMove>> {
+    let col = Self::column_at(self.input.next_input()?.x);
+    if col >= game.width() || game.cell(col, game.height() - 1).is_some() {
+      return Ok(MakeMoveControl::Continue);
+    }
+    Ok(MakeMoveControl::Done(ConnectMove { col }))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Cursor;
+
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    error::GameInterfaceError,
+    human_players::connect_n_player::{ConnectNMousePlayer, ConnectNPlayer},
+    interactive::{
+      human_player::HumanPlayer,
+      input_reader::MouseReader,
+      line_reader::GameMoveLineReader,
+      player::{MakeMoveControl, Player},
+    },
+    test_games::{ConnectMove, ConnectN},
+  };
+
+  #[gtest]
+  fn test_parse_move() {
+    let game = ConnectN::new(7, 6, 4);
+    let parse = |input: &str| {
+      ConnectNPlayer.parse_move(&mut GameMoveLineReader::new(input.as_bytes()), &game)
+    };
+    expect_that!(
+      parse("3"),
+      ok(matches_pattern!(MakeMoveControl::Done(eq(&ConnectMove {
+        col: 3
+      }))))
+    );
+    expect_that!(
+      parse("three"),
+      err(matches_pattern!(GameInterfaceError::MalformedMove(
+        anything()
+      )))
+    );
+    expect_that!(parse("q"), err(matches_pattern!(GameInterfaceError::Quit)));
+  }
+
+  fn click(x: u16, y: u16) -> String {
+    format!("\x1b[<0;{x};{y}M")
+  }
+
+  #[gtest]
+  fn test_mouse_player() {
+    let game = ConnectN::from_layout(4, 2, 2, &["X...", "O..."]).unwrap();
+    let input = [click(3, 1), click(6, 2), click(9, 1), click(1, 1)].concat();
+    let mut player =
+      ConnectNMousePlayer::new("Alice".to_owned(), MouseReader::new(Cursor::new(input)));
+
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Done(eq(&ConnectMove {
+        col: 1
+      }))))
+    );
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Done(eq(&ConnectMove {
+        col: 2
+      }))))
+    );
+    // Past the right edge of the board.
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Continue))
+    );
+    // Column 0 is full.
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Continue))
+    );
+    expect_that!(
+      player.make_move(&game),
+      err(matches_pattern!(GameInterfaceError::IoError(anything())))
+    );
+  }
+}
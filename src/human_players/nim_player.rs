@@ -1,10 +1,6 @@
-use std::io::BufRead;
-
 use crate::{
   error::{GameInterfaceError, GameInterfaceResult},
-  interactive::{
-    human_player::HumanPlayer, line_reader::GameMoveLineReader, player::MakeMoveControl,
-  },
+  interactive::{human_player::HumanPlayer, input_reader::InputReader, player::MakeMoveControl},
   test_games::Nim,
 };
 
@@ -14,22 +10,20 @@ impl HumanPlayer for NimPlayer {
   type Game = Nim;
 
   fn prompt_move_text(&self, game: &Nim) -> String {
-    format!(
-      "How many sticks would you like to take? {}",
-      if game.sticks() == 1 {
-        "1 is the only option"
-      } else {
-        "1 or 2"
-      }
-    )
+    let options = match game.max_take().min(game.sticks()) {
+      1 => "1 is the only option".to_owned(),
+      2 => "1 or 2".to_owned(),
+      max => format!("1 to {max}"),
+    };
+    format!("How many sticks would you like to take? {options}")
   }
 
-  fn parse_move<I: BufRead>(
+  fn parse_move<R: InputReader<Input = String>>(
     &self,
-    mut move_reader: GameMoveLineReader<I>,
+    move_reader: &mut R,
     game: &Nim,
   ) -> GameInterfaceResult<MakeMoveControl<u32>> {
-    let move_text = move_reader.next_line()?;
+    let move_text = move_reader.next_input()?;
     let sticks = move_text
       .parse()
       .map_err(|_| GameInterfaceError::MalformedMove(format!("{move_text} is not a number")))?;
@@ -39,7 +33,13 @@ impl HumanPlayer for NimPlayer {
         "Can't take 0 sticks!".to_owned(),
       ));
     }
-    if sticks > game.sticks().min(2) {
+    if sticks > game.max_take() {
+      return Err(GameInterfaceError::MalformedMove(format!(
+        "Can't take more than {} sticks per turn",
+        game.max_take()
+      )));
+    }
+    if sticks > game.sticks() {
       return Err(GameInterfaceError::MalformedMove(format!(
         "{sticks} is greater than the number of sticks remaining ({})",
         game.sticks()
@@ -49,3 +49,59 @@ impl HumanPlayer for NimPlayer {
     Ok(MakeMoveControl::Done(sticks))
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    error::GameInterfaceError,
+    human_players::nim_player::NimPlayer,
+    interactive::{
+      human_player::HumanPlayer, line_reader::GameMoveLineReader, player::MakeMoveControl,
+    },
+    test_games::Nim,
+  };
+
+  /// Parses `input` as a move, returning `None` if it is malformed.
+  fn parse(game: &Nim, input: &str) -> Option<u32> {
+    match NimPlayer.parse_move(&mut GameMoveLineReader::new(input.as_bytes()), game) {
+      Ok(MakeMoveControl::Done(m)) => Some(m),
+      Err(GameInterfaceError::MalformedMove(_)) => None,
+      _ => panic!("Unexpected result parsing \"{input}\""),
+    }
+  }
+
+  #[gtest]
+  fn test_parse_move() {
+    let game = Nim::with_max_take(3, 4);
+    expect_eq!(parse(&game, "2"), Some(2));
+    expect_eq!(parse(&game, " 3 "), Some(3));
+    expect_eq!(parse(&game, "0"), None);
+    expect_eq!(parse(&game, "two"), None);
+    // More than the sticks remaining, and more than the max per turn.
+    expect_eq!(parse(&game, "4"), None);
+    expect_eq!(parse(&game, "5"), None);
+  }
+
+  #[gtest]
+  fn test_prompt_move_text() {
+    let prompt = |game: Nim| NimPlayer.prompt_move_text(&game);
+    expect_eq!(
+      prompt(Nim::new(5)),
+      "How many sticks would you like to take? 1 or 2"
+    );
+    expect_eq!(
+      prompt(Nim::with_max_take(5, 4)),
+      "How many sticks would you like to take? 1 to 4"
+    );
+    expect_eq!(
+      prompt(Nim::with_max_take(3, 4)),
+      "How many sticks would you like to take? 1 to 3"
+    );
+    expect_eq!(
+      prompt(Nim::with_max_take(1, 4)),
+      "How many sticks would you like to take? 1 is the only option"
+    );
+  }
+}
@@ -1,10 +1,6 @@
-use std::io::BufRead;
-
 use crate::{
   error::{GameInterfaceError, GameInterfaceResult},
-  interactive::{
-    human_player::HumanPlayer, line_reader::GameMoveLineReader, player::MakeMoveControl,
-  },
+  interactive::{human_player::HumanPlayer, input_reader::InputReader, player::MakeMoveControl},
   test_games::{TTTMove, TicTacToe},
   Game, GamePlayer,
 };
@@ -24,46 +20,38 @@ impl HumanPlayer for TicTacToePlayer {
     )
   }
 
-  fn parse_move<I: BufRead>(
+  fn parse_move<R: InputReader<Input = String>>(
     &self,
-    mut move_reader: GameMoveLineReader<I>,
+    move_reader: &mut R,
     game: &TicTacToe,
   ) -> GameInterfaceResult<MakeMoveControl<TTTMove>> {
-    let move_text = move_reader.next_line()?;
+    let move_text = move_reader.next_input()?;
     let make_malformed_move_err = || {
       GameInterfaceError::MalformedMove(format!(
         "\"{move_text}\" is not a valid coordinate pair \"X,Y\""
       ))
     };
 
-    let mut chars = move_text.chars();
-    let c1 = chars.next().ok_or_else(make_malformed_move_err)?;
-    let c2 = chars.next().ok_or_else(make_malformed_move_err)?;
-    let c3 = chars.next().ok_or_else(make_malformed_move_err)?;
-    if chars.next().is_some() {
-      return Err(GameInterfaceError::MalformedMove(format!(
-        "Move string is greater than 3 characters long"
-      )));
-    }
-
-    if c2 != ',' {
-      return Err(GameInterfaceError::MalformedMove(format!(
-        "Expected ',' in second position of move string"
-      )));
-    }
+    let (x_text, y_text) = move_text
+      .split_once(',')
+      .ok_or_else(make_malformed_move_err)?;
 
-    if !('1'..='3').contains(&c1) {
-      return Err(GameInterfaceError::MalformedMove(format!(
-        "Expected a number from '1' - '3' as the x-coordinate, found {c1}"
-      )));
-    }
-    if !('1'..='3').contains(&c3) {
-      return Err(GameInterfaceError::MalformedMove(format!(
-        "Expected a number from '1' - '3' as the y-coordinate, found {c3}"
-      )));
-    }
-    let x = c1 as u32 - '1' as u32;
-    let y = c3 as u32 - '1' as u32;
+    let size = game.size();
+    let parse_coord = |text: &str, axis: &str| {
+      text
+        .trim()
+        .parse::<u32>()
+        .ok()
+        .filter(|coord| (1..=size).contains(coord))
+        .map(|coord| coord - 1)
+        .ok_or_else(|| {
+          GameInterfaceError::MalformedMove(format!(
+            "Expected a number from '1' - '{size}' as the {axis}-coordinate, found {text}"
+          ))
+        })
+    };
+    let x = parse_coord(x_text, "x")?;
+    let y = parse_coord(y_text, "y")?;
 
     if !game.is_empty((x, y)) {
       return Err(GameInterfaceError::MalformedMove(format!(
@@ -74,3 +62,39 @@ impl HumanPlayer for TicTacToePlayer {
     Ok(MakeMoveControl::Done(TTTMove::new((x, y))))
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    error::GameInterfaceError,
+    human_players::tic_tac_toe_player::TicTacToePlayer,
+    interactive::{
+      human_player::HumanPlayer, line_reader::GameMoveLineReader, player::MakeMoveControl,
+    },
+    test_games::{TTTMove, TicTacToe},
+  };
+
+  /// Parses `input` as a move, returning `None` if it is malformed.
+  fn parse(game: &TicTacToe, input: &str) -> Option<TTTMove> {
+    match TicTacToePlayer.parse_move(&mut GameMoveLineReader::new(input.as_bytes()), game) {
+      Ok(MakeMoveControl::Done(m)) => Some(m),
+      Err(GameInterfaceError::MalformedMove(_)) => None,
+      _ => panic!("Unexpected result parsing \"{input}\""),
+    }
+  }
+
+  #[gtest]
+  fn test_parse_move_bounds() {
+    let game = TicTacToe::new();
+    expect_eq!(parse(&game, "1,3"), Some(TTTMove::new((0, 2))));
+    expect_eq!(parse(&game, "4,1"), None);
+    expect_eq!(parse(&game, "0,1"), None);
+    expect_eq!(parse(&game, "2"), None);
+
+    let game = TicTacToe::with_size(5, 4);
+    expect_eq!(parse(&game, "5,4"), Some(TTTMove::new((4, 3))));
+    expect_eq!(parse(&game, "6,1"), None);
+  }
+}
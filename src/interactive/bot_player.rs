@@ -1,22 +1,54 @@
+use std::io::{stderr, Stderr, Write};
+
 use crate::{
   error::{GameInterfaceError, GameInterfaceResult},
   interactive::player::{MakeMoveControl, Player},
   Game, Solver,
 };
 
-pub struct BotPlayer<S> {
+pub struct BotPlayer<S, W = Stderr> {
   name: String,
   solver: S,
   depth: u32,
+  /// Whether to write the score of each position the bot moves from to `log`.
+  verbose: bool,
+  log: W,
 }
 
 impl<S> BotPlayer<S> {
   pub fn new(name: String, solver: S, depth: u32) -> Self {
-    Self { name, solver, depth }
+    Self {
+      name,
+      solver,
+      depth,
+      verbose: false,
+      log: stderr(),
+    }
   }
 }
 
-impl<S: Solver> Player for BotPlayer<S> {
+impl<S, W> BotPlayer<S, W> {
+  /// Sets whether the bot reports the score of each position it moves from.
+  /// This is off by default, since the reports interfere with the game being
+  /// drawn when written to the terminal.
+  pub fn with_verbose(mut self, verbose: bool) -> Self {
+    self.verbose = verbose;
+    self
+  }
+
+  /// Writes the bot's reports to `log` instead of stderr.
+  pub fn with_log<L: Write>(self, log: L) -> BotPlayer<S, L> {
+    BotPlayer {
+      name: self.name,
+      solver: self.solver,
+      depth: self.depth,
+      verbose: self.verbose,
+      log,
+    }
+  }
+}
+
+impl<S: Solver, W: Write> Player for BotPlayer<S, W> {
   type Game = S::Game;
 
   fn display_name(&self) -> String {
@@ -32,7 +64,50 @@ impl<S: Solver> Player for BotPlayer<S> {
       GameInterfaceError::InternalError(format!("No move found for game:\n{game:?}"))
     })?;
 
-    eprintln!("Score {score} for game\n{game:?}");
+    if self.verbose {
+      writeln!(self.log, "Score {score} for game\n{game:?}")
+        .map_err(|err| GameInterfaceError::IoError(err.to_string()))?;
+    }
     Ok(MakeMoveControl::Done(m))
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    interactive::{
+      bot_player::BotPlayer,
+      player::{MakeMoveControl, Player},
+    },
+    solvers::MinimaxSolver,
+    test_games::Nim,
+  };
+
+  #[gtest]
+  fn test_quiet_by_default() {
+    let mut log = Vec::new();
+    let mut player = BotPlayer::new("Bot".to_owned(), MinimaxSolver::new(), 5).with_log(&mut log);
+    expect_that!(
+      player.make_move(&Nim::new(4)),
+      ok(matches_pattern!(MakeMoveControl::Done(eq(&1))))
+    );
+    drop(player);
+    expect_that!(log, is_empty());
+  }
+
+  #[gtest]
+  fn test_verbose() {
+    let mut log = Vec::new();
+    let mut player = BotPlayer::new("Bot".to_owned(), MinimaxSolver::new(), 5)
+      .with_verbose(true)
+      .with_log(&mut log);
+    expect_that!(
+      player.make_move(&Nim::new(4)),
+      ok(matches_pattern!(MakeMoveControl::Done(eq(&1))))
+    );
+    drop(player);
+    expect_that!(String::from_utf8(log).unwrap(), starts_with("Score [tie:"));
+  }
+}
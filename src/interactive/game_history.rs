@@ -0,0 +1,130 @@
+use crate::Game;
+
+/// The positions of a game in progress and the moves made between them, which
+/// may be undone and redone.
+#[derive(Clone, Debug)]
+pub struct GameHistory<G: Game> {
+  /// Every position reached, from the initial position to the current one.
+  states: Vec<G>,
+  /// The moves made from each position in `states` but the last.
+  moves: Vec<G::Move>,
+  /// The moves which have been undone, with the most recently undone last.
+  undone: Vec<G::Move>,
+}
+
+impl<G: Game> GameHistory<G> {
+  pub fn new(game: G) -> Self {
+    Self {
+      states: vec![game],
+      moves: Vec::new(),
+      undone: Vec::new(),
+    }
+  }
+
+  /// The current position.
+  pub fn current(&self) -> &G {
+    self
+      .states
+      .last()
+      .expect("The initial position is never removed")
+  }
+
+  /// The current position, which may be modified without being recorded as a
+  /// move, e.g. to resolve a chance event.
+  pub fn current_mut(&mut self) -> &mut G {
+    self
+      .states
+      .last_mut()
+      .expect("The initial position is never removed")
+  }
+
+  /// Makes `m` from the current position. Any undone moves can no longer be
+  /// redone.
+  pub fn push(&mut self, m: G::Move) {
+    let next = self.current().with_move(m);
+    self.states.push(next);
+    self.moves.push(m);
+    self.undone.clear();
+  }
+
+  /// Takes back the last move, returning the position before it was made, or
+  /// `None` if no moves have been made.
+  pub fn undo(&mut self) -> Option<G> {
+    let m = self.moves.pop()?;
+    self.states.pop();
+    self.undone.push(m);
+    Some(self.current().clone())
+  }
+
+  /// Makes the most recently undone move again, returning the position after
+  /// it, or `None` if there is nothing to redo.
+  pub fn redo(&mut self) -> Option<G> {
+    let m = self.undone.pop()?;
+    let next = self.current().with_move(m);
+    self.states.push(next.clone());
+    self.moves.push(m);
+    Some(next)
+  }
+
+  /// The moves made from the initial position to reach the current one.
+  pub fn moves(&self) -> &[G::Move] {
+    &self.moves
+  }
+
+  /// Each move made, along with the position it was made from.
+  pub fn entries(&self) -> impl Iterator<Item = (&G, G::Move)> {
+    self.states.iter().zip(self.moves.iter().copied())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{interactive::game_history::GameHistory, test_games::Nim, Game};
+
+  #[gtest]
+  fn test_undo_redo() {
+    let mut history = GameHistory::new(Nim::new(10));
+    history.push(1);
+    history.push(2);
+    expect_eq!(history.current(), &Nim::new(7));
+    expect_eq!(history.moves(), &[1, 2]);
+
+    expect_eq!(history.undo(), Some(Nim::new(10).with_move(1)));
+    expect_eq!(history.undo(), Some(Nim::new(10)));
+    expect_eq!(history.undo(), None);
+    expect_eq!(history.current(), &Nim::new(10));
+    expect_that!(history.moves(), is_empty());
+
+    expect_eq!(history.redo(), Some(Nim::new(10).with_move(1)));
+    expect_eq!(history.redo(), Some(Nim::new(10).with_move(1).with_move(2)));
+    expect_eq!(history.redo(), None);
+    expect_eq!(history.moves(), &[1, 2]);
+  }
+
+  #[gtest]
+  fn test_push_truncates_redo() {
+    let mut history = GameHistory::new(Nim::new(10));
+    history.push(1);
+    history.push(2);
+    history.undo();
+    history.undo();
+
+    history.push(2);
+    expect_eq!(history.redo(), None);
+    expect_eq!(history.moves(), &[2]);
+    expect_eq!(history.current(), &Nim::new(10).with_move(2));
+  }
+
+  #[gtest]
+  fn test_entries() {
+    let mut history = GameHistory::new(Nim::new(10));
+    history.push(2);
+    history.push(1);
+    expect_eq!(
+      history.entries().collect::<Vec<_>>(),
+      vec![(&Nim::new(10), 2), (&Nim::new(10).with_move(2), 1)]
+    );
+  }
+}
@@ -1,8 +1,6 @@
-use std::io::BufRead;
-
 use crate::{
   error::GameInterfaceResult,
-  interactive::{line_reader::GameMoveLineReader, player::MakeMoveControl},
+  interactive::{input_reader::InputReader, player::MakeMoveControl},
   Game,
 };
 
@@ -13,11 +11,18 @@ pub trait HumanPlayer {
   /// a move.
   fn prompt_move_text(&self, game: &Self::Game) -> String;
 
-  /// Parses a player's move, returning the parsed move, or an error if parsing
-  /// failed.
-  fn parse_move<I: BufRead>(
+  /// Parses a player's move from the lines of text read from `move_reader`,
+  /// returning the parsed move, or an error if parsing failed.
+  fn parse_move<R: InputReader<Input = String>>(
     &self,
-    move_reader: GameMoveLineReader<I>,
+    move_reader: &mut R,
     game: &Self::Game,
   ) -> GameInterfaceResult<MakeMoveControl<<Self::Game as Game>::Move>>;
+
+  /// If true, the player is asked to confirm move `m` before it is made, and
+  /// is prompted for a new move if they decline. Useful for moves with large,
+  /// irreversible consequences.
+  fn requires_confirmation(&self, _game: &Self::Game, _m: <Self::Game as Game>::Move) -> bool {
+    false
+  }
 }
@@ -1,6 +1,4 @@
-use std::io::{stdin, BufReader};
-
-use itertools::Itertools;
+use std::io::{stdin, BufRead, BufReader, Stdin};
 
 use crate::{
   error::{GameInterfaceError, GameInterfaceResult},
@@ -12,18 +10,60 @@ use crate::{
   Game,
 };
 
-pub struct HumanTermPlayer<P> {
+pub struct HumanTermPlayer<P: HumanPlayer, I = BufReader<Stdin>> {
   name: String,
   player: P,
+  input: I,
+  /// A move the player has chosen which is awaiting their confirmation.
+  pending_confirmation: Option<<P::Game as Game>::Move>,
 }
 
-impl<P> HumanTermPlayer<P> {
+impl<P: HumanPlayer> HumanTermPlayer<P> {
   pub fn new(name: String, player: P) -> Self {
-    Self { name, player }
+    Self::with_input(name, player, BufReader::new(stdin()))
   }
 }
 
-impl<P: HumanPlayer> Player for HumanTermPlayer<P> {
+impl<P: HumanPlayer, I: BufRead> HumanTermPlayer<P, I> {
+  /// Constructs a player which reads its moves from `input` instead of stdin.
+  pub fn with_input(name: String, player: P, input: I) -> Self {
+    Self {
+      name,
+      player,
+      input,
+      pending_confirmation: None,
+    }
+  }
+
+  fn confirm_move(
+    &mut self,
+    m: <P::Game as Game>::Move,
+  ) -> GameInterfaceResult<MakeMoveControl<<P::Game as Game>::Move>> {
+    let answer = GameMoveLineReader { input: &mut self.input }
+      .next_line()
+      .inspect_err(|err| {
+        // The move being confirmed may not be legal after an undo or redo.
+        if matches!(err, GameInterfaceError::Undo | GameInterfaceError::Redo) {
+          self.pending_confirmation = None;
+        }
+      })?;
+    match answer.as_str() {
+      "y" => {
+        self.pending_confirmation = None;
+        Ok(MakeMoveControl::Done(m))
+      }
+      "n" => {
+        self.pending_confirmation = None;
+        Ok(MakeMoveControl::Continue)
+      }
+      _ => Err(GameInterfaceError::MalformedMove(format!(
+        "Please answer \"y\" or \"n\", not \"{answer}\""
+      ))),
+    }
+  }
+}
+
+impl<P: HumanPlayer, I: BufRead> Player for HumanTermPlayer<P, I> {
   type Game = P::Game;
 
   fn display_name(&self) -> String {
@@ -31,25 +71,131 @@ impl<P: HumanPlayer> Player for HumanTermPlayer<P> {
   }
 
   fn prompt_move_text(&self, game: &Self::Game) -> Option<String> {
-    Some(self.player.prompt_move_text(game))
+    Some(match self.pending_confirmation {
+      Some(m) => format!("Confirm move {m:?}? (y/n)"),
+      None => self.player.prompt_move_text(game),
+    })
   }
 
   fn make_move(
     &mut self,
     game: &Self::Game,
   ) -> GameInterfaceResult<MakeMoveControl<<P::Game as Game>::Move>> {
-    let m = self
+    if let Some(m) = self.pending_confirmation {
+      return self.confirm_move(m);
+    }
+
+    let control = self
       .player
-      .parse_move(GameMoveLineReader { input: BufReader::new(stdin()) }, game)?;
+      .parse_move(&mut GameMoveLineReader { input: &mut self.input }, game)?;
+    let MakeMoveControl::Done(m) = control else {
+      return Ok(control);
+    };
 
-    if let MakeMoveControl::Done(m) = &m {
-      if !game.each_move().contains(m) {
-        return Err(GameInterfaceError::MalformedMove(format!(
-          "{m:?} is not a legal move!"
-        )));
-      }
+    let m = game.normalize_move(m);
+    if !game.is_legal_move(m) {
+      return Err(GameInterfaceError::MalformedMove(format!(
+        "{m:?} is not a legal move!"
+      )));
+    }
+
+    if self.player.requires_confirmation(game, m) {
+      self.pending_confirmation = Some(m);
+      return Ok(MakeMoveControl::Continue);
+    }
+
+    Ok(MakeMoveControl::Done(m))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::io::Cursor;
+
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    error::GameInterfaceResult,
+    human_players::nim_player::NimPlayer,
+    interactive::{
+      human_player::HumanPlayer,
+      human_term_player::HumanTermPlayer,
+      input_reader::InputReader,
+      player::{MakeMoveControl, Player},
+    },
+    test_games::Nim,
+  };
+
+  /// A Nim player which must confirm taking the last stick.
+  struct CarefulNimPlayer;
+
+  impl HumanPlayer for CarefulNimPlayer {
+    type Game = Nim;
+
+    fn prompt_move_text(&self, game: &Nim) -> String {
+      NimPlayer.prompt_move_text(game)
+    }
+
+    fn parse_move<R: InputReader<Input = String>>(
+      &self,
+      move_reader: &mut R,
+      game: &Nim,
+    ) -> GameInterfaceResult<MakeMoveControl<u32>> {
+      NimPlayer.parse_move(move_reader, game)
     }
 
-    Ok(m)
+    fn requires_confirmation(&self, game: &Nim, m: u32) -> bool {
+      m == game.sticks()
+    }
+  }
+
+  #[gtest]
+  fn test_confirmation_declined_then_accepted() {
+    let game = Nim::new(2);
+    let mut player = HumanTermPlayer::with_input(
+      "p1".to_owned(),
+      CarefulNimPlayer,
+      Cursor::new("2\nn\n2\ny\n"),
+    );
+
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Continue))
+    );
+    expect_that!(
+      player.prompt_move_text(&game),
+      some(eq("Confirm move 2? (y/n)"))
+    );
+
+    // Declining re-prompts for a move.
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Continue))
+    );
+    expect_that!(
+      player.prompt_move_text(&game),
+      some(eq(&NimPlayer.prompt_move_text(&game)))
+    );
+
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Continue))
+    );
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Done(eq(&2))))
+    );
+  }
+
+  #[gtest]
+  fn test_no_confirmation_needed() {
+    let game = Nim::new(2);
+    let mut player =
+      HumanTermPlayer::with_input("p1".to_owned(), CarefulNimPlayer, Cursor::new("1\n"));
+
+    expect_that!(
+      player.make_move(&game),
+      ok(matches_pattern!(MakeMoveControl::Done(eq(&1))))
+    );
   }
 }
@@ -0,0 +1,292 @@
+use std::{
+  io::{BufRead, Read},
+  sync::mpsc::{self, Receiver, RecvTimeoutError, Sender},
+  thread,
+  time::Duration,
+};
+
+use itertools::Either;
+use termion::{
+  event::{Event, Key, MouseButton, MouseEvent},
+  input::{Events, TermRead},
+};
+
+use crate::{
+  error::{GameInterfaceError, GameInterfaceResult},
+  interactive::line_reader::GameMoveLineReader,
+};
+
+/// A source of user input, e.g. lines of text typed into a terminal.
+pub trait InputReader {
+  type Input;
+
+  /// Blocks until the next input is available, returning an error if the
+  /// user quit or the input could not be read.
+  fn next_input(&mut self) -> GameInterfaceResult<Self::Input>;
+}
+
+impl<I: BufRead> InputReader for GameMoveLineReader<I> {
+  type Input = String;
+
+  fn next_input(&mut self) -> GameInterfaceResult<String> {
+    self.next_line()
+  }
+}
+
+/// A click of the left mouse button, at one-based terminal coordinates, so the
+/// top-left character of the terminal is at `(1, 1)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MouseButtonPress {
+  pub x: u16,
+  pub y: u16,
+}
+
+/// Reads left clicks from terminal input, e.g. raw-mode stdin. The terminal
+/// only reports mouse events once mouse reporting is turned on, for example by
+/// writing to a `termion::input::MouseTerminal`.
+///
+/// Other mouse events and keys are skipped, except for "q", which quits, and
+/// "u" and "r", which ask to undo and redo moves, like with
+/// `GameMoveLineReader`.
+pub struct MouseReader<R> {
+  events: Events<R>,
+}
+
+impl<R: Read> MouseReader<R> {
+  pub fn new(input: R) -> Self {
+    Self { events: input.events() }
+  }
+}
+
+impl<R: Read> InputReader for MouseReader<R> {
+  type Input = MouseButtonPress;
+
+  fn next_input(&mut self) -> GameInterfaceResult<MouseButtonPress> {
+    loop {
+      let event = self
+        .events
+        .next()
+        .ok_or_else(|| GameInterfaceError::IoError("Input closed".to_owned()))?
+        .map_err(|err| GameInterfaceError::IoError(err.to_string()))?;
+      match event {
+        Event::Mouse(MouseEvent::Press(MouseButton::Left, x, y)) => {
+          return Ok(MouseButtonPress { x, y })
+        }
+        Event::Key(Key::Char('q')) => return Err(GameInterfaceError::Quit),
+        Event::Key(Key::Char('u')) => return Err(GameInterfaceError::Undo),
+        Event::Key(Key::Char('r')) => return Err(GameInterfaceError::Redo),
+        _ => {}
+      }
+    }
+  }
+}
+
+/// Wraps an `InputReader`, returning `GameInterfaceError::Timeout` if the
+/// wrapped reader doesn't produce an input within a time limit.
+///
+/// The wrapped reader is moved to a background thread. A read which times out
+/// is not cancelled: the next call to `next_input` continues waiting for it,
+/// with a fresh time limit.
+pub struct TimeoutReader<T> {
+  timeout: Duration,
+  requests: Sender<()>,
+  inputs: Receiver<GameInterfaceResult<T>>,
+  /// True if a read has been requested from the background thread which has
+  /// not been received yet.
+  read_pending: bool,
+}
+
+impl<T: Send + 'static> TimeoutReader<T> {
+  pub fn new<I>(mut reader: I, timeout: Duration) -> Self
+  where
+    I: InputReader<Input = T> + Send + 'static,
+  {
+    let (requests, request_receiver) = mpsc::channel::<()>();
+    let (input_sender, inputs) = mpsc::channel();
+    thread::spawn(move || {
+      for () in request_receiver {
+        if input_sender.send(reader.next_input()).is_err() {
+          break;
+        }
+      }
+    });
+
+    Self {
+      timeout,
+      requests,
+      inputs,
+      read_pending: false,
+    }
+  }
+
+  pub fn timeout(&self) -> Duration {
+    self.timeout
+  }
+}
+
+impl<T> InputReader for TimeoutReader<T> {
+  type Input = T;
+
+  fn next_input(&mut self) -> GameInterfaceResult<T> {
+    let disconnected =
+      || GameInterfaceError::InternalError("Input reader thread exited unexpectedly".to_owned());
+
+    if !self.read_pending {
+      self.requests.send(()).map_err(|_| disconnected())?;
+      self.read_pending = true;
+    }
+
+    match self.inputs.recv_timeout(self.timeout) {
+      Ok(input) => {
+        self.read_pending = false;
+        input
+      }
+      Err(RecvTimeoutError::Timeout) => Err(GameInterfaceError::Timeout),
+      Err(RecvTimeoutError::Disconnected) => Err(disconnected()),
+    }
+  }
+}
+
+/// Reads from `A`, falling back to reading from `B` whenever `A` returns
+/// `GameInterfaceError::NoInput`, e.g. to accept either mouse clicks or typed
+/// coordinates. Any other error from either reader, like `Quit`, is returned
+/// as is.
+pub struct ChainedReader<A, B> {
+  first: A,
+  fallback: B,
+}
+
+impl<A, B> ChainedReader<A, B> {
+  pub fn new(first: A, fallback: B) -> Self {
+    Self { first, fallback }
+  }
+}
+
+impl<A: InputReader, B: InputReader> InputReader for ChainedReader<A, B> {
+  type Input = Either<A::Input, B::Input>;
+
+  fn next_input(&mut self) -> GameInterfaceResult<Self::Input> {
+    match self.first.next_input() {
+      Err(GameInterfaceError::NoInput) => self.fallback.next_input().map(Either::Right),
+      input => input.map(Either::Left),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{io::Cursor, thread, time::Duration};
+
+  use googletest::{gtest, prelude::*};
+
+  use itertools::Either;
+
+  use crate::{
+    error::{GameInterfaceError, GameInterfaceResult},
+    interactive::{
+      input_reader::{ChainedReader, InputReader, MouseButtonPress, MouseReader, TimeoutReader},
+      line_reader::GameMoveLineReader,
+    },
+  };
+
+  /// A reader which never produces any input.
+  struct NeverReader;
+
+  impl InputReader for NeverReader {
+    type Input = String;
+
+    fn next_input(&mut self) -> GameInterfaceResult<String> {
+      loop {
+        thread::park();
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_timeout() {
+    let mut reader = TimeoutReader::new(NeverReader, Duration::from_millis(10));
+    expect_that!(
+      reader.next_input(),
+      err(matches_pattern!(GameInterfaceError::Timeout))
+    );
+    expect_that!(
+      reader.next_input(),
+      err(matches_pattern!(GameInterfaceError::Timeout))
+    );
+  }
+
+  #[gtest]
+  fn test_input_within_timeout() {
+    let lines = GameMoveLineReader::new(Cursor::new("1\nq\n"));
+    let mut reader = TimeoutReader::new(lines, Duration::from_secs(10));
+    expect_that!(reader.next_input(), ok(eq("1")));
+    expect_that!(
+      reader.next_input(),
+      err(matches_pattern!(GameInterfaceError::Quit))
+    );
+  }
+
+  #[gtest]
+  fn test_mouse_reader() {
+    // A left click at (3, 2), its release, a right click, a left click at
+    // (7, 1), and a "q".
+    let input = "\x1b[<0;3;2M\x1b[<0;3;2m\x1b[<2;5;5M\x1b[<0;7;1Mq";
+    let mut reader = MouseReader::new(Cursor::new(input));
+    expect_that!(
+      reader.next_input(),
+      ok(eq(&MouseButtonPress { x: 3, y: 2 }))
+    );
+    expect_that!(
+      reader.next_input(),
+      ok(eq(&MouseButtonPress { x: 7, y: 1 }))
+    );
+    expect_that!(
+      reader.next_input(),
+      err(matches_pattern!(GameInterfaceError::Quit))
+    );
+    expect_that!(
+      reader.next_input(),
+      err(matches_pattern!(GameInterfaceError::IoError(anything())))
+    );
+  }
+
+  /// A reader which replays a fixed sequence of mouse clicks, where `None` is
+  /// a click that didn't land anywhere usable.
+  struct ClickReader {
+    clicks: Vec<Option<(u32, u32)>>,
+  }
+
+  impl InputReader for ClickReader {
+    type Input = (u32, u32);
+
+    fn next_input(&mut self) -> GameInterfaceResult<(u32, u32)> {
+      if self.clicks.is_empty() {
+        return Err(GameInterfaceError::Quit);
+      }
+      self.clicks.remove(0).ok_or(GameInterfaceError::NoInput)
+    }
+  }
+
+  #[gtest]
+  fn test_chained_reader() {
+    let clicks = ClickReader {
+      clicks: vec![Some((1, 2)), None, Some((0, 0)), None],
+    };
+    let lines = GameMoveLineReader::new(Cursor::new("a1\nq\n"));
+    let mut reader = ChainedReader::new(clicks, lines);
+
+    expect_that!(reader.next_input(), ok(eq(&Either::Left((1, 2)))));
+    expect_that!(reader.next_input(), ok(eq(&Either::Right("a1".to_owned()))));
+    expect_that!(reader.next_input(), ok(eq(&Either::Left((0, 0)))));
+    // Quitting from the fallback reader.
+    expect_that!(
+      reader.next_input(),
+      err(matches_pattern!(GameInterfaceError::Quit))
+    );
+    // Quitting from the first reader.
+    expect_that!(
+      reader.next_input(),
+      err(matches_pattern!(GameInterfaceError::Quit))
+    );
+  }
+}
@@ -7,19 +7,35 @@ pub struct GameMoveLineReader<I> {
 }
 
 impl<I: BufRead> GameMoveLineReader<I> {
+  pub fn new(input: I) -> Self {
+    Self { input }
+  }
+
   /// Reads the next line from the input source, returning an error if the user
-  /// quit or the underlying `BufReader` returned an error when trying to read
-  /// the next line.
+  /// quit with "q", asked to undo the last move with "u" or redo it with "r",
+  /// asked for a hint with "hint", asked to save the game with "save <path>",
+  /// the input ended, or the underlying `BufReader` returned an error when
+  /// trying to read the next line.
   pub fn next_line(&mut self) -> GameInterfaceResult<String> {
     let mut buffer = String::new();
-    self
+    let bytes_read = self
       .input
       .read_line(&mut buffer)
       .map_err(|err| GameInterfaceError::IoError(err.to_string()))?;
+    if bytes_read == 0 {
+      return Err(GameInterfaceError::IoError("Input ended".to_owned()));
+    }
 
     let move_text = buffer.trim();
-    if move_text == "q" {
-      return Err(GameInterfaceError::Quit);
+    match move_text {
+      "q" => return Err(GameInterfaceError::Quit),
+      "u" => return Err(GameInterfaceError::Undo),
+      "r" => return Err(GameInterfaceError::Redo),
+      "hint" => return Err(GameInterfaceError::Hint),
+      _ => {}
+    }
+    if let Some(path) = move_text.strip_prefix("save ") {
+      return Err(GameInterfaceError::Save(path.trim().to_owned()));
     }
 
     Ok(move_text.to_owned())
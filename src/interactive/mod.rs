@@ -1,6 +1,8 @@
 pub mod bot_player;
+pub mod game_history;
 pub mod human_player;
 pub mod human_term_player;
+pub mod input_reader;
 pub mod line_reader;
 pub mod player;
 pub mod term_interface;
@@ -3,6 +3,7 @@ use crate::{error::GameInterfaceResult, Game};
 /// Value returned from `make_move` to tell the game engine whether to accept a
 /// move from a player, or to keep prompting the player. `Continue` may be used
 /// if a move requires multiple selections from the user.
+#[derive(Debug)]
 pub enum MakeMoveControl<M> {
   /// The move that the player chose.
   Done(M),
@@ -1,8 +1,12 @@
 use std::{
   fmt::Display,
-  io::{stdin, Stdout, Write},
+  fs,
+  io::{stdin, stdout, BufRead, BufReader, Stdin, Stdout, Write},
+  path::Path,
 };
 
+use itertools::Itertools;
+use rand::rngs::ThreadRng;
 use termion::{
   clear, cursor,
   screen::{AlternateScreen, IntoAlternateScreen},
@@ -10,15 +14,37 @@ use termion::{
 
 use crate::{
   error::{GameInterfaceError, GameInterfaceResult},
-  interactive::player::{MakeMoveControl, Player},
-  Game, GamePlayer, GameResult,
+  interactive::{
+    bot_player::BotPlayer,
+    game_history::GameHistory,
+    human_player::HumanPlayer,
+    human_term_player::HumanTermPlayer,
+    line_reader::GameMoveLineReader,
+    player::{MakeMoveControl, Player},
+  },
+  Game, GamePlayer, GameResult, GameSerialize, GridSetup, Solver,
 };
 
-pub struct TermInterface<G, P1, P2> {
-  game: G,
+pub struct TermInterface<G: Game, P1, P2, W = AlternateScreen<Stdout>, I = BufReader<Stdin>> {
+  /// The current position, along with the moves made to reach it.
+  history: GameHistory<G>,
   player1: P1,
   player2: P2,
-  stdout: AlternateScreen<Stdout>,
+  stdout: W,
+  input: I,
+  /// Whether to wait for a line of input after the game ends, so the user can
+  /// see the result before the screen is cleared.
+  pause_at_end: bool,
+  /// Whether to separate each redraw of the game with a line of text instead
+  /// of clearing the screen, for output which isn't going to a terminal.
+  plain_output: bool,
+  /// Serializes the game for the "save" command, if saving is enabled.
+  to_save_string: Option<fn(&G) -> String>,
+  /// Suggests moves for the "hint" command, if hints are enabled.
+  hint_solver: Option<Box<dyn Solver<Game = G>>>,
+  /// The depth `hint_solver` searches to.
+  hint_depth: u32,
+  rng: ThreadRng,
 }
 
 impl<G, P1, P2> TermInterface<G, P1, P2>
@@ -31,7 +57,124 @@ where
     let stdout = std::io::stdout().into_alternate_screen().map_err(|err| {
       GameInterfaceError::IoError(format!("Failed to enter alternate screen: {err}"))
     })?;
-    Ok(Self { game, player1, player2, stdout })
+    Ok(Self::with_io(
+      game,
+      player1,
+      player2,
+      stdout,
+      BufReader::new(stdin()),
+    ))
+  }
+}
+
+impl<G, H, S> TermInterface<G, HumanTermPlayer<H>, BotPlayer<S>>
+where
+  G: Game + Display,
+  H: HumanPlayer<Game = G>,
+  S: Solver<Game = G>,
+{
+  /// Constructs an interface for a person playing against `bot`, with the
+  /// person moving first.
+  pub fn human_vs_bot(
+    game: G,
+    human: HumanTermPlayer<H>,
+    bot: BotPlayer<S>,
+  ) -> GameInterfaceResult<Self> {
+    Self::new(game, human, bot)
+  }
+}
+
+impl<G, P1, P2> TermInterface<G, P1, P2, Stdout>
+where
+  G: Game + Display,
+  P1: Player<Game = G>,
+  P2: Player<Game = G>,
+{
+  /// Constructs an interface which writes to stdout as plain text, without
+  /// switching to the alternate screen or clearing it, e.g. for piping the
+  /// game to a file. It doesn't pause at the end of the game, since the
+  /// output stays visible.
+  pub fn new_plain(game: G, player1: P1, player2: P2) -> Self {
+    Self::with_io(game, player1, player2, stdout(), BufReader::new(stdin()))
+      .with_plain_output(true)
+      .with_pause_at_end(false)
+  }
+}
+
+impl<G, P1, P2, W, I> TermInterface<G, P1, P2, W, I>
+where
+  G: Game + Display,
+  P1: Player<Game = G>,
+  P2: Player<Game = G>,
+  W: Write,
+  I: BufRead,
+{
+  /// Constructs an interface which writes the game to `output` instead of
+  /// stdout, and waits for the final keypress on `input` instead of stdin.
+  pub fn with_io(game: G, player1: P1, player2: P2, output: W, input: I) -> Self {
+    Self {
+      history: GameHistory::new(game),
+      player1,
+      player2,
+      stdout: output,
+      input,
+      pause_at_end: true,
+      plain_output: false,
+      to_save_string: None,
+      hint_solver: None,
+      hint_depth: 0,
+      rng: rand::rng(),
+    }
+  }
+
+  /// Sets whether `play` waits for a line of input after the game ends. This
+  /// is on by default, and may be turned off when the output stays visible,
+  /// e.g. when it is being captured.
+  pub fn with_pause_at_end(mut self, pause_at_end: bool) -> Self {
+    self.pause_at_end = pause_at_end;
+    self
+  }
+
+  /// Sets whether the screen is cleared between moves using terminal control
+  /// sequences, or the redraws are separated by a line of dashes instead.
+  /// This is off by default.
+  pub fn with_plain_output(mut self, plain_output: bool) -> Self {
+    self.plain_output = plain_output;
+    self
+  }
+
+  /// Enables the "hint" command, which suggests the move `solver` finds when
+  /// searching `depth` moves ahead without making it.
+  pub fn with_hint_solver(mut self, solver: impl Solver<Game = G> + 'static, depth: u32) -> Self {
+    self.hint_solver = Some(Box::new(solver));
+    self.hint_depth = depth;
+    self
+  }
+
+  /// Describes the move the hint solver suggests for the current position.
+  fn hint(&mut self) -> String {
+    let Some(solver) = &mut self.hint_solver else {
+      return "Hints aren't enabled for this game".to_owned();
+    };
+    if self.history.current().finished().is_finished() {
+      return "The game is over, there are no moves to suggest".to_owned();
+    }
+    match solver.best_move(self.history.current(), self.hint_depth) {
+      (score, Some(m)) => format!(
+        "Hint: {} ({score:#})",
+        self.history.current().format_move(m)
+      ),
+      (_, None) => "The solver has no move to suggest".to_owned(),
+    }
+  }
+
+  /// Writes the current position to `path`, for `TermInterface::resume`.
+  fn save(&self, path: &str) -> GameInterfaceResult {
+    let to_save_string = self.to_save_string.ok_or_else(|| {
+      GameInterfaceError::InternalError("Saving isn't supported for this game".to_owned())
+    })?;
+    fs::write(path, to_save_string(self.history.current()))
+      .map_err(|err| GameInterfaceError::IoError(format!("Failed to save to {path}: {err}")))
   }
 
   fn player_name(&self, player: GamePlayer) -> String {
@@ -42,19 +185,27 @@ where
   }
 
   fn current_player_name(&self) -> String {
-    self.player_name(self.game.current_player())
+    self.player_name(self.history.current().current_player())
   }
 
   fn next_move(&mut self) -> GameInterfaceResult<MakeMoveControl<G::Move>> {
     loop {
-      let move_result = match self.game.current_player() {
-        GamePlayer::Player1 => self.player1.make_move(&self.game),
-        GamePlayer::Player2 => self.player2.make_move(&self.game),
+      let move_result = match self.history.current().current_player() {
+        GamePlayer::Player1 => self.player1.make_move(self.history.current()),
+        GamePlayer::Player2 => self.player2.make_move(self.history.current()),
       };
 
       match move_result {
         Ok(m) => break Ok(m),
-        Err(err @ (GameInterfaceError::Quit | GameInterfaceError::IoError(_))) => break Err(err),
+        Err(
+          err @ (GameInterfaceError::Quit
+          | GameInterfaceError::Undo
+          | GameInterfaceError::Redo
+          | GameInterfaceError::Save(_)
+          | GameInterfaceError::Hint
+          | GameInterfaceError::Timeout
+          | GameInterfaceError::IoError(_)),
+        ) => break Err(err),
         Err(err) => {
           self.println(&format!("{err}"))?;
         }
@@ -77,15 +228,108 @@ where
   }
 
   fn clear(&mut self) -> GameInterfaceResult {
+    if self.plain_output {
+      return self.println(&"-".repeat(20));
+    }
     self.print(&format!("{}{}", cursor::Goto(1, 1), clear::All))
   }
 
-  pub fn play(mut self) -> GameInterfaceResult {
-    while !self.game.finished().is_finished() {
-      self.println(&format!("{}", self.game))?;
-      if let Some(flavor_text) = match self.game.current_player() {
-        GamePlayer::Player1 => self.player1.prompt_move_text(&self.game),
-        GamePlayer::Player2 => self.player2.prompt_move_text(&self.game),
+  /// Prints the moves made so far as a numbered list, along with the player
+  /// who made each.
+  fn print_move_log(&mut self) -> GameInterfaceResult {
+    let lines = self
+      .history
+      .entries()
+      .enumerate()
+      .map(|(i, (game, m))| {
+        format!(
+          "{:>3}. {}: {}",
+          i + 1,
+          self.player_name(game.current_player()),
+          game.format_move(m)
+        )
+      })
+      .collect_vec();
+    self.println("Moves:")?;
+    for line in lines {
+      self.println(&line)?;
+    }
+    Ok(())
+  }
+
+  fn print_result(&mut self, result: &GameResult) -> GameInterfaceResult {
+    match result {
+      GameResult::Win(player) => self.println(&format!("{} wins!", self.player_name(*player))),
+      GameResult::Tie => self.println("It's a tie!"),
+      GameResult::NotFinished => self.println(&format!(
+        "The game isn't over, {} to move.",
+        self.current_player_name()
+      )),
+    }
+  }
+
+  /// Plays back `moves` from `game` one at a time, drawing the position
+  /// before each move and waiting for the user to press enter to make it.
+  ///
+  /// Stops with a `MalformedMove` error at the first move which isn't legal,
+  /// including any moves after the game has finished. Otherwise, `play` will
+  /// continue from the position after the last move, which may be before the
+  /// game finishes.
+  pub fn replay(&mut self, game: G, moves: Vec<G::Move>) -> GameInterfaceResult {
+    self.history = GameHistory::new(game);
+    for (i, m) in moves.into_iter().enumerate() {
+      let game = self.history.current();
+      if game.finished().is_finished() {
+        return Err(GameInterfaceError::MalformedMove(format!(
+          "Move {} ({}) was made after the game ended",
+          i + 1,
+          game.format_move(m)
+        )));
+      }
+      if !game.is_legal_move(m) {
+        return Err(GameInterfaceError::MalformedMove(format!(
+          "Move {} ({}) is not legal",
+          i + 1,
+          game.format_move(m)
+        )));
+      }
+
+      let prompt = format!(
+        "{}\nPress enter for move {}, {}: {}",
+        game,
+        i + 1,
+        self.current_player_name(),
+        game.format_move(m)
+      );
+      self.clear()?;
+      self.println(&prompt)?;
+      GameMoveLineReader { input: &mut self.input }.next_line()?;
+      self.history.push(m);
+    }
+
+    self.clear()?;
+    self.println(&format!("{}", self.history.current()))?;
+    let result = self.history.current().finished();
+    self.print_result(&result)
+  }
+
+  /// Plays the game to completion, returning the result. Once the game
+  /// finishes, the moves made are printed before the result.
+  ///
+  /// Players may take back the last move by entering "u", which returns to
+  /// the position before it was made, and make an undone move again by
+  /// entering "r". If enabled, they may also ask for a suggested move by
+  /// entering "hint", and save the current position by entering
+  /// "save <path>".
+  pub fn play(mut self) -> GameInterfaceResult<GameResult> {
+    while !self.history.current().finished().is_finished() {
+      // Resolve any random events before the next player chooses their move.
+      self.history.current_mut().resolve_chance(&mut self.rng);
+
+      self.println(&format!("{}", self.history.current()))?;
+      if let Some(flavor_text) = match self.history.current().current_player() {
+        GamePlayer::Player1 => self.player1.prompt_move_text(self.history.current()),
+        GamePlayer::Player2 => self.player2.prompt_move_text(self.history.current()),
       } {
         self.println(&flavor_text)?;
       } else {
@@ -93,7 +337,7 @@ where
       }
 
       // Prompt the player for their next move.
-      let next_move = self.next_move()?;
+      let next_move = self.next_move();
 
       // Clear the screen before interpreting their move.
       self.clear()?;
@@ -101,29 +345,648 @@ where
       // If the player requested to continue, loop back and redraw the screen.
       // Otherwise, make the move and loop back.
       match next_move {
-        MakeMoveControl::Done(m) => self.game.make_move(m),
-        MakeMoveControl::Continue => continue,
+        Ok(MakeMoveControl::Done(m)) => self.history.push(m),
+        Ok(MakeMoveControl::Continue) => continue,
+        Err(GameInterfaceError::Hint) => {
+          let hint = self.hint();
+          self.println(&hint)?;
+        }
+        Err(GameInterfaceError::Undo) => {
+          if self.history.undo().is_none() {
+            self.println("There are no moves to undo!")?;
+          }
+        }
+        Err(GameInterfaceError::Redo) => {
+          if self.history.redo().is_none() {
+            self.println("There are no moves to redo!")?;
+          }
+        }
+        Err(GameInterfaceError::Save(path)) => match self.save(&path) {
+          Ok(()) => self.println(&format!("Saved the game to {path}"))?,
+          Err(err) => self.println(&format!("{err}"))?,
+        },
+        Err(err) => return Err(err),
       };
     }
 
-    self.println(&format!("{}", self.game))?;
+    self.println(&format!("{}", self.history.current()))?;
+    self.print_move_log()?;
+
+    let result = self.history.current().finished();
+    self.print_result(&result)?;
+
+    // Wait for the user to press enter to end the program, so they may see the
+    // result of the game.
+    if self.pause_at_end {
+      self
+        .input
+        .read_line(&mut String::new())
+        .map_err(|err| GameInterfaceError::IoError(err.to_string()))?;
+    }
+
+    Ok(result)
+  }
+}
+
+impl<G, P1, P2> TermInterface<G, P1, P2>
+where
+  G: GameSerialize + Display,
+  P1: Player<Game = G>,
+  P2: Player<Game = G>,
+{
+  /// Constructs an interface which plays from the position saved at `path`
+  /// with the "save" command, with saving enabled.
+  pub fn resume(path: impl AsRef<Path>, player1: P1, player2: P2) -> GameInterfaceResult<Self> {
+    let game = Self::load_game(path)?;
+    Ok(Self::new(game, player1, player2)?.with_saving())
+  }
+}
 
-    match self.game.finished() {
-      GameResult::Win(player) => {
-        self.println(&format!("{} wins!", self.player_name(player)))?;
+impl<G, P1, P2, W, I> TermInterface<G, P1, P2, W, I>
+where
+  G: GameSerialize + Display,
+  P1: Player<Game = G>,
+  P2: Player<Game = G>,
+  W: Write,
+  I: BufRead,
+{
+  /// Enables the "save <path>" command, which writes the position to `path`
+  /// in `GameSerialize` notation.
+  pub fn with_saving(mut self) -> Self {
+    self.to_save_string = Some(G::to_notation);
+    self
+  }
+
+  /// Reads a position written by the "save" command.
+  pub fn load_game(path: impl AsRef<Path>) -> GameInterfaceResult<G> {
+    let path = path.as_ref();
+    let notation = fs::read_to_string(path).map_err(|err| {
+      GameInterfaceError::IoError(format!("Failed to read {}: {err}", path.display()))
+    })?;
+    G::from_notation(notation.trim()).map_err(|err| {
+      GameInterfaceError::IoError(format!("Invalid save file {}: {err}", path.display()))
+    })
+  }
+}
+
+impl<G, P1, P2, W, I> TermInterface<G, P1, P2, W, I>
+where
+  G: GridSetup + Display,
+  P1: Player<Game = G>,
+  P2: Player<Game = G>,
+  W: Write,
+  I: BufRead,
+{
+  /// Lets the user set up a position starting from `game`, before playing
+  /// from it. Entering "X,Y" coordinates (counting from 1) cycles the cell
+  /// between empty, player 1 and player 2, "p1" or "p2" chooses the player to
+  /// move, and "done" finishes editing. The position must be
+  /// `is_legal_state` to finish.
+  ///
+  /// Returns the edited position, which `play` will then start from.
+  pub fn edit(&mut self, mut game: G) -> GameInterfaceResult<G> {
+    let mut message = None;
+    loop {
+      self.clear()?;
+      self.println(&format!("{game}"))?;
+      if let Some(message) = message.take() {
+        self.println(message)?;
       }
-      GameResult::Tie => {
-        self.println(&format!("It's a tie!"))?;
+      self.println(&format!(
+        "{} to move.",
+        self.player_name(game.current_player())
+      ))?;
+      self.println(
+        "Toggle a cell with \"X,Y\", choose the player to move with \"p1\" or \"p2\", or \
+         finish with \"done\":",
+      )?;
+
+      match self.read_edit_command()?.as_str() {
+        "done" if game.is_legal_state() => {
+          self.history = GameHistory::new(game.clone());
+          return Ok(game);
+        }
+        "done" => message = Some("This position can't be reached in a real game!"),
+        "p1" => game.set_current_player(GamePlayer::Player1),
+        "p2" => game.set_current_player(GamePlayer::Player2),
+        command => match Self::parse_cell(&game, command) {
+          Some(pos) => {
+            let piece = game.as_grid()[pos.1 as usize][pos.0 as usize];
+            let next_piece = match piece {
+              None => Some(GamePlayer::Player1),
+              Some(GamePlayer::Player1) => Some(GamePlayer::Player2),
+              Some(GamePlayer::Player2) => None,
+            };
+            game.set_cell(pos, next_piece);
+          }
+          None => message = Some("Unrecognized command"),
+        },
       }
-      GameResult::NotFinished => unreachable!(),
     }
+  }
 
-    // Wait for the user to press enter to end the program, so they may see the
-    // result of the game.
-    stdin()
-      .read_line(&mut String::new())
+  /// Parses "X,Y" coordinates counting from 1 into a cell of `game`'s grid.
+  fn parse_cell(game: &G, command: &str) -> Option<(u32, u32)> {
+    let (x, y) = command.split_once(',')?;
+    let (x, y) = (x.trim().parse::<u32>().ok()?, y.trim().parse::<u32>().ok()?);
+    let grid = game.as_grid();
+    let width = grid.first().map_or(0, Vec::len) as u32;
+    ((1..=width).contains(&x) && (1..=grid.len() as u32).contains(&y)).then(|| (x - 1, y - 1))
+  }
+
+  fn read_edit_command(&mut self) -> GameInterfaceResult<String> {
+    let mut buffer = String::new();
+    let bytes_read = self
+      .input
+      .read_line(&mut buffer)
       .map_err(|err| GameInterfaceError::IoError(err.to_string()))?;
+    if bytes_read == 0 {
+      return Err(GameInterfaceError::IoError(
+        "Input ended while editing".to_owned(),
+      ));
+    }
 
-    Ok(())
+    let command = buffer.trim();
+    if command == "q" {
+      return Err(GameInterfaceError::Quit);
+    }
+    Ok(command.to_owned())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{env, fs, io::Cursor};
+
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+
+  use crate::{
+    error::GameInterfaceError,
+    human_players::{nim_player::NimPlayer, tic_tac_toe_player::TicTacToePlayer},
+    interactive::{
+      bot_player::BotPlayer, human_term_player::HumanTermPlayer, term_interface::TermInterface,
+    },
+    solvers::MinimaxSolver,
+    test_games::{Nim, TTTMove, TicTacToe},
+    Game, GamePlayer, GameResult, GameSerialize,
+  };
+
+  #[gtest]
+  fn test_play_returns_result() {
+    let player1 = HumanTermPlayer::with_input(
+      "Alice".to_owned(),
+      TicTacToePlayer,
+      Cursor::new("1,1\n1,2\n1,3\n"),
+    );
+    let player2 =
+      HumanTermPlayer::with_input("Bob".to_owned(), TicTacToePlayer, Cursor::new("2,1\n2,2\n"));
+    let mut output = Vec::new();
+    let result = TermInterface::with_io(
+      TicTacToe::new(),
+      player1,
+      player2,
+      &mut output,
+      Cursor::new("\n"),
+    )
+    .play();
+
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player1))));
+    expect_that!(
+      String::from_utf8(output).unwrap(),
+      contains_substring("Alice wins!")
+    );
+  }
+
+  #[gtest]
+  fn test_replay() {
+    let moves = [(0, 0), (1, 1), (0, 1), (2, 2), (0, 2)]
+      .map(TTTMove::new)
+      .to_vec();
+    let mut output = Vec::new();
+    let mut input = Cursor::new("\n".repeat(5));
+    let mut interface = TermInterface::with_io(
+      TicTacToe::new(),
+      HumanTermPlayer::with_input("Alice".to_owned(), TicTacToePlayer, Cursor::new("")),
+      HumanTermPlayer::with_input("Bob".to_owned(), TicTacToePlayer, Cursor::new("")),
+      &mut output,
+      &mut input,
+    )
+    .with_plain_output(true);
+    expect_that!(interface.replay(TicTacToe::new(), moves), ok(eq(&())));
+    drop(interface);
+
+    let output = String::from_utf8(output).unwrap();
+    let boards = output
+      .split(&"-".repeat(20))
+      .filter(|step| !step.is_empty())
+      .collect_vec();
+    expect_eq!(
+      boards,
+      vec![
+        "\n...\n...\n...\nPress enter for move 1, Alice: 1,1\n",
+        "\n...\n...\nX..\nPress enter for move 2, Bob: 2,2\n",
+        "\n...\n.O.\nX..\nPress enter for move 3, Alice: 1,2\n",
+        "\n...\nXO.\nX..\nPress enter for move 4, Bob: 3,3\n",
+        "\n..O\nXO.\nX..\nPress enter for move 5, Alice: 1,3\n",
+        "\nX.O\nXO.\nX..\nAlice wins!\n",
+      ]
+    );
+    expect_eq!(input.position(), 5);
+  }
+
+  #[gtest]
+  fn test_replay_unfinished() {
+    let moves = vec![TTTMove::new((1, 1))];
+    let mut output = Vec::new();
+    let mut interface = TermInterface::with_io(
+      TicTacToe::new(),
+      HumanTermPlayer::with_input("Alice".to_owned(), TicTacToePlayer, Cursor::new("")),
+      HumanTermPlayer::with_input("Bob".to_owned(), TicTacToePlayer, Cursor::new("")),
+      &mut output,
+      Cursor::new("\n"),
+    );
+    expect_that!(interface.replay(TicTacToe::new(), moves), ok(eq(&())));
+    drop(interface);
+    expect_that!(
+      String::from_utf8(output).unwrap(),
+      ends_with("The game isn't over, Bob to move.\n")
+    );
+  }
+
+  #[gtest]
+  fn test_replay_illegal_moves() {
+    let replay = |moves: &[(u32, u32)]| {
+      TermInterface::with_io(
+        TicTacToe::new(),
+        HumanTermPlayer::with_input("Alice".to_owned(), TicTacToePlayer, Cursor::new("")),
+        HumanTermPlayer::with_input("Bob".to_owned(), TicTacToePlayer, Cursor::new("")),
+        Vec::new(),
+        Cursor::new("\n".repeat(moves.len())),
+      )
+      .replay(
+        TicTacToe::new(),
+        moves.iter().copied().map(TTTMove::new).collect(),
+      )
+    };
+
+    expect_that!(
+      replay(&[(0, 0), (1, 1), (0, 0)]),
+      err(displays_as(eq("Malformed move: Move 3 (1,1) is not legal")))
+    );
+    expect_that!(
+      replay(&[(0, 0), (1, 1), (0, 1), (2, 2), (0, 2), (2, 1)]),
+      err(displays_as(eq(
+        "Malformed move: Move 6 (3,2) was made after the game ended"
+      )))
+    );
+  }
+
+  #[gtest]
+  fn test_play_human_vs_bot() {
+    // Alice leaves 2 sticks, so the bot takes both and wins.
+    let human = HumanTermPlayer::with_input("Alice".to_owned(), NimPlayer, Cursor::new("2\n"));
+    let bot = BotPlayer::new("Bot".to_owned(), MinimaxSolver::new(), 10);
+    let mut output = Vec::new();
+    let result = TermInterface::with_io(Nim::new(4), human, bot, &mut output, Cursor::new(""))
+      .with_pause_at_end(false)
+      .play();
+
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player2))));
+    expect_that!(
+      String::from_utf8(output).unwrap(),
+      ends_with("Moves:\n  1. Alice: take 2\n  2. Bot: take 2\nBot wins!\n")
+    );
+  }
+
+  #[gtest]
+  fn test_play_input_ended() {
+    // After rejecting the illegal move, the script runs out, which ends the
+    // game instead of reading empty moves forever.
+    let player1 = HumanTermPlayer::with_input("Alice".to_owned(), NimPlayer, Cursor::new("5\n"));
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), NimPlayer, Cursor::new(""));
+    let mut output = Vec::new();
+    let result =
+      TermInterface::with_io(Nim::new(3), player1, player2, &mut output, Cursor::new(""))
+        .with_plain_output(true)
+        .with_pause_at_end(false)
+        .play();
+
+    expect_that!(
+      result,
+      err(matches_pattern!(GameInterfaceError::IoError(eq(
+        "Input ended"
+      ))))
+    );
+    expect_eq!(
+      String::from_utf8(output).unwrap(),
+      format!(
+        "Sticks left: 3\n\
+         How many sticks would you like to take? 1 or 2\n\
+         Malformed move: Can't take more than 2 sticks per turn\n\
+         {}\n",
+        "-".repeat(20)
+      )
+    );
+  }
+
+  #[gtest]
+  fn test_play_plain_output() {
+    let player1 = HumanTermPlayer::with_input("Alice".to_owned(), NimPlayer, Cursor::new("1\n"));
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), NimPlayer, Cursor::new("2\n"));
+    let mut output = Vec::new();
+    let result =
+      TermInterface::with_io(Nim::new(3), player1, player2, &mut output, Cursor::new(""))
+        .with_plain_output(true)
+        .with_pause_at_end(false)
+        .play();
+
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player2))));
+    let separator = "-".repeat(20);
+    expect_eq!(
+      String::from_utf8(output).unwrap(),
+      format!(
+        "Sticks left: 3\n\
+         How many sticks would you like to take? 1 or 2\n\
+         {separator}\n\
+         Sticks left: 2\n\
+         How many sticks would you like to take? 1 or 2\n\
+         {separator}\n\
+         Sticks left: 0\n\
+         Moves:\n  \
+         1. Alice: take 1\n  \
+         2. Bob: take 2\n\
+         Bob wins!\n"
+      )
+    );
+  }
+
+  #[gtest]
+  fn test_play_without_pause() {
+    let player1 = HumanTermPlayer::with_input("Alice".to_owned(), NimPlayer, Cursor::new("1\n"));
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), NimPlayer, Cursor::new("2\n"));
+    let mut output = Vec::new();
+    let mut input = Cursor::new("\n");
+    let result = TermInterface::with_io(Nim::new(3), player1, player2, &mut output, &mut input)
+      .with_pause_at_end(false)
+      .play();
+
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player2))));
+    let output = String::from_utf8(output).unwrap();
+    expect_that!(output, contains_substring("Sticks left: 0"));
+    expect_that!(output, ends_with("Bob wins!\n"));
+    // The final pause would have consumed the input.
+    expect_eq!(input.position(), 0);
+  }
+
+  #[gtest]
+  fn test_hint() {
+    // Taking 1 of 7 sticks leaves a multiple of 3, which loses for Bob.
+    let player1 =
+      HumanTermPlayer::with_input("Alice".to_owned(), NimPlayer, Cursor::new("hint\n1\n"));
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), NimPlayer, Cursor::new("q\n"));
+    let mut output = Vec::new();
+    let result =
+      TermInterface::with_io(Nim::new(7), player1, player2, &mut output, Cursor::new(""))
+        .with_hint_solver(MinimaxSolver::new(), 7)
+        .play();
+
+    expect_that!(result, err(matches_pattern!(GameInterfaceError::Quit)));
+    let output = String::from_utf8(output).unwrap();
+    expect_that!(output, contains_substring("Hint: take 1"));
+    // The hint doesn't make the move for Alice, so the board is redrawn
+    // unchanged before she moves.
+    expect_eq!(output.matches("Sticks left: 7").count(), 2);
+    expect_that!(output, contains_substring("Sticks left: 6"));
+  }
+
+  #[gtest]
+  fn test_hint_disabled() {
+    let player1 =
+      HumanTermPlayer::with_input("Alice".to_owned(), NimPlayer, Cursor::new("hint\n1\n"));
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), NimPlayer, Cursor::new(""));
+    let mut output = Vec::new();
+    let result =
+      TermInterface::with_io(Nim::new(1), player1, player2, &mut output, Cursor::new("")).play();
+
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player1))));
+    expect_that!(
+      String::from_utf8(output).unwrap(),
+      contains_substring("Hints aren't enabled")
+    );
+  }
+
+  #[gtest]
+  fn test_save_and_load() {
+    let path = env::temp_dir().join(format!("abstract_game_save_{}.txt", std::process::id()));
+    let save_command = format!("save {}\n", path.display());
+
+    // Bob saves after Alice's first move, then quits.
+    let player1 = HumanTermPlayer::with_input("Alice".to_owned(), NimPlayer, Cursor::new("2\n"));
+    let player2 = HumanTermPlayer::with_input(
+      "Bob".to_owned(),
+      NimPlayer,
+      Cursor::new(format!("{save_command}q\n")),
+    );
+    let mut output = Vec::new();
+    let result = TermInterface::with_io(
+      Nim::with_max_take(9, 3),
+      player1,
+      player2,
+      &mut output,
+      Cursor::new(""),
+    )
+    .with_saving()
+    .play();
+    expect_that!(result, err(matches_pattern!(GameInterfaceError::Quit)));
+    expect_that!(
+      String::from_utf8(output).unwrap(),
+      contains_substring("Saved the game")
+    );
+
+    let game =
+      TermInterface::<Nim, HumanTermPlayer<NimPlayer>, HumanTermPlayer<NimPlayer>>::load_game(
+        &path,
+      );
+    fs::remove_file(&path).unwrap();
+    expect_that!(game, ok(eq(&Nim::with_max_take(9, 3).with_move(2))));
+    expect_that!(
+      game.map(|game| game.current_player()),
+      ok(eq(&GamePlayer::Player2))
+    );
+  }
+
+  #[gtest]
+  fn test_save_unsupported() {
+    let player1 = HumanTermPlayer::with_input(
+      "Alice".to_owned(),
+      NimPlayer,
+      Cursor::new("save unused.txt\n1\n"),
+    );
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), NimPlayer, Cursor::new(""));
+    let mut output = Vec::new();
+    let result =
+      TermInterface::with_io(Nim::new(1), player1, player2, &mut output, Cursor::new("")).play();
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player1))));
+    expect_that!(
+      String::from_utf8(output).unwrap(),
+      contains_substring("Saving isn't supported")
+    );
+  }
+
+  #[gtest]
+  fn test_play_prints_move_log() {
+    // Alice takes back Bob's first move, so it isn't logged.
+    let player1 = HumanTermPlayer::with_input(
+      "Alice".to_owned(),
+      TicTacToePlayer,
+      Cursor::new("1,1\nu\n1,2\n1,3\n"),
+    );
+    let player2 = HumanTermPlayer::with_input(
+      "Bob".to_owned(),
+      TicTacToePlayer,
+      Cursor::new("2,2\n2,1\n2,2\n"),
+    );
+    let mut output = Vec::new();
+    TermInterface::with_io(
+      TicTacToe::new(),
+      player1,
+      player2,
+      &mut output,
+      Cursor::new("\n"),
+    )
+    .play()
+    .unwrap();
+
+    expect_that!(
+      String::from_utf8(output).unwrap(),
+      ends_with(
+        "Moves:\n  \
+           1. Alice: 1,1\n  \
+           2. Bob: 2,1\n  \
+           3. Alice: 1,2\n  \
+           4. Bob: 2,2\n  \
+           5. Alice: 1,3\n\
+         Alice wins!\n"
+      )
+    );
+  }
+
+  #[gtest]
+  fn test_play_undo() {
+    // Bob takes back Alice's first move, so she can play it again.
+    let player1 = HumanTermPlayer::with_input(
+      "Alice".to_owned(),
+      TicTacToePlayer,
+      Cursor::new("1,1\n1,1\n1,2\n1,3\n"),
+    );
+    let player2 = HumanTermPlayer::with_input(
+      "Bob".to_owned(),
+      TicTacToePlayer,
+      Cursor::new("u\n2,1\n2,2\n"),
+    );
+    let mut output = Vec::new();
+    let result = TermInterface::with_io(
+      TicTacToe::new(),
+      player1,
+      player2,
+      &mut output,
+      Cursor::new("\n"),
+    )
+    .play();
+
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player1))));
+    let output = String::from_utf8(output).unwrap();
+    expect_that!(output, not(contains_substring("already occupied")));
+
+    // The empty board is redrawn after the undo.
+    let first_move = output.find("...\n...\nX..\n").unwrap();
+    expect_that!(output[first_move..], contains_substring("...\n...\n...\n"));
+  }
+
+  #[gtest]
+  fn test_play_redo() {
+    // Alice has nothing to redo at first. After Bob takes back her move, she
+    // redoes it.
+    let player1 =
+      HumanTermPlayer::with_input("Alice".to_owned(), NimPlayer, Cursor::new("r\n1\nr\n"));
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), NimPlayer, Cursor::new("u\n2\n"));
+    let mut output = Vec::new();
+    let result =
+      TermInterface::with_io(Nim::new(3), player1, player2, &mut output, Cursor::new(""))
+        .with_pause_at_end(false)
+        .play();
+
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player2))));
+    let output = String::from_utf8(output).unwrap();
+    expect_that!(output, contains_substring("There are no moves to redo!"));
+    expect_that!(
+      output,
+      contains_substring("Moves:\n  1. Alice: take 1\n  2. Bob: take 2\n")
+    );
+  }
+
+  #[gtest]
+  fn test_play_undo_at_start() {
+    let player1 = HumanTermPlayer::with_input(
+      "Alice".to_owned(),
+      TicTacToePlayer,
+      Cursor::new("u\n1,1\n1,2\n1,3\n"),
+    );
+    let player2 =
+      HumanTermPlayer::with_input("Bob".to_owned(), TicTacToePlayer, Cursor::new("2,1\n2,2\n"));
+    let mut output = Vec::new();
+    let result = TermInterface::with_io(
+      TicTacToe::new(),
+      player1,
+      player2,
+      &mut output,
+      Cursor::new("\n"),
+    )
+    .play();
+
+    expect_that!(result, ok(eq(&GameResult::Win(GamePlayer::Player1))));
+    expect_that!(
+      String::from_utf8(output).unwrap(),
+      contains_substring("There are no moves to undo!")
+    );
+  }
+
+  #[gtest]
+  fn test_edit() {
+    let player1 = HumanTermPlayer::with_input("Alice".to_owned(), TicTacToePlayer, Cursor::new(""));
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), TicTacToePlayer, Cursor::new(""));
+    let mut output = Vec::new();
+    let mut interface = TermInterface::with_io(
+      TicTacToe::new(),
+      player1,
+      player2,
+      &mut output,
+      Cursor::new("2,2\n1,1\n1,1\n4,1\n3,3\ndone\np2\ndone\n"),
+    );
+    let game = interface.edit(TicTacToe::new());
+    drop(interface);
+
+    expect_that!(
+      game,
+      ok(eq(&TicTacToe::from_notation("..X/.X./O..").unwrap()))
+    );
+    let output = String::from_utf8(output).unwrap();
+    expect_that!(output, contains_substring("Unrecognized command"));
+    expect_that!(output, contains_substring("can't be reached"));
+  }
+
+  #[gtest]
+  fn test_edit_input_ends() {
+    let player1 = HumanTermPlayer::with_input("Alice".to_owned(), TicTacToePlayer, Cursor::new(""));
+    let player2 = HumanTermPlayer::with_input("Bob".to_owned(), TicTacToePlayer, Cursor::new(""));
+    let mut interface = TermInterface::with_io(
+      TicTacToe::new(),
+      player1,
+      player2,
+      Vec::new(),
+      Cursor::new("2,2\n"),
+    );
+    expect_that!(interface.edit(TicTacToe::new()), err(anything()));
   }
 }
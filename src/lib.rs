@@ -4,11 +4,14 @@ pub mod error;
 mod game;
 pub mod human_players;
 pub mod interactive;
+mod move_limited;
 mod score;
 mod solver;
+pub mod solvers;
 pub mod test_games;
 pub mod test_util;
 
 pub use game::*;
+pub use move_limited::*;
 pub use score::*;
 pub use solver::*;
@@ -0,0 +1,134 @@
+use std::fmt::Display;
+
+use rand::Rng;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+
+/// A wrapper around a game which declares the game a tie once a fixed number
+/// of moves have been made, unless the wrapped game finished on its own. This
+/// makes any game finite, which is useful for exhaustive solving.
+#[derive(Clone, Debug)]
+pub struct MoveLimited<G> {
+  game: G,
+  moves_made: u32,
+  move_limit: u32,
+}
+
+impl<G> MoveLimited<G> {
+  pub fn new(game: G, move_limit: u32) -> Self {
+    Self { game, moves_made: 0, move_limit }
+  }
+
+  pub fn game(&self) -> &G {
+    &self.game
+  }
+
+  pub fn moves_made(&self) -> u32 {
+    self.moves_made
+  }
+
+  pub fn move_limit(&self) -> u32 {
+    self.move_limit
+  }
+
+  fn limit_reached(&self) -> bool {
+    self.moves_made >= self.move_limit
+  }
+}
+
+pub struct MoveLimitedMoveGen<G: Game> {
+  /// The wrapped game's move generator, or `None` if the move limit has been
+  /// reached.
+  move_gen: Option<G::MoveGenerator>,
+}
+
+impl<G: Game> GameMoveIterator for MoveLimitedMoveGen<G> {
+  type Game = MoveLimited<G>;
+
+  fn next(&mut self, game: &MoveLimited<G>) -> Option<G::Move> {
+    self.move_gen.as_mut()?.next(&game.game)
+  }
+}
+
+impl<G: Game> Game for MoveLimited<G> {
+  type Move = G::Move;
+  type MoveGenerator = MoveLimitedMoveGen<G>;
+
+  const MAX_MOVES: usize = G::MAX_MOVES;
+
+  fn move_generator(&self) -> MoveLimitedMoveGen<G> {
+    MoveLimitedMoveGen {
+      move_gen: (!self.limit_reached()).then(|| self.game.move_generator()),
+    }
+  }
+
+  fn make_move(&mut self, m: G::Move) {
+    debug_assert!(!self.limit_reached());
+    self.game.make_move(m);
+    self.moves_made += 1;
+  }
+
+  fn resolve_chance(&mut self, rng: &mut impl Rng) {
+    self.game.resolve_chance(rng);
+  }
+
+  fn current_player(&self) -> GamePlayer {
+    self.game.current_player()
+  }
+
+  fn finished(&self) -> GameResult {
+    match self.game.finished() {
+      GameResult::NotFinished if self.limit_reached() => GameResult::Tie,
+      result => result,
+    }
+  }
+}
+
+impl<G: Display> Display for MoveLimited<G> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    writeln!(f, "{}", self.game)?;
+    write!(f, "Move {} of {}", self.moves_made, self.move_limit)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+
+  use crate::{test_games::Nim, Game, GamePlayer, GameResult, MoveLimited};
+
+  #[gtest]
+  fn test_tie_at_limit() {
+    let mut game = MoveLimited::new(Nim::new(10), 2);
+    game.make_move(1);
+    expect_eq!(game.finished(), GameResult::NotFinished);
+    game.make_move(1);
+    expect_eq!(game.game().sticks(), 8);
+    expect_eq!(game.finished(), GameResult::Tie);
+    expect_that!(game.each_move().collect_vec(), is_empty());
+  }
+
+  #[gtest]
+  fn test_win_on_last_move() {
+    let mut game = MoveLimited::new(Nim::new(2), 1);
+    game.make_move(2);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+  }
+
+  #[gtest]
+  fn test_matches_game_below_limit() {
+    let mut game = MoveLimited::new(Nim::new(7), 4);
+    let mut nim = Nim::new(7);
+    for m in [2, 1, 2] {
+      game.make_move(m);
+      nim.make_move(m);
+      expect_eq!(game.finished(), nim.finished());
+      expect_eq!(game.current_player(), nim.current_player());
+      expect_eq!(
+        game.each_move().collect_vec(),
+        nim.each_move().collect_vec()
+      );
+    }
+  }
+}
@@ -1,10 +1,39 @@
 use std::{
   cmp::Ordering,
+  error::Error,
   fmt::{Debug, Display},
-  hint::unreachable_unchecked,
 };
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+use crate::GamePlayer;
+
+/// Errors from constructing a `Score` with depths that can't be represented.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScoreError {
+  /// The number of moves to a win/loss was 0 or exceeded `MAX_WIN_DEPTH`.
+  WinDepthOutOfRange(u32),
+  /// The tie depth exceeded `MAX_TIE_DEPTH`.
+  TieDepthOutOfRange(u32),
+}
+
+impl Error for ScoreError {}
+
+impl Display for ScoreError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::WinDepthOutOfRange(depth) => {
+        write!(f, "Win depth {depth} is outside the representable range")
+      }
+      Self::TieDepthOutOfRange(depth) => {
+        write!(
+          f,
+          "Tie depth {depth} exceeds the maximum representable depth"
+        )
+      }
+    }
+  }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum ScoreValue {
   OtherPlayerWins,
   Tie,
@@ -59,822 +88,1645 @@ pub struct Score {
   pub(crate) data: u32,
 }
 
-impl Score {
-  const TIE_BITS: u32 = 11;
-  const TIE_SHIFT: u32 = 0;
-  const MAX_TIE_DEPTH: u32 = (1 << Self::TIE_BITS) - 1;
-  const TIE_MASK: u32 = Self::MAX_TIE_DEPTH << Self::TIE_SHIFT;
+/// A `Score` with wider turn count fields, for games whose optimal lines are
+/// deeper than `Score::MAX_WIN_DEPTH` moves.
+#[derive(Clone, Copy)]
+pub struct Score64 {
+  /// Layout:
+  /// ```text
+  ///          63         62     -    42   41 -  21  20     -     0
+  /// +------------------+----------------+--------+----------------+
+  /// | cur player wins? | turn count win | unused | turn count tie |
+  /// +------------------+----------------+--------+----------------+
+  /// ```
+  pub(crate) data: u64,
+}
+
+/// Implements the packed score operations for a score type `$score` backed by
+/// the unsigned integer `$data`, with `$signed` being the signed integer of the
+/// same width. The "cur player wins" bit must be the most significant bit.
+macro_rules! impl_packed_score {
+  (
+    $score:ident,
+    $data:ty,
+    $signed:ty,tie_bits:
+    $tie_bits:expr,unused_bits:
+    $unused_bits:expr,win_bits:
+    $win_bits:expr
+  ) => {
+    impl $score {
+      const TIE_BITS: u32 = $tie_bits;
+      const TIE_SHIFT: u32 = 0;
+      /// The maximum depth a tie can be discovered to. A tie at this depth is a
+      /// guaranteed tie.
+      pub const MAX_TIE_DEPTH: u32 = (1 << Self::TIE_BITS) - 1;
+      const TIE_MASK: $data = (Self::MAX_TIE_DEPTH as $data) << Self::TIE_SHIFT;
+
+      const UNUSED_BITS: u32 = $unused_bits;
+      const UNUSED_SHIFT: u32 = Self::TIE_SHIFT + Self::TIE_BITS;
+
+      const WIN_BITS: u32 = $win_bits;
+      const WIN_SHIFT: u32 = Self::UNUSED_SHIFT + Self::UNUSED_BITS;
+      /// The maximum number of moves to a win/loss that can be represented.
+      pub const MAX_WIN_DEPTH: u32 = (1 << Self::WIN_BITS) - 1;
+      const WIN_MASK: $data = (Self::MAX_WIN_DEPTH as $data) << Self::WIN_SHIFT;
+
+      const CUR_PLAYER_WINS_SHIFT: u32 = Self::WIN_SHIFT + Self::WIN_BITS;
+      const CUR_PLAYER_WINS_MASK: $data = 1 << Self::CUR_PLAYER_WINS_SHIFT;
+
+      const INC_WIN: $data = 1 << Self::WIN_SHIFT;
+
+      /// A `Score` that contains no information.
+      pub const NO_INFO: Self = Self::new(false, 0, 0);
+
+      /// An impossible score. Can be used to mark a game state as an ancestor of
+      /// the current tree being explored.
+      pub const ANCESTOR: Self = Self {
+        data: Self::CUR_PLAYER_WINS_MASK | Self::WIN_MASK,
+      };
+
+      /// The best possible score, an immediate win for the current player. No
+      /// other score is `better` than this.
+      pub const BEST: Self = Self::optimal_win(1);
+
+      /// The worst possible score, an immediate loss for the current player.
+      /// This is not `better` than any other score.
+      pub const WORST: Self = Self::optimal_lose(1);
+
+      const fn new(cur_player_wins: bool, turn_count_tie: u32, turn_count_win: u32) -> Self {
+        debug_assert!(turn_count_tie <= Self::MAX_TIE_DEPTH);
+        debug_assert!(turn_count_win <= Self::MAX_WIN_DEPTH);
+        debug_assert!(
+          !cur_player_wins || turn_count_win != 0,
+          "If turn_count_win == 0, then this is a tie, and cur_player_wins must be false."
+        );
+        Self {
+          data: Self::pack(
+            cur_player_wins,
+            turn_count_tie,
+            if turn_count_win == 0 {
+              Self::MAX_WIN_DEPTH
+            } else {
+              turn_count_win - 1
+            },
+          ),
+        }
+      }
 
-  const UNUSED_BITS: u32 = 9;
-  const UNUSED_SHIFT: u32 = Self::TIE_SHIFT + Self::TIE_BITS;
+      /// Constructs a score directly from its packed representation. No checks
+      /// are made that `data` is a valid score.
+      pub const fn from_raw(data: $data) -> Self {
+        Self { data }
+      }
 
-  const WIN_BITS: u32 = 11;
-  const WIN_SHIFT: u32 = Self::UNUSED_SHIFT + Self::UNUSED_BITS;
-  const MAX_WIN_DEPTH: u32 = (1 << Self::WIN_BITS) - 1;
-  const WIN_MASK: u32 = Self::MAX_WIN_DEPTH << Self::WIN_SHIFT;
+      /// Returns the packed representation of this score.
+      pub const fn raw(&self) -> $data {
+        self.data
+      }
 
-  const CUR_PLAYER_WINS_SHIFT: u32 = Self::WIN_SHIFT + Self::WIN_BITS;
-  const CUR_PLAYER_WINS_MASK: u32 = 1 << Self::CUR_PLAYER_WINS_SHIFT;
+      /// Returns true if this score contains no info.
+      pub const fn has_no_info(&self) -> bool {
+        self.data == Self::NO_INFO.data
+      }
 
-  const INC_WIN: u32 = 1 << Self::WIN_SHIFT;
+      pub const fn is_winning(&self) -> bool {
+        self.cur_player_wins()
+      }
 
-  /// A `Score` that contains no information.
-  pub const NO_INFO: Score = Score::new(false, 0, 0);
+      pub const fn is_losing(&self) -> bool {
+        !self.cur_player_wins() && !self.is_tie()
+      }
 
-  /// An impossible score. Can be used to mark a game state as an ancestor of
-  /// the current tree being explored.
-  pub const ANCESTOR: Score = Score {
-    data: Self::CUR_PLAYER_WINS_MASK | Self::WIN_MASK,
-  };
+      pub const fn is_tie(&self) -> bool {
+        (self.data & Self::WIN_MASK) == Self::WIN_MASK
+      }
+
+      pub const fn is_guaranteed_tie(&self) -> bool {
+        (self.data & Self::TIE_MASK) == Self::TIE_MASK
+      }
+
+      /// Returns true if the two scores agree on the outcome of the game, i.e.
+      /// which player wins, or that neither does.
+      pub fn same_outcome(&self, other: Self) -> bool {
+        self.score() == other.score()
+      }
+
+      /// Returns true if the outcome of the game is known, i.e. this is a win,
+      /// a loss, or a guaranteed tie.
+      pub const fn is_solved(&self) -> bool {
+        !self.is_tie() || self.is_guaranteed_tie()
+      }
+
+      /// Returns true if this score represents an ancestor, e.g. is currently being computed.
+      pub const fn is_ancestor(&self) -> bool {
+        self.data == Self::ANCESTOR.data
+      }
+
+      /// Construct a `Score` for the current player winning in `turn_count_win`
+      /// moves.
+      pub const fn win(turn_count_win: u32) -> Self {
+        debug_assert!(turn_count_win != 0);
+        Self::new(true, 0, turn_count_win)
+      }
 
-  const fn new(cur_player_wins: bool, turn_count_tie: u32, turn_count_win: u32) -> Self {
-    debug_assert!(turn_count_tie <= Self::MAX_TIE_DEPTH);
-    debug_assert!(turn_count_win < Self::MAX_WIN_DEPTH);
-    debug_assert!(
-      !cur_player_wins || turn_count_win != 0,
-      "If turn_count_win == 0, then this is a tie, and cur_player_wins must be false."
-    );
-    Self {
-      data: Self::pack(
-        cur_player_wins,
-        turn_count_tie,
-        if turn_count_win == 0 {
-          Self::MAX_WIN_DEPTH
+      /// Construct a `Score` for the current player winning in `turn_count_win`
+      /// moves, assuming there is no faster way to force a win.
+      pub const fn optimal_win(turn_count_win: u32) -> Self {
+        debug_assert!(turn_count_win != 0);
+        Self::new(true, turn_count_win - 1, turn_count_win)
+      }
+
+      /// Construct a `Score` for the current player losing in `turn_count_lose`
+      /// moves.
+      pub const fn lose(turn_count_lose: u32) -> Self {
+        debug_assert!(turn_count_lose != 0);
+        Self::new(false, 0, turn_count_lose)
+      }
+
+      /// Construct a `Score` for the current player losing in `turn_count_lose`
+      /// moves, assuming there is no faster way for the opponent to force a win.
+      pub const fn optimal_lose(turn_count_lose: u32) -> Self {
+        debug_assert!(turn_count_lose != 0);
+        Self::new(false, turn_count_lose - 1, turn_count_lose)
+      }
+
+      /// Construct a `Score` for no possible forcing win in `turn_count_tie` moves.
+      pub const fn tie(turn_count_tie: u32) -> Self {
+        Self::new(false, turn_count_tie, 0)
+      }
+
+      /// Construct a `Score` for no possible forcing win in any number of moves
+      /// into the future.
+      pub const fn guaranteed_tie() -> Self {
+        Self::tie(Self::MAX_TIE_DEPTH)
+      }
+
+      /// Like `Score::win`, but returns an error if `turn_count_win` is 0 or
+      /// exceeds `Score::MAX_WIN_DEPTH`.
+      pub fn try_win(turn_count_win: u32) -> Result<Self, ScoreError> {
+        Self::check_win_depth(turn_count_win)?;
+        Ok(Self::win(turn_count_win))
+      }
+
+      /// Like `Score::optimal_win`, but returns an error if `turn_count_win` is 0
+      /// or exceeds `Score::MAX_WIN_DEPTH`.
+      pub fn try_optimal_win(turn_count_win: u32) -> Result<Self, ScoreError> {
+        Self::check_win_depth(turn_count_win)?;
+        Ok(Self::optimal_win(turn_count_win))
+      }
+
+      /// Like `Score::lose`, but returns an error if `turn_count_lose` is 0 or
+      /// exceeds `Score::MAX_WIN_DEPTH`.
+      pub fn try_lose(turn_count_lose: u32) -> Result<Self, ScoreError> {
+        Self::check_win_depth(turn_count_lose)?;
+        Ok(Self::lose(turn_count_lose))
+      }
+
+      /// Like `Score::optimal_lose`, but returns an error if `turn_count_lose` is
+      /// 0 or exceeds `Score::MAX_WIN_DEPTH`.
+      pub fn try_optimal_lose(turn_count_lose: u32) -> Result<Self, ScoreError> {
+        Self::check_win_depth(turn_count_lose)?;
+        Ok(Self::optimal_lose(turn_count_lose))
+      }
+
+      /// Like `Score::tie`, but returns an error if `turn_count_tie` exceeds
+      /// `Score::MAX_TIE_DEPTH`.
+      pub fn try_tie(turn_count_tie: u32) -> Result<Self, ScoreError> {
+        if turn_count_tie > Self::MAX_TIE_DEPTH {
+          return Err(ScoreError::TieDepthOutOfRange(turn_count_tie));
+        }
+        Ok(Self::tie(turn_count_tie))
+      }
+
+      fn check_win_depth(turn_count_win: u32) -> Result<(), ScoreError> {
+        if (1..=Self::MAX_WIN_DEPTH).contains(&turn_count_win) {
+          Ok(())
         } else {
-          turn_count_win - 1
-        },
-      ),
-    }
-  }
+          Err(ScoreError::WinDepthOutOfRange(turn_count_win))
+        }
+      }
 
-  /// Returns true if this score contains no info.
-  pub const fn has_no_info(&self) -> bool {
-    self.data == Self::NO_INFO.data
-  }
+      pub fn score(&self) -> ScoreValue {
+        if self.is_winning() {
+          ScoreValue::CurrentPlayerWins
+        } else if self.is_tie() {
+          ScoreValue::Tie
+        } else {
+          ScoreValue::OtherPlayerWins
+        }
+      }
 
-  pub const fn is_winning(&self) -> bool {
-    self.cur_player_wins()
-  }
+      /// Returns true if this score is determined at every depth, meaning we know
+      /// exactly the minimum moves to force a win, or it's a guaranteed tie.
+      pub fn fully_determined(&self) -> bool {
+        let (_, tie, win) = Self::unpack(self.data);
+        tie == win
+      }
 
-  pub const fn is_losing(&self) -> bool {
-    !self.cur_player_wins() && !self.is_tie()
-  }
+      /// The maximum depth that this score is determined to.
+      ///
+      /// For wins and losses this is the number of moves to the win/loss, and
+      /// for ties it is the depth the tie has been discovered to. `NO_INFO` is
+      /// determined to depth 0, and `guaranteed_tie()` is determined to every
+      /// depth, so returns `u32::MAX`.
+      pub fn determined_depth(&self) -> u32 {
+        if self.is_guaranteed_tie() {
+          return u32::MAX;
+        }
+
+        // The win field holds `turn_count_win - 1`, which wraps to 0 for ties.
+        let (_, tie, win) = Self::unpack(self.data.wrapping_add(Self::INC_WIN));
+        tie.max(win)
+      }
 
-  pub const fn is_tie(&self) -> bool {
-    (self.data & Self::WIN_MASK) == Self::WIN_MASK
-  }
+      /// The score of the game given `depth` moves to play.
+      ///
+      /// Panics if the score has not been discovered to `depth`.
+      pub fn score_at_depth(&self, depth: u32) -> ScoreValue {
+        self
+          .try_score_at_depth(depth)
+          .expect("Attempted to resolve score at undiscovered depth")
+      }
 
-  pub const fn is_guaranteed_tie(&self) -> bool {
-    (self.data & Self::TIE_MASK) == Self::TIE_MASK
-  }
+      /// The score of the game given `depth` moves to play, or `None` if the
+      /// score has not been discovered to `depth`.
+      pub fn try_score_at_depth(&self, depth: u32) -> Option<ScoreValue> {
+        if depth <= self.turn_count_tie() || self.is_guaranteed_tie() {
+          Some(ScoreValue::Tie)
+        } else if self.is_tie() {
+          None
+        } else if depth >= self.turn_count_win() {
+          Some(if self.cur_player_wins() {
+            ScoreValue::CurrentPlayerWins
+          } else {
+            ScoreValue::OtherPlayerWins
+          })
+        } else {
+          None
+        }
+      }
 
-  /// Returns true if this score represents an ancestor, e.g. is currently being computed.
-  pub const fn is_ancestor(&self) -> bool {
-    self.data == Self::ANCESTOR.data
-  }
+      /// Maps this score onto a signed numeric scale, where larger values are
+      /// better for the current player, consistent with `Score::better`.
+      ///
+      /// A win in `n` moves maps to `2 * horizon - n`, a loss in `n` moves to
+      /// `n - 2 * horizon`, and ties to 0. Wins and losses are clamped to
+      /// `1..=2 * horizon` and `-2 * horizon..=-1` respectively (saturating at
+      /// the bounds of `i32`), so wins deeper than `2 * horizon` still compare
+      /// above ties.
+      pub fn to_centiscore(&self, horizon: u32) -> i32 {
+        if self.is_tie() {
+          return 0;
+        }
+
+        let max = (2 * horizon as i64).clamp(1, i32::MAX as i64);
+        let magnitude = (max - self.turn_count_win() as i64).clamp(1, max) as i32;
+        if self.cur_player_wins() {
+          magnitude
+        } else {
+          -magnitude
+        }
+      }
 
-  /// Construct a `Score` for the current player winning in `turn_count_win`
-  /// moves.
-  pub const fn win(turn_count_win: u32) -> Self {
-    debug_assert!(turn_count_win != 0);
-    Score::new(true, 0, turn_count_win)
-  }
+      /// Projects this score onto a continuous scale, so it may be compared
+      /// with heuristic evaluations. With `W = MAX_WIN_DEPTH + 1`,
+      /// `T = MAX_TIE_DEPTH + 1`, `n` the number of moves to a win/loss and `t`
+      /// the tie depth, the value is:
+      ///
+      /// - a win: `T * (2 * W - n) + t`
+      /// - a loss: `-T * (2 * W - n) + t`
+      /// - a tie: `t`
+      ///
+      /// Larger values are better for the current player, and for `Score` the
+      /// values are exactly representable, so they are ordered the same as
+      /// `Score::better`. `Score64`'s fields are too wide for an `f32`, so nearby
+      /// scores may project to the same value. The value of `ANCESTOR` is
+      /// meaningless.
+      pub fn heuristic_value(&self) -> f32 {
+        let tie_scale = Self::MAX_TIE_DEPTH as f64 + 1.;
+        let tie = self.turn_count_tie() as f64;
+        if self.is_tie() {
+          return tie as f32;
+        }
+
+        let win_range = 2. * (Self::MAX_WIN_DEPTH as f64 + 1.) - self.turn_count_win() as f64;
+        let sign = if self.cur_player_wins() { 1. } else { -1. };
+        (sign * tie_scale * win_range + tie) as f32
+      }
 
-  /// Construct a `Score` for the current player winning in `turn_count_win`
-  /// moves, assuming there is no faster way to force a win.
-  pub const fn optimal_win(turn_count_win: u32) -> Self {
-    debug_assert!(turn_count_win != 0);
-    Score::new(true, turn_count_win - 1, turn_count_win)
-  }
+      const fn cur_player_wins(&self) -> bool {
+        (self.data & Self::CUR_PLAYER_WINS_MASK) != 0
+      }
 
-  /// Construct a `Score` for the current player losing in `turn_count_lose`
-  /// moves.
-  pub const fn lose(turn_count_lose: u32) -> Self {
-    debug_assert!(turn_count_lose != 0);
-    Score::new(false, 0, turn_count_lose)
-  }
+      const fn turn_count_tie(&self) -> u32 {
+        ((self.data & Self::TIE_MASK) >> Self::TIE_SHIFT) as u32
+      }
 
-  /// Construct a `Score` for the current player losing in `turn_count_lose`
-  /// moves, assuming there is no faster way for the opponent to force a win.
-  pub const fn optimal_lose(turn_count_lose: u32) -> Self {
-    debug_assert!(turn_count_lose != 0);
-    Score::new(false, turn_count_lose - 1, turn_count_lose)
-  }
+      const fn turn_count_win(&self) -> u32 {
+        ((self.data.wrapping_add(Self::INC_WIN) & Self::WIN_MASK) >> Self::WIN_SHIFT) as u32
+      }
 
-  /// Construct a `Score` for no possible forcing win in `turn_count_tie` moves.
-  pub const fn tie(turn_count_tie: u32) -> Self {
-    Score::new(false, turn_count_tie, 0)
-  }
+      /// Returns this score as seen by the other player at the same game state,
+      /// i.e. a win for the current player becomes a loss in the same number of
+      /// moves. Ties are unchanged.
+      pub const fn flip_perspective(&self) -> Self {
+        Self {
+          data: self.data ^ (!self.is_tie() as $data * Self::CUR_PLAYER_WINS_MASK),
+        }
+      }
 
-  /// Construct a `Score` for no possible forcing win in any number of moves
-  /// into the future.
-  pub const fn guaranteed_tie() -> Self {
-    Score::tie(Self::MAX_TIE_DEPTH)
-  }
+      /// Returns this score, computed from the perspective of `current`, the
+      /// player to move, as seen by `viewer`. This lets a spectator view show
+      /// scores from one player's perspective regardless of whose turn it is.
+      pub fn as_seen_by(&self, current: GamePlayer, viewer: GamePlayer) -> Self {
+        if current == viewer {
+          *self
+        } else {
+          self.flip_perspective()
+        }
+      }
 
-  pub fn score(&self) -> ScoreValue {
-    if self.is_winning() {
-      ScoreValue::CurrentPlayerWins
-    } else if self.is_tie() {
-      ScoreValue::Tie
-    } else {
-      ScoreValue::OtherPlayerWins
-    }
-  }
+      /// Transforms a score at a given state of the game to how that score would
+      /// appear from the perspective of a game state one step before it.
+      ///
+      /// For example, if a winning move for one player has been found in n steps,
+      /// then it is turned into a winning move for the other player in n + 1
+      /// steps.
+      pub fn backstep(&self) -> Self {
+        self.debug_validate();
+        debug_assert!(self.is_tie() || self.turn_count_win() < Self::MAX_WIN_DEPTH);
+        let to_add = (!self.is_tie() as $data * (Self::INC_WIN | Self::CUR_PLAYER_WINS_MASK))
+          + (!self.is_guaranteed_tie() as $data * (1 << Self::TIE_SHIFT));
+        Self { data: self.data.wrapping_add(to_add) }.debug_validate()
+      }
 
-  /// Returns true if this score is determined at every depth, meaning we know
-  /// exactly the minimum moves to force a win, or it's a guaranteed tie.
-  pub fn fully_determined(&self) -> bool {
-    let (_, tie, win) = Self::unpack(self.data);
-    tie == win
-  }
+      /// Transforms a score at a given state of the game to how that score would
+      /// appear from the perspective of a game state one step after it.
+      ///
+      /// For example, if a winning move for one player has been found in n steps,
+      /// then it is turned into a winning move for the other player in n - 1
+      /// steps.
+      pub fn forwardstep(&self) -> Self {
+        self.debug_validate();
+        let (_, tie_bits, win_bits) = Self::unpack_unshifted(self.data);
+        let swap_player_turn = !self.is_tie();
+        let deduct_winning_turns = swap_player_turn && win_bits != 0;
+        let deduct_tied_turns = !self.is_guaranteed_tie() && tie_bits != 0;
+
+        Self {
+          data: self.data.wrapping_sub(
+            (swap_player_turn as $data * Self::CUR_PLAYER_WINS_MASK)
+              + (deduct_winning_turns as $data * Self::INC_WIN)
+              + (deduct_tied_turns as $data * (1 << Self::TIE_SHIFT)),
+          ),
+        }
+        .debug_validate()
+      }
 
-  /// The maximum depth that this score is determined to.
-  pub fn determined_depth(&self) -> u32 {
-    let (_, tie, win) = Self::unpack(self.data + Self::INC_WIN);
-    tie.max(win)
-  }
+      /// Like `backstep`, but for a move made by `mover` after which `next` is to
+      /// move. In games with extra turns, like Dots and Boxes, `mover` may move
+      /// again, in which case the score keeps its perspective and only gains a
+      /// move.
+      pub fn backstep_between(&self, mover: GamePlayer, next: GamePlayer) -> Self {
+        self.backstep().as_seen_by(next.opposite(), mover)
+      }
 
-  /// The score of the game given `depth` moves to play.
-  pub fn score_at_depth(&self, depth: u32) -> ScoreValue {
-    if depth <= self.turn_count_tie() {
-      ScoreValue::Tie
-    } else if depth >= self.turn_count_win() {
-      if self.cur_player_wins() {
-        ScoreValue::CurrentPlayerWins
-      } else {
-        ScoreValue::OtherPlayerWins
-      }
-    } else {
-      debug_assert!(false, "Attempted to resolve score at undiscovered depth");
-      unsafe { unreachable_unchecked() }
-    }
-  }
+      /// Like `forwardstep`, but for a move made by `mover` after which `next`
+      /// is to move. This is the inverse of `backstep_between`.
+      pub fn forwardstep_between(&self, mover: GamePlayer, next: GamePlayer) -> Self {
+        self.forwardstep().as_seen_by(mover.opposite(), next)
+      }
 
-  const fn cur_player_wins(&self) -> bool {
-    (self.data & Self::CUR_PLAYER_WINS_MASK) != 0
-  }
+      /// Merges the information contained in another score into this one,
+      /// returning `Err(other)` if the scores are not `compatible`. Unlike
+      /// `merge`, this is safe to call with conflicting scores.
+      pub fn merge_checked(&self, other: Self) -> Result<Self, Self> {
+        if self.compatible(other) {
+          Ok(self.merge(other))
+        } else {
+          Err(other)
+        }
+      }
 
-  const fn turn_count_tie(&self) -> u32 {
-    (self.data & Self::TIE_MASK) >> Self::TIE_SHIFT
-  }
+      /// Merges the information contained in another score into this one. This
+      /// assumes that the scores are compatible, i.e. they don't contain
+      /// conflicting information.
+      pub fn merge(&self, other: Self) -> Self {
+        self.debug_validate();
+        other.debug_validate();
+        debug_assert!(self.compatible(other));
 
-  const fn turn_count_win(&self) -> u32 {
-    ((self.data + Self::INC_WIN) & Self::WIN_MASK) >> Self::WIN_SHIFT
-  }
+        let (cur_player_wins1, tie1, win1) = Self::unpack_unshifted(self.data);
+        let (cur_player_wins2, tie2, win2) = Self::unpack_unshifted(other.data);
 
-  /// Transforms a score at a given state of the game to how that score would
-  /// appear from the perspective of a game state one step before it.
-  ///
-  /// For example, if a winning move for one player has been found in n steps,
-  /// then it is turned into a winning move for the other player in n + 1
-  /// steps.
-  pub fn backstep(&self) -> Self {
-    debug_assert!(self.is_tie() || self.turn_count_win() < Self::MAX_WIN_DEPTH);
-    let to_add = (!self.is_tie() as u32 * (Self::INC_WIN | Self::CUR_PLAYER_WINS_MASK))
-      + (!self.is_guaranteed_tie() as u32 * (1 << Self::TIE_SHIFT));
-    Score { data: self.data.wrapping_add(to_add) }
-  }
+        let tie = tie1.max(tie2);
+        let win = win1.min(win2);
+        let cur_player_wins = cur_player_wins1 | cur_player_wins2;
 
-  /// Transforms a score at a given state of the game to how that score would
-  /// appear from the perspective of a game state one step after it.
-  ///
-  /// For example, if a winning move for one player has been found in n steps,
-  /// then it is turned into a winning move for the other player in n - 1
-  /// steps.
-  pub fn forwardstep(&self) -> Self {
-    let (_, tie_bits, win_bits) = Self::unpack_unshifted(self.data);
-    let swap_player_turn = !self.is_tie();
-    let deduct_winning_turns = swap_player_turn && win_bits != 0;
-    let deduct_tied_turns = !self.is_guaranteed_tie() && tie_bits != 0;
-
-    Self {
-      data: self.data.wrapping_sub(
-        (swap_player_turn as u32 * Self::CUR_PLAYER_WINS_MASK)
-          + (deduct_winning_turns as u32 * Self::INC_WIN)
-          + (deduct_tied_turns as u32 * (1 << Self::TIE_SHIFT)),
-      ),
-    }
-  }
+        Self { data: tie + win + cur_player_wins }.debug_validate()
+      }
 
-  /// Merges the information contained in another score into this one. This
-  /// assumes that the scores are compatible, i.e. they don't contain
-  /// conflicting information.
-  pub fn merge(&self, other: Self) -> Self {
-    debug_assert!(self.compatible(other));
+      /// Accumulates two scores which are both reachable from a particular
+      /// position. Can be used to determine the score of a position by
+      /// accumulating the backstepped scores of all children of that position.
+      pub fn accumulate(&self, other: Self) -> Self {
+        let (cur_player_wins1, tie1, win1) = Self::unpack_unshifted(self.data);
+        let (cur_player_wins2, tie2, win2) = Self::unpack_unshifted(other.data);
 
-    let (cur_player_wins1, tie1, win1) = Self::unpack_unshifted(self.data);
-    let (cur_player_wins2, tie2, win2) = Self::unpack_unshifted(other.data);
+        let invert_tie1 =
+          ((self.data + Self::INC_WIN) as $signed >> (<$signed>::BITS - 1)) as $data;
+        let invert_tie2 =
+          ((other.data + Self::INC_WIN) as $signed >> (<$signed>::BITS - 1)) as $data;
+        let tie1 = tie1 ^ invert_tie1;
+        let tie2 = tie2 ^ invert_tie2;
 
-    let tie = tie1.max(tie2);
-    let win = win1.min(win2);
-    let cur_player_wins = cur_player_wins1 | cur_player_wins2;
+        let invert_mask1 = Self::invert_win_mask(self.data);
+        let invert_mask2 = Self::invert_win_mask(other.data);
 
-    Score { data: tie + win + cur_player_wins }
-  }
+        let win1 = (cur_player_wins1 | win1) ^ invert_mask1;
+        let win2 = (cur_player_wins2 | win2) ^ invert_mask2;
 
-  /// Accumulates two scores which are both reachable from a particular
-  /// position. Can be used to determine the score of a position by
-  /// accumulating the backstepped scores of all children of that position.
-  pub fn accumulate(&self, other: Self) -> Self {
-    let (cur_player_wins1, tie1, win1) = Self::unpack_unshifted(self.data);
-    let (cur_player_wins2, tie2, win2) = Self::unpack_unshifted(other.data);
+        let tie = tie1.max(tie2) ^ (invert_tie1 | invert_tie2);
+        let win = win1.max(win2) ^ (invert_mask1 | invert_mask2);
 
-    let invert_tie1 = ((self.data + Self::INC_WIN) as i32 >> (i32::BITS - 1)) as u32;
-    let invert_tie2 = ((other.data + Self::INC_WIN) as i32 >> (i32::BITS - 1)) as u32;
-    let tie1 = tie1 ^ invert_tie1;
-    let tie2 = tie2 ^ invert_tie2;
+        Self { data: tie | win }
+      }
 
-    let invert_mask1 = Self::invert_win_mask(self.data);
-    let invert_mask2 = Self::invert_win_mask(other.data);
+      /// True if this score can be used in place of a search that goes
+      /// `search_depth` moves deep (i.e. this score will equal the score calculated
+      /// by a full search this deep).
+      pub fn determined(&self, search_depth: u32) -> bool {
+        let (tie_depth, win_depth) = self.determined_range();
+        search_depth > win_depth || search_depth <= tie_depth
+      }
 
-    let win1 = (cur_player_wins1 | win1) ^ invert_mask1;
-    let win2 = (cur_player_wins2 | win2) ^ invert_mask2;
+      /// The depths `(tie, win)` bounding the searches this score is
+      /// `determined` for: it is determined for a search `d` moves deep iff
+      /// `d <= tie` or `d > win`.
+      ///
+      /// `tie` is the depth the game is known to be tied through, and `win` is
+      /// one less than the number of moves to a win or loss. Ties and `NO_INFO`
+      /// know of no win, so `win` is `MAX_WIN_DEPTH`. A guaranteed tie has
+      /// `tie` at `MAX_TIE_DEPTH`, which covers every depth a search can reach.
+      pub fn determined_range(&self) -> (u32, u32) {
+        let (_, turn_count_tie, turn_count_win) = Self::unpack(self.data);
+        (turn_count_tie, turn_count_win)
+      }
 
-    let tie = tie1.max(tie2) ^ (invert_tie1 | invert_tie2);
-    let win = win1.max(win2) ^ (invert_mask1 | invert_mask2);
+      /// Returns true if the two scores don't contain conflicting information, i.e.
+      /// they are compatible. If true, the scores can be safely `Score::merge`d.
+      pub fn compatible(&self, other: Self) -> bool {
+        let tie_to_win_shift = Self::WIN_SHIFT - Self::TIE_SHIFT;
 
-    Score { data: tie | win }
-  }
+        let (cur_player_wins1, tie1, win1) = Self::unpack_unshifted(self.data);
+        let (cur_player_wins2, tie2, win2) = Self::unpack_unshifted(other.data);
 
-  /// True if this score can be used in place of a search that goes
-  /// `search_depth` moves deep (i.e. this score will equal the score calculated
-  /// by a full search this deep).
-  pub fn determined(&self, search_depth: u32) -> bool {
-    let (_, turn_count_tie, turn_count_win) = Self::unpack(self.data);
-    search_depth > turn_count_win || search_depth <= turn_count_tie
-  }
+        let agree = self.is_tie() || other.is_tie() || cur_player_wins1 == cur_player_wins2;
 
-  /// Returns true if the two scores don't contain conflicting information, i.e.
-  /// they are compatible. If true, the scores can be safely `Score::merge`d.
-  pub fn compatible(&self, other: Score) -> bool {
-    let tie_to_win_shift = Self::WIN_SHIFT - Self::TIE_SHIFT;
+        win1 >= (tie2 << tie_to_win_shift) && win2 >= (tie1 << tie_to_win_shift) && agree
+      }
 
-    let (cur_player_wins1, tie1, win1) = Self::unpack_unshifted(self.data);
-    let (cur_player_wins2, tie2, win2) = Self::unpack_unshifted(other.data);
+      /// True if this score is better than `other` for the current player.
+      pub fn better(&self, other: Self) -> bool {
+        let data1 = self.data ^ Self::invert_win_mask(self.data);
+        let data2 = other.data ^ Self::invert_win_mask(other.data);
 
-    let agree = self.is_tie() || other.is_tie() || cur_player_wins1 == cur_player_wins2;
+        data1 > data2
+      }
 
-    win1 >= (tie2 << tie_to_win_shift) && win2 >= (tie1 << tie_to_win_shift) && agree
-  }
+      /// Chooses between two scores for the same position computed by different
+      /// means (e.g. a fast refutation search and a full search), returning
+      /// the chosen score along with the tag of its source.
+      ///
+      /// If the scores are `compatible`, the one determined to a greater depth
+      /// is chosen, preferring `a` if they are determined equally deep.
+      /// Otherwise, the strictly better of the two is chosen.
+      pub fn best_informative<T>((a, a_tag): (Self, T), (b, b_tag): (Self, T)) -> (Self, T) {
+        let choose_b = if a.compatible(b) {
+          b.determined_depth() > a.determined_depth()
+        } else {
+          b.better(a)
+        };
 
-  /// True if this score is better than `other` for the current player.
-  pub fn better(&self, other: Score) -> bool {
-    let data1 = self.data ^ Self::invert_win_mask(self.data);
-    let data2 = other.data ^ Self::invert_win_mask(other.data);
+        if choose_b {
+          (b, b_tag)
+        } else {
+          (a, a_tag)
+        }
+      }
 
-    data1 > data2
-  }
+      /// Constructs a score for a game state where not all possible next moves were
+      /// explored. This sets `turn_count_tie` to 0, since we can't prove that there
+      /// is no forced win out to any depth, and `turn_count_win` to infinity if the
+      /// current player is not winning, since we don't know if there exists a
+      /// better move that would make this game a tie/win for the current player.
+      pub fn break_early(&self) -> Self {
+        let losing_mask = ((!self.data as $signed)
+          >> (Self::CUR_PLAYER_WINS_SHIFT - Self::WIN_BITS)) as $data
+          & Self::WIN_MASK;
+        let tie_mask = !Self::TIE_MASK;
+        Self {
+          data: (self.data & tie_mask) | losing_mask,
+        }
+      }
 
-  /// Constructs a score for a game state where not all possible next moves were
-  /// explored. This sets `turn_count_tie` to 0, since we can't prove that there
-  /// is no forced win out to any depth, and `turn_count_win` to infinity if the
-  /// current player is not winning, since we don't know if there exists a
-  /// better move that would make this game a tie/win for the current player.
-  pub fn break_early(&self) -> Self {
-    let losing_mask = ((!self.data as i32) >> (Self::CUR_PLAYER_WINS_SHIFT - Self::WIN_BITS))
-      as u32
-      & Self::WIN_MASK;
-    let tie_mask = !Self::TIE_MASK;
-    Score {
-      data: (self.data & tie_mask) | losing_mask,
-    }
-  }
+      /// With the `score-validate` feature enabled, panics if this score breaks
+      /// any invariant of the packed representation. Otherwise does nothing.
+      #[inline(always)]
+      fn debug_validate(self) -> Self {
+        #[cfg(feature = "score-validate")]
+        if let Some(violation) = self.invariant_violation() {
+          panic!("Invalid score {:#x}: {violation}", self.data);
+        }
+        self
+      }
 
-  /// If the current player is winning, returns a mask with the bits in
-  /// WIN_MASK set, otherwise returning 0.
-  fn invert_win_mask(data: u32) -> u32 {
-    let cpw_to_win_shift = Self::CUR_PLAYER_WINS_SHIFT - Self::WIN_SHIFT;
-    let shifted_bits = (data as i32 >> cpw_to_win_shift) as u32;
-    shifted_bits & Self::WIN_MASK
-  }
+      /// Returns a description of the first invariant this score violates, if
+      /// any.
+      #[cfg(feature = "score-validate")]
+      fn invariant_violation(&self) -> Option<&'static str> {
+        let unused_mask = (((1 as $data) << Self::UNUSED_BITS) - 1) << Self::UNUSED_SHIFT;
+        if self.is_ancestor() {
+          None
+        } else if self.data & unused_mask != 0 {
+          Some("unused bits are set")
+        } else if self.is_tie() && self.cur_player_wins() {
+          Some("tie has the current player winning")
+        } else if !self.is_tie() && self.turn_count_win() <= self.turn_count_tie() {
+          Some("win depth is not greater than tie depth")
+        } else {
+          None
+        }
+      }
 
-  const fn pack(cur_player_wins: bool, turn_count_tie: u32, turn_count_win: u32) -> u32 {
-    debug_assert!(turn_count_tie <= Self::MAX_TIE_DEPTH);
-    debug_assert!(turn_count_win <= Self::MAX_WIN_DEPTH);
+      /// If the current player is winning, returns a mask with the bits in
+      /// WIN_MASK set, otherwise returning 0.
+      fn invert_win_mask(data: $data) -> $data {
+        let cpw_to_win_shift = Self::CUR_PLAYER_WINS_SHIFT - Self::WIN_SHIFT;
+        let shifted_bits = (data as $signed >> cpw_to_win_shift) as $data;
+        shifted_bits & Self::WIN_MASK
+      }
 
-    ((cur_player_wins as u32) << Self::CUR_PLAYER_WINS_SHIFT)
-      + (turn_count_tie << Self::TIE_SHIFT)
-      + (turn_count_win << Self::WIN_SHIFT)
-  }
+      const fn pack(cur_player_wins: bool, turn_count_tie: u32, turn_count_win: u32) -> $data {
+        debug_assert!(turn_count_tie <= Self::MAX_TIE_DEPTH);
+        debug_assert!(turn_count_win <= Self::MAX_WIN_DEPTH);
 
-  const fn unpack_unshifted(data: u32) -> (u32, u32, u32) {
-    (
-      data & Self::CUR_PLAYER_WINS_MASK,
-      data & Self::TIE_MASK,
-      data & Self::WIN_MASK,
-    )
-  }
+        ((cur_player_wins as $data) << Self::CUR_PLAYER_WINS_SHIFT)
+          + ((turn_count_tie as $data) << Self::TIE_SHIFT)
+          + ((turn_count_win as $data) << Self::WIN_SHIFT)
+      }
 
-  const fn unpack(data: u32) -> (bool, u32, u32) {
-    (
-      (data & Self::CUR_PLAYER_WINS_MASK) != 0,
-      (data & Self::TIE_MASK) >> Self::TIE_SHIFT,
-      (data & Self::WIN_MASK) >> Self::WIN_SHIFT,
-    )
-  }
-}
+      const fn unpack_unshifted(data: $data) -> ($data, $data, $data) {
+        (
+          data & Self::CUR_PLAYER_WINS_MASK,
+          data & Self::TIE_MASK,
+          data & Self::WIN_MASK,
+        )
+      }
 
-impl PartialEq for Score {
-  fn eq(&self, other: &Self) -> bool {
-    self.data == other.data
-  }
-}
+      const fn unpack(data: $data) -> (bool, u32, u32) {
+        (
+          (data & Self::CUR_PLAYER_WINS_MASK) != 0,
+          ((data & Self::TIE_MASK) >> Self::TIE_SHIFT) as u32,
+          ((data & Self::WIN_MASK) >> Self::WIN_SHIFT) as u32,
+        )
+      }
+    }
 
-impl Eq for Score {}
+    impl PartialEq for $score {
+      fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+      }
+    }
 
-impl PartialOrd for Score {
-  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-    Some(self.cmp(other))
-  }
-}
+    impl Eq for $score {}
 
-impl Ord for Score {
-  fn cmp(&self, other: &Self) -> Ordering {
-    if self.better(*other) {
-      debug_assert!(!other.better(*self));
-      Ordering::Greater
-    } else if self == other {
-      debug_assert!(!other.better(*self));
-      Ordering::Equal
-    } else {
-      debug_assert!(other.better(*self));
-      Ordering::Less
+    impl PartialOrd for $score {
+      fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+      }
     }
-  }
-}
 
-impl Debug for Score {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    write!(f, "{}", self)
-  }
-}
+    impl Ord for $score {
+      fn cmp(&self, other: &Self) -> Ordering {
+        if self.better(*other) {
+          debug_assert!(!other.better(*self));
+          Ordering::Greater
+        } else if self == other {
+          debug_assert!(!other.better(*self));
+          Ordering::Equal
+        } else {
+          debug_assert!(other.better(*self));
+          Ordering::Less
+        }
+      }
+    }
 
-impl Display for Score {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    if *self == Self::ANCESTOR {
-      write!(f, "[ancestor]")
-    } else if self.is_guaranteed_tie() {
-      write!(f, "[tie:∞]")
-    } else if self.is_tie() {
-      write!(f, "[tie:{}]", self.turn_count_tie())
-    } else {
-      write!(
-        f,
-        "[tie:{},{}:{}]",
-        self.turn_count_tie(),
-        if self.cur_player_wins() { "cur" } else { "oth" },
-        self.turn_count_win(),
-      )
+    impl Debug for $score {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self)
+      }
     }
-  }
+
+    /// Displays scores compactly, e.g. `[tie:5,cur:10]`. The alternate flag
+    /// (`{:#}`) displays the score as a sentence instead.
+    impl Display for $score {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+          return self.fmt_verbose(f);
+        }
+
+        if *self == Self::ANCESTOR {
+          write!(f, "[ancestor]")
+        } else if self.is_guaranteed_tie() {
+          write!(f, "[tie:∞]")
+        } else if self.is_tie() {
+          write!(f, "[tie:{}]", self.turn_count_tie())
+        } else {
+          write!(
+            f,
+            "[tie:{},{}:{}]",
+            self.turn_count_tie(),
+            if self.cur_player_wins() { "cur" } else { "oth" },
+            self.turn_count_win(),
+          )
+        }
+      }
+    }
+
+    impl $score {
+      fn fmt_verbose(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let plural = |n: u32| if n == 1 { "" } else { "s" };
+
+        if *self == Self::ANCESTOR {
+          return write!(f, "ancestor of the current search");
+        } else if self.has_no_info() {
+          return write!(f, "no information");
+        } else if self.is_guaranteed_tie() {
+          return write!(f, "guaranteed tie");
+        }
+
+        let tie = self.turn_count_tie();
+        if self.is_tie() {
+          return write!(f, "tied through depth {tie}");
+        }
+
+        let win = self.turn_count_win();
+        write!(
+          f,
+          "{} player wins in {win} move{}",
+          if self.cur_player_wins() {
+            "current"
+          } else {
+            "other"
+          },
+          plural(win),
+        )?;
+        if tie != 0 {
+          write!(f, ", proven tied through depth {tie}")?;
+        }
+        Ok(())
+      }
+    }
+  };
 }
 
+impl_packed_score!(Score, u32, i32, tie_bits: 11, unused_bits: 9, win_bits: 11);
+impl_packed_score!(Score64, u64, i64, tie_bits: 21, unused_bits: 21, win_bits: 21);
+
 #[cfg(test)]
 mod tests {
   use crate::{Score, ScoreValue};
 
   use googletest::{gtest, prelude::*};
+  use rand::{rngs::StdRng, Rng, SeedableRng};
 
-  fn opposite_score(score: Score) -> Score {
-    if score.is_tie() || score.is_ancestor() {
-      return score;
-    }
-
-    Score::new(
-      !score.cur_player_wins(),
-      score.turn_count_tie(),
-      score.turn_count_win(),
-    )
+  #[gtest]
+  fn test_score_value_ord() {
+    expect_lt!(ScoreValue::OtherPlayerWins, ScoreValue::CurrentPlayerWins);
+    expect_lt!(ScoreValue::OtherPlayerWins, ScoreValue::Tie);
+    expect_lt!(ScoreValue::Tie, ScoreValue::CurrentPlayerWins);
   }
 
-  fn check_compatible(s1: Score, s2: Score) {
-    assert!(s1.compatible(s2), "{s1} vs {s2}");
-    assert!(s2.compatible(s1), "{s2} vs {s1}");
-
-    let opposite_s1 = opposite_score(s1);
-    let opposite_s2 = opposite_score(s2);
-    assert!(
-      opposite_s1.compatible(opposite_s2),
-      "{opposite_s1} vs {opposite_s2}"
-    );
-    assert!(
-      opposite_s2.compatible(opposite_s1),
-      "{opposite_s2} vs {opposite_s1}"
-    );
+  fn random_score<R: Rng>(rng: &mut R) -> Score {
+    match rng.random_range(0..3) {
+      0 => Score::tie(rng.random_range(0..=Score::MAX_TIE_DEPTH)),
+      category => {
+        let moves = rng.random_range(1..=Score::MAX_WIN_DEPTH);
+        let tie = Score::tie(rng.random_range(0..moves));
+        if category == 1 {
+          Score::win(moves).merge(tie)
+        } else {
+          Score::lose(moves).merge(tie)
+        }
+      }
+    }
   }
 
-  fn check_incompatible(s1: Score, s2: Score) {
-    assert!(!s1.compatible(s2), "{s1} vs {s2}");
-    assert!(!s2.compatible(s1), "{s2} vs {s1}");
-
-    let opposite_s1 = opposite_score(s1);
-    let opposite_s2 = opposite_score(s2);
-    assert!(
-      !opposite_s1.compatible(opposite_s2),
-      "{opposite_s1} vs {opposite_s2}"
-    );
-    assert!(
-      !opposite_s2.compatible(opposite_s1),
-      "{opposite_s2} vs {opposite_s1}"
-    );
-  }
+  #[gtest]
+  fn test_heuristic_value_matches_better() {
+    let boundaries = [
+      Score::NO_INFO,
+      Score::tie(1),
+      Score::tie(Score::MAX_TIE_DEPTH - 1),
+      Score::guaranteed_tie(),
+      Score::win(1),
+      Score::optimal_win(1),
+      Score::win(Score::MAX_WIN_DEPTH),
+      Score::optimal_win(Score::MAX_WIN_DEPTH),
+      Score::lose(1),
+      Score::optimal_lose(1),
+      Score::lose(Score::MAX_WIN_DEPTH),
+      Score::optimal_lose(Score::MAX_WIN_DEPTH),
+    ];
+    for a in boundaries {
+      for b in boundaries {
+        expect_eq!(
+          a.better(b),
+          a.heuristic_value() > b.heuristic_value(),
+          "{a} vs {b}"
+        );
+      }
+    }
 
-  fn check_merge_eq(s1: Score, s2: Score, expected: Score) {
-    assert_eq!(s1.merge(s2), expected, "Merging {s1} and {s2}");
-    assert_eq!(s2.merge(s1), expected, "Merging {s2} and {s1}");
-
-    let opposite_s1 = opposite_score(s1);
-    let opposite_s2 = opposite_score(s2);
-    let opposite_expected = opposite_score(expected);
-    assert_eq!(
-      opposite_s1.merge(opposite_s2),
-      opposite_expected,
-      "Merging {opposite_s1} and {opposite_s2}"
-    );
-    assert_eq!(
-      opposite_s2.merge(opposite_s1),
-      opposite_expected,
-      "Merging {opposite_s2} and {opposite_s1}"
-    );
+    let mut rng = StdRng::seed_from_u64(1264);
+    for _ in 0..10_000 {
+      let a = random_score(&mut rng);
+      let b = random_score(&mut rng);
+      expect_eq!(
+        a.better(b),
+        a.heuristic_value() > b.heuristic_value(),
+        "{a} vs {b}"
+      );
+    }
   }
 
-  fn check_accumulate(s1: Score, s2: Score, expected: Score) {
-    expect_eq!(s1.accumulate(s2), expected, "{s1}.accumulate({s2})");
-    expect_eq!(s2.accumulate(s1), expected, "{s2}.accumulate({s1})");
-  }
+  /// Instantiates the score tests for a packed score type, which is aliased to
+  /// `Score` within the generated tests.
+  macro_rules! score_tests {
+    ($score:ty) => {
+      use crate::{ScoreError, ScoreValue};
 
-  #[gtest]
-  fn test_score_value_ord() {
-    expect_lt!(ScoreValue::OtherPlayerWins, ScoreValue::CurrentPlayerWins);
-    expect_lt!(ScoreValue::OtherPlayerWins, ScoreValue::Tie);
-    expect_lt!(ScoreValue::Tie, ScoreValue::CurrentPlayerWins);
-  }
+      use googletest::{gtest, prelude::*};
 
-  #[gtest]
-  fn test_turn_count() {
-    expect_eq!(Score::win(10).turn_count_win(), 10);
-    expect_eq!(Score::lose(10).turn_count_win(), 10);
-    expect_eq!(Score::optimal_win(10).turn_count_win(), 10);
-    expect_eq!(Score::optimal_lose(10).turn_count_win(), 10);
+      type Score = $score;
 
-    expect_eq!(Score::win(10).turn_count_tie(), 0);
-    expect_eq!(Score::lose(10).turn_count_tie(), 0);
-    expect_eq!(Score::optimal_win(10).turn_count_tie(), 9);
-    expect_eq!(Score::optimal_lose(10).turn_count_tie(), 9);
+      fn opposite_score(score: Score) -> Score {
+        if score.is_tie() || score.is_ancestor() {
+          return score;
+        }
 
-    expect_eq!(Score::tie(3).turn_count_win(), 0);
-    expect_eq!(Score::tie(3).turn_count_tie(), 3);
+        Score::new(
+          !score.cur_player_wins(),
+          score.turn_count_tie(),
+          score.turn_count_win(),
+        )
+      }
 
-    expect_eq!(Score::guaranteed_tie().turn_count_win(), 0);
+      fn check_compatible(s1: Score, s2: Score) {
+        assert!(s1.compatible(s2), "{s1} vs {s2}");
+        assert!(s2.compatible(s1), "{s2} vs {s1}");
+        assert_eq!(s1.merge_checked(s2), Ok(s1.merge(s2)), "{s1} vs {s2}");
+        assert_eq!(s2.merge_checked(s1), Ok(s2.merge(s1)), "{s2} vs {s1}");
+
+        let opposite_s1 = opposite_score(s1);
+        let opposite_s2 = opposite_score(s2);
+        assert!(
+          opposite_s1.compatible(opposite_s2),
+          "{opposite_s1} vs {opposite_s2}"
+        );
+        assert!(
+          opposite_s2.compatible(opposite_s1),
+          "{opposite_s2} vs {opposite_s1}"
+        );
+      }
 
-    expect_eq!(Score::NO_INFO.turn_count_win(), 0);
-    expect_eq!(Score::NO_INFO.turn_count_tie(), 0);
-  }
+      fn check_incompatible(s1: Score, s2: Score) {
+        assert!(!s1.compatible(s2), "{s1} vs {s2}");
+        assert!(!s2.compatible(s1), "{s2} vs {s1}");
+        assert_eq!(s1.merge_checked(s2), Err(s2), "{s1} vs {s2}");
+        assert_eq!(s2.merge_checked(s1), Err(s1), "{s2} vs {s1}");
+
+        let opposite_s1 = opposite_score(s1);
+        let opposite_s2 = opposite_score(s2);
+        assert!(
+          !opposite_s1.compatible(opposite_s2),
+          "{opposite_s1} vs {opposite_s2}"
+        );
+        assert!(
+          !opposite_s2.compatible(opposite_s1),
+          "{opposite_s2} vs {opposite_s1}"
+        );
+      }
 
-  #[gtest]
-  fn test_fully_determined() {
-    expect_true!(Score::optimal_win(4).fully_determined());
-    expect_true!(Score::optimal_lose(6).fully_determined());
-    expect_false!(Score::win(3).fully_determined());
-    expect_false!(Score::lose(2).fully_determined());
-    expect_false!(Score::new(true, 3, 5).fully_determined());
-
-    expect_true!(Score::guaranteed_tie().fully_determined());
-    expect_false!(Score::tie(1).fully_determined());
-    expect_false!(Score::tie(4).fully_determined());
-
-    expect_false!(Score::NO_INFO.fully_determined());
-  }
+      fn check_merge_eq(s1: Score, s2: Score, expected: Score) {
+        assert_eq!(s1.merge(s2), expected, "Merging {s1} and {s2}");
+        assert_eq!(s2.merge(s1), expected, "Merging {s2} and {s1}");
+
+        let opposite_s1 = opposite_score(s1);
+        let opposite_s2 = opposite_score(s2);
+        let opposite_expected = opposite_score(expected);
+        assert_eq!(
+          opposite_s1.merge(opposite_s2),
+          opposite_expected,
+          "Merging {opposite_s1} and {opposite_s2}"
+        );
+        assert_eq!(
+          opposite_s2.merge(opposite_s1),
+          opposite_expected,
+          "Merging {opposite_s2} and {opposite_s1}"
+        );
+      }
 
-  #[gtest]
-  fn test_determined() {
-    expect_true!(Score::win(10).determined(10));
-    expect_false!(Score::win(10).determined(9));
-    expect_false!(Score::win(10).determined(1));
-    expect_true!(Score::win(10).determined(0));
-    expect_true!(Score::win(10).determined(100));
-
-    expect_true!(Score::lose(10).determined(10));
-    expect_false!(Score::lose(10).determined(9));
-    expect_false!(Score::lose(10).determined(1));
-    expect_true!(Score::lose(10).determined(0));
-    expect_true!(Score::lose(10).determined(100));
-
-    expect_true!(Score::optimal_win(10).determined(10));
-    expect_true!(Score::optimal_win(10).determined(9));
-    expect_true!(Score::optimal_win(10).determined(0));
-    expect_true!(Score::optimal_win(10).determined(100));
-
-    expect_true!(Score::optimal_lose(10).determined(10));
-    expect_true!(Score::optimal_lose(10).determined(9));
-    expect_true!(Score::optimal_lose(10).determined(0));
-    expect_true!(Score::optimal_lose(10).determined(100));
-
-    expect_true!(Score::guaranteed_tie().determined(0));
-    expect_true!(Score::guaranteed_tie().determined(1));
-    expect_true!(Score::guaranteed_tie().determined(10));
-
-    expect_true!(Score::tie(5).determined(5));
-    expect_true!(Score::tie(5).determined(1));
-    expect_false!(Score::tie(5).determined(6));
-    expect_false!(Score::tie(5).determined(100));
-  }
+      fn check_accumulate(s1: Score, s2: Score, expected: Score) {
+        expect_eq!(s1.accumulate(s2), expected, "{s1}.accumulate({s2})");
+        expect_eq!(s2.accumulate(s1), expected, "{s2}.accumulate({s1})");
+      }
 
-  #[test]
-  fn test_compatible() {
-    // Guaranteed tie is incompatible with anything that isn't a tie.
-    check_compatible(Score::guaranteed_tie(), Score::guaranteed_tie());
-    check_compatible(Score::guaranteed_tie(), Score::tie(10));
-    check_compatible(Score::guaranteed_tie(), Score::NO_INFO);
-
-    check_incompatible(Score::guaranteed_tie(), Score::win(1));
-    check_incompatible(Score::guaranteed_tie(), Score::lose(1));
-    check_incompatible(Score::guaranteed_tie(), Score::win(10));
-    check_incompatible(Score::guaranteed_tie(), Score::lose(10));
-
-    // Scores are compatible if they have the same winner and don't disagree on
-    // tie/win regions.
-    check_compatible(Score::new(true, 10, 20), Score::new(true, 5, 40));
-    check_compatible(Score::new(true, 5, 20), Score::new(true, 10, 40));
-    check_compatible(Score::new(true, 10, 20), Score::new(true, 10, 40));
-    check_compatible(Score::new(true, 5, 20), Score::new(true, 10, 20));
-    check_compatible(Score::win(10), Score::win(20));
-    check_compatible(Score::win(10), Score::new(true, 5, 20));
-
-    // Scores with overlapping tied/win regions are incompatible.
-    check_incompatible(Score::new(true, 0, 20), Score::new(true, 30, 40));
-    check_incompatible(Score::new(true, 0, 20), Score::new(true, 20, 40));
-
-    // Scores with different winners are always incompatible.
-    check_incompatible(Score::new(true, 0, 20), Score::new(false, 30, 40));
-    check_incompatible(Score::new(true, 0, 20), Score::new(false, 20, 40));
-    check_incompatible(Score::new(true, 0, 20), Score::new(false, 0, 40));
-    check_incompatible(Score::new(true, 0, 20), Score::new(false, 0, 20));
-  }
+      #[gtest]
+      fn test_turn_count() {
+        expect_eq!(Score::win(10).turn_count_win(), 10);
+        expect_eq!(Score::lose(10).turn_count_win(), 10);
+        expect_eq!(Score::optimal_win(10).turn_count_win(), 10);
+        expect_eq!(Score::optimal_lose(10).turn_count_win(), 10);
 
-  #[test]
-  fn test_merge() {
-    // Merging no_info with anything doesn't change the score.
-    check_merge_eq(Score::NO_INFO, Score::win(10), Score::win(10));
-    check_merge_eq(Score::NO_INFO, Score::lose(10), Score::lose(10));
-    check_merge_eq(Score::NO_INFO, Score::tie(10), Score::tie(10));
-    check_merge_eq(
-      Score::NO_INFO,
-      Score::optimal_win(10),
-      Score::optimal_win(10),
-    );
-    check_merge_eq(
-      Score::NO_INFO,
-      Score::optimal_lose(10),
-      Score::optimal_lose(10),
-    );
-
-    // Merging two wins/loses results in the smaller of the two.
-    check_merge_eq(Score::win(10), Score::win(5), Score::win(5));
-    check_merge_eq(
-      Score::new(true, 5, 40),
-      Score::new(true, 10, 20),
-      Score::new(true, 10, 20),
-    );
-    check_merge_eq(
-      Score::new(true, 5, 20),
-      Score::new(true, 10, 40),
-      Score::new(true, 10, 20),
-    );
-
-    // Merging a tie and a win results in a win
-    check_merge_eq(Score::win(10), Score::tie(5), Score::new(true, 5, 10));
-    check_merge_eq(
-      Score::new(true, 5, 20),
-      Score::tie(10),
-      Score::new(true, 10, 20),
-    );
-  }
+        expect_eq!(Score::win(10).turn_count_tie(), 0);
+        expect_eq!(Score::lose(10).turn_count_tie(), 0);
+        expect_eq!(Score::optimal_win(10).turn_count_tie(), 9);
+        expect_eq!(Score::optimal_lose(10).turn_count_tie(), 9);
 
-  #[gtest]
-  fn test_accumulate() {
-    check_accumulate(Score::NO_INFO, Score::NO_INFO, Score::NO_INFO);
-    check_accumulate(Score::win(3), Score::win(3), Score::win(3));
-    check_accumulate(Score::lose(4), Score::lose(4), Score::lose(4));
-    check_accumulate(Score::tie(5), Score::tie(5), Score::tie(5));
-    check_accumulate(
-      Score::guaranteed_tie(),
-      Score::guaranteed_tie(),
-      Score::guaranteed_tie(),
-    );
-
-    check_accumulate(Score::win(5), Score::win(3), Score::win(3));
-    check_accumulate(
-      Score::optimal_win(5),
-      Score::optimal_win(3),
-      Score::optimal_win(3),
-    );
-    check_accumulate(Score::optimal_win(5), Score::win(3), Score::win(3));
-    check_accumulate(Score::win(5), Score::optimal_win(3), Score::win(3));
-    check_accumulate(
-      Score::new(true, 1, 5),
-      Score::optimal_win(3),
-      Score::new(true, 1, 3),
-    );
-
-    check_accumulate(Score::win(5), Score::tie(3), Score::win(5));
-    check_accumulate(Score::optimal_win(5), Score::tie(3), Score::new(true, 3, 5));
-    check_accumulate(Score::win(5), Score::tie(6), Score::win(5));
-    check_accumulate(Score::optimal_win(5), Score::tie(6), Score::optimal_win(5));
-
-    check_accumulate(Score::win(6), Score::lose(3), Score::win(6));
-    check_accumulate(Score::win(6), Score::lose(8), Score::win(6));
-    check_accumulate(Score::optimal_win(6), Score::lose(3), Score::optimal_win(6));
-    check_accumulate(
-      Score::optimal_win(6),
-      Score::optimal_lose(3),
-      Score::optimal_win(6),
-    );
-    check_accumulate(
-      Score::optimal_win(6),
-      Score::optimal_lose(8),
-      Score::optimal_win(6),
-    );
-
-    check_accumulate(Score::tie(4), Score::tie(2), Score::tie(2));
-    check_accumulate(Score::tie(4), Score::guaranteed_tie(), Score::tie(4));
-
-    check_accumulate(Score::tie(5), Score::lose(3), Score::tie(5));
-    check_accumulate(Score::tie(5), Score::lose(7), Score::tie(5));
-    check_accumulate(Score::tie(5), Score::optimal_lose(3), Score::tie(5));
-    check_accumulate(Score::tie(5), Score::optimal_lose(7), Score::tie(5));
-
-    check_accumulate(Score::lose(5), Score::lose(3), Score::lose(5));
-    check_accumulate(
-      Score::optimal_lose(5),
-      Score::lose(3),
-      Score::optimal_lose(5),
-    );
-    check_accumulate(
-      Score::optimal_lose(5),
-      Score::optimal_lose(3),
-      Score::optimal_lose(5),
-    );
-    check_accumulate(
-      Score::lose(5),
-      Score::optimal_lose(3),
-      Score::new(false, 2, 5),
-    );
-  }
+        expect_eq!(Score::tie(3).turn_count_win(), 0);
+        expect_eq!(Score::tie(3).turn_count_tie(), 3);
 
-  #[gtest]
-  fn test_score() {
-    expect_eq!(Score::win(4).score(), ScoreValue::CurrentPlayerWins);
-    expect_eq!(Score::optimal_win(7).score(), ScoreValue::CurrentPlayerWins);
-    expect_eq!(Score::win(1).score(), ScoreValue::CurrentPlayerWins);
-
-    expect_eq!(Score::lose(4).score(), ScoreValue::OtherPlayerWins);
-    expect_eq!(Score::optimal_lose(7).score(), ScoreValue::OtherPlayerWins);
-    expect_eq!(Score::lose(1).score(), ScoreValue::OtherPlayerWins);
-
-    expect_eq!(Score::tie(4).score(), ScoreValue::Tie);
-    expect_eq!(Score::guaranteed_tie().score(), ScoreValue::Tie);
-    expect_eq!(Score::tie(1).score(), ScoreValue::Tie);
-    expect_eq!(Score::NO_INFO.score(), ScoreValue::Tie);
-  }
+        expect_eq!(Score::guaranteed_tie().turn_count_win(), 0);
 
-  #[gtest]
-  fn test_determined_depth() {
-    expect_eq!(Score::win(3).determined_depth(), 3);
-    expect_eq!(Score::optimal_win(3).determined_depth(), 3);
-    expect_eq!(Score::lose(3).determined_depth(), 3);
-    expect_eq!(Score::optimal_lose(3).determined_depth(), 3);
-    expect_eq!(Score::tie(3).determined_depth(), 3);
-
-    expect_eq!(Score::NO_INFO.determined_depth(), 0);
-  }
+        expect_eq!(Score::NO_INFO.turn_count_win(), 0);
+        expect_eq!(Score::NO_INFO.turn_count_tie(), 0);
+      }
 
-  #[gtest]
-  fn test_score_at_depth() {
-    expect_eq!(
-      Score::win(3).score_at_depth(3),
-      ScoreValue::CurrentPlayerWins
-    );
-    expect_eq!(
-      Score::win(3).score_at_depth(10),
-      ScoreValue::CurrentPlayerWins
-    );
-
-    expect_eq!(
-      Score::optimal_win(3).score_at_depth(3),
-      ScoreValue::CurrentPlayerWins
-    );
-    expect_eq!(
-      Score::optimal_win(3).score_at_depth(10),
-      ScoreValue::CurrentPlayerWins
-    );
-    expect_eq!(Score::optimal_win(3).score_at_depth(2), ScoreValue::Tie);
-
-    expect_eq!(
-      Score::lose(3).score_at_depth(3),
-      ScoreValue::OtherPlayerWins
-    );
-    expect_eq!(
-      Score::lose(3).score_at_depth(10),
-      ScoreValue::OtherPlayerWins
-    );
-
-    expect_eq!(
-      Score::optimal_lose(3).score_at_depth(3),
-      ScoreValue::OtherPlayerWins
-    );
-    expect_eq!(
-      Score::optimal_lose(3).score_at_depth(10),
-      ScoreValue::OtherPlayerWins
-    );
-    expect_eq!(Score::optimal_lose(3).score_at_depth(2), ScoreValue::Tie);
-
-    expect_eq!(Score::tie(3).score_at_depth(3), ScoreValue::Tie);
-    expect_eq!(Score::tie(3).score_at_depth(0), ScoreValue::Tie);
-
-    expect_eq!(Score::guaranteed_tie().score_at_depth(100), ScoreValue::Tie);
-    expect_eq!(Score::guaranteed_tie().score_at_depth(0), ScoreValue::Tie);
-
-    expect_eq!(Score::NO_INFO.score_at_depth(0), ScoreValue::Tie);
-  }
+      #[gtest]
+      fn test_try_constructors() {
+        expect_that!(
+          Score::try_win(Score::MAX_WIN_DEPTH),
+          ok(eq(Score::win(Score::MAX_WIN_DEPTH)))
+        );
+        expect_that!(
+          Score::try_win(Score::MAX_WIN_DEPTH + 1),
+          err(eq(ScoreError::WinDepthOutOfRange(Score::MAX_WIN_DEPTH + 1)))
+        );
+        expect_that!(
+          Score::try_win(0),
+          err(eq(ScoreError::WinDepthOutOfRange(0)))
+        );
+        expect_eq!(
+          Score::win(Score::MAX_WIN_DEPTH).turn_count_win(),
+          Score::MAX_WIN_DEPTH
+        );
+
+        expect_that!(
+          Score::try_lose(Score::MAX_WIN_DEPTH),
+          ok(eq(Score::lose(Score::MAX_WIN_DEPTH)))
+        );
+        expect_that!(
+          Score::try_lose(Score::MAX_WIN_DEPTH + 1),
+          err(eq(ScoreError::WinDepthOutOfRange(Score::MAX_WIN_DEPTH + 1)))
+        );
+
+        expect_that!(
+          Score::try_optimal_win(Score::MAX_WIN_DEPTH),
+          ok(eq(Score::optimal_win(Score::MAX_WIN_DEPTH)))
+        );
+        expect_that!(
+          Score::try_optimal_win(Score::MAX_WIN_DEPTH + 1),
+          err(eq(ScoreError::WinDepthOutOfRange(Score::MAX_WIN_DEPTH + 1)))
+        );
+
+        expect_that!(
+          Score::try_optimal_lose(Score::MAX_WIN_DEPTH),
+          ok(eq(Score::optimal_lose(Score::MAX_WIN_DEPTH)))
+        );
+        expect_that!(
+          Score::try_optimal_lose(Score::MAX_WIN_DEPTH + 1),
+          err(eq(ScoreError::WinDepthOutOfRange(Score::MAX_WIN_DEPTH + 1)))
+        );
+
+        expect_that!(Score::try_tie(0), ok(eq(Score::NO_INFO)));
+        expect_that!(
+          Score::try_tie(Score::MAX_TIE_DEPTH),
+          ok(eq(Score::guaranteed_tie()))
+        );
+        expect_that!(
+          Score::try_tie(Score::MAX_TIE_DEPTH + 1),
+          err(eq(ScoreError::TieDepthOutOfRange(Score::MAX_TIE_DEPTH + 1)))
+        );
+      }
 
-  #[gtest]
-  fn test_backstep() {
-    expect_eq!(Score::win(1).backstep(), Score::optimal_lose(2));
-    expect_eq!(Score::lose(1).backstep(), Score::optimal_win(2));
-    expect_eq!(Score::tie(1).backstep(), Score::tie(2));
+      #[gtest]
+      fn test_is_solved() {
+        expect_true!(Score::win(3).is_solved());
+        expect_true!(Score::lose(3).is_solved());
+        expect_true!(Score::optimal_win(3).is_solved());
+        expect_true!(Score::guaranteed_tie().is_solved());
+        expect_false!(Score::tie(3).is_solved());
+        expect_false!(Score::NO_INFO.is_solved());
+      }
 
-    expect_eq!(Score::NO_INFO.backstep(), Score::tie(1));
-    expect_eq!(Score::guaranteed_tie().backstep(), Score::guaranteed_tie());
-  }
+      #[gtest]
+      fn test_same_outcome() {
+        expect_true!(Score::win(3).same_outcome(Score::optimal_win(5)));
+        expect_true!(Score::lose(3).same_outcome(Score::lose(1)));
+        expect_true!(Score::tie(3).same_outcome(Score::guaranteed_tie()));
+        expect_false!(Score::win(3).same_outcome(Score::lose(3)));
+        expect_false!(Score::win(3).same_outcome(Score::tie(2)));
+        expect_false!(Score::lose(3).same_outcome(Score::NO_INFO));
+      }
 
-  #[gtest]
-  fn test_forwardstep() {
-    expect_eq!(Score::win(2).forwardstep(), Score::lose(1));
-    expect_eq!(Score::lose(2).forwardstep(), Score::win(1));
-
-    expect_eq!(Score::win(1).forwardstep(), Score::lose(1));
-    expect_eq!(Score::lose(1).forwardstep(), Score::win(1));
-
-    expect_eq!(Score::NO_INFO.forwardstep(), Score::NO_INFO);
-    expect_eq!(Score::tie(1).forwardstep(), Score::NO_INFO);
-    expect_eq!(
-      Score::guaranteed_tie().forwardstep(),
-      Score::guaranteed_tie()
-    );
-  }
+      #[gtest]
+      fn test_fully_determined() {
+        expect_true!(Score::optimal_win(4).fully_determined());
+        expect_true!(Score::optimal_lose(6).fully_determined());
+        expect_false!(Score::win(3).fully_determined());
+        expect_false!(Score::lose(2).fully_determined());
+        expect_false!(Score::new(true, 3, 5).fully_determined());
 
-  #[gtest]
-  fn test_better() {
-    // Winning is better than losing.
-    expect_gt!(Score::win(1), Score::lose(1));
-    expect_gt!(Score::win(100), Score::lose(1));
+        expect_true!(Score::guaranteed_tie().fully_determined());
+        expect_false!(Score::tie(1).fully_determined());
+        expect_false!(Score::tie(4).fully_determined());
+
+        expect_false!(Score::NO_INFO.fully_determined());
+      }
+
+      #[gtest]
+      fn test_determined() {
+        expect_true!(Score::win(10).determined(10));
+        expect_false!(Score::win(10).determined(9));
+        expect_false!(Score::win(10).determined(1));
+        expect_true!(Score::win(10).determined(0));
+        expect_true!(Score::win(10).determined(100));
+
+        expect_true!(Score::lose(10).determined(10));
+        expect_false!(Score::lose(10).determined(9));
+        expect_false!(Score::lose(10).determined(1));
+        expect_true!(Score::lose(10).determined(0));
+        expect_true!(Score::lose(10).determined(100));
+
+        expect_true!(Score::optimal_win(10).determined(10));
+        expect_true!(Score::optimal_win(10).determined(9));
+        expect_true!(Score::optimal_win(10).determined(0));
+        expect_true!(Score::optimal_win(10).determined(100));
+
+        expect_true!(Score::optimal_lose(10).determined(10));
+        expect_true!(Score::optimal_lose(10).determined(9));
+        expect_true!(Score::optimal_lose(10).determined(0));
+        expect_true!(Score::optimal_lose(10).determined(100));
+
+        expect_true!(Score::guaranteed_tie().determined(0));
+        expect_true!(Score::guaranteed_tie().determined(1));
+        expect_true!(Score::guaranteed_tie().determined(10));
+
+        expect_true!(Score::tie(5).determined(5));
+        expect_true!(Score::tie(5).determined(1));
+        expect_false!(Score::tie(5).determined(6));
+        expect_false!(Score::tie(5).determined(100));
+      }
+
+      #[gtest]
+      fn test_determined_range() {
+        expect_eq!(Score::win(10).determined_range(), (0, 9));
+        expect_eq!(Score::lose(10).determined_range(), (0, 9));
+        expect_eq!(Score::optimal_win(10).determined_range(), (9, 9));
+        expect_eq!(Score::optimal_lose(10).determined_range(), (9, 9));
+        expect_eq!(Score::tie(5).determined_range(), (5, Score::MAX_WIN_DEPTH));
+        expect_eq!(
+          Score::guaranteed_tie().determined_range(),
+          (Score::MAX_TIE_DEPTH, Score::MAX_WIN_DEPTH)
+        );
+        expect_eq!(Score::NO_INFO.determined_range(), (0, Score::MAX_WIN_DEPTH));
+
+        for score in [
+          Score::win(10),
+          Score::lose(10),
+          Score::optimal_win(10),
+          Score::optimal_lose(10),
+          Score::tie(5),
+          Score::guaranteed_tie(),
+          Score::NO_INFO,
+        ] {
+          let (tie, win) = score.determined_range();
+          for depth in 0..=Score::MAX_WIN_DEPTH + 1 {
+            expect_eq!(
+              score.determined(depth),
+              depth <= tie || depth > win,
+              "{score} at depth {depth}"
+            );
+          }
+        }
+      }
+
+      #[test]
+      fn test_compatible() {
+        // Guaranteed tie is incompatible with anything that isn't a tie.
+        check_compatible(Score::guaranteed_tie(), Score::guaranteed_tie());
+        check_compatible(Score::guaranteed_tie(), Score::tie(10));
+        check_compatible(Score::guaranteed_tie(), Score::NO_INFO);
+
+        check_incompatible(Score::guaranteed_tie(), Score::win(1));
+        check_incompatible(Score::guaranteed_tie(), Score::lose(1));
+        check_incompatible(Score::guaranteed_tie(), Score::win(10));
+        check_incompatible(Score::guaranteed_tie(), Score::lose(10));
+
+        // Scores are compatible if they have the same winner and don't disagree on
+        // tie/win regions.
+        check_compatible(Score::new(true, 10, 20), Score::new(true, 5, 40));
+        check_compatible(Score::new(true, 5, 20), Score::new(true, 10, 40));
+        check_compatible(Score::new(true, 10, 20), Score::new(true, 10, 40));
+        check_compatible(Score::new(true, 5, 20), Score::new(true, 10, 20));
+        check_compatible(Score::win(10), Score::win(20));
+        check_compatible(Score::win(10), Score::new(true, 5, 20));
+
+        // Scores with overlapping tied/win regions are incompatible.
+        check_incompatible(Score::new(true, 0, 20), Score::new(true, 30, 40));
+        check_incompatible(Score::new(true, 0, 20), Score::new(true, 20, 40));
+
+        // Scores with different winners are always incompatible.
+        check_incompatible(Score::new(true, 0, 20), Score::new(false, 30, 40));
+        check_incompatible(Score::new(true, 0, 20), Score::new(false, 20, 40));
+        check_incompatible(Score::new(true, 0, 20), Score::new(false, 0, 40));
+        check_incompatible(Score::new(true, 0, 20), Score::new(false, 0, 20));
+      }
+
+      #[test]
+      fn test_merge() {
+        // Merging no_info with anything doesn't change the score.
+        check_merge_eq(Score::NO_INFO, Score::win(10), Score::win(10));
+        check_merge_eq(Score::NO_INFO, Score::lose(10), Score::lose(10));
+        check_merge_eq(Score::NO_INFO, Score::tie(10), Score::tie(10));
+        check_merge_eq(
+          Score::NO_INFO,
+          Score::optimal_win(10),
+          Score::optimal_win(10),
+        );
+        check_merge_eq(
+          Score::NO_INFO,
+          Score::optimal_lose(10),
+          Score::optimal_lose(10),
+        );
+
+        // Merging two wins/loses results in the smaller of the two.
+        check_merge_eq(Score::win(10), Score::win(5), Score::win(5));
+        check_merge_eq(
+          Score::new(true, 5, 40),
+          Score::new(true, 10, 20),
+          Score::new(true, 10, 20),
+        );
+        check_merge_eq(
+          Score::new(true, 5, 20),
+          Score::new(true, 10, 40),
+          Score::new(true, 10, 20),
+        );
+
+        // Merging a tie and a win results in a win
+        check_merge_eq(Score::win(10), Score::tie(5), Score::new(true, 5, 10));
+        check_merge_eq(
+          Score::new(true, 5, 20),
+          Score::tie(10),
+          Score::new(true, 10, 20),
+        );
+      }
+
+      #[gtest]
+      fn test_accumulate() {
+        check_accumulate(Score::NO_INFO, Score::NO_INFO, Score::NO_INFO);
+        check_accumulate(Score::win(3), Score::win(3), Score::win(3));
+        check_accumulate(Score::lose(4), Score::lose(4), Score::lose(4));
+        check_accumulate(Score::tie(5), Score::tie(5), Score::tie(5));
+        check_accumulate(
+          Score::guaranteed_tie(),
+          Score::guaranteed_tie(),
+          Score::guaranteed_tie(),
+        );
+
+        check_accumulate(Score::win(5), Score::win(3), Score::win(3));
+        check_accumulate(
+          Score::optimal_win(5),
+          Score::optimal_win(3),
+          Score::optimal_win(3),
+        );
+        check_accumulate(Score::optimal_win(5), Score::win(3), Score::win(3));
+        check_accumulate(Score::win(5), Score::optimal_win(3), Score::win(3));
+        check_accumulate(
+          Score::new(true, 1, 5),
+          Score::optimal_win(3),
+          Score::new(true, 1, 3),
+        );
+
+        check_accumulate(Score::win(5), Score::tie(3), Score::win(5));
+        check_accumulate(Score::optimal_win(5), Score::tie(3), Score::new(true, 3, 5));
+        check_accumulate(Score::win(5), Score::tie(6), Score::win(5));
+        check_accumulate(Score::optimal_win(5), Score::tie(6), Score::optimal_win(5));
+
+        check_accumulate(Score::win(6), Score::lose(3), Score::win(6));
+        check_accumulate(Score::win(6), Score::lose(8), Score::win(6));
+        check_accumulate(Score::optimal_win(6), Score::lose(3), Score::optimal_win(6));
+        check_accumulate(
+          Score::optimal_win(6),
+          Score::optimal_lose(3),
+          Score::optimal_win(6),
+        );
+        check_accumulate(
+          Score::optimal_win(6),
+          Score::optimal_lose(8),
+          Score::optimal_win(6),
+        );
+
+        check_accumulate(Score::tie(4), Score::tie(2), Score::tie(2));
+        check_accumulate(Score::tie(4), Score::guaranteed_tie(), Score::tie(4));
+
+        check_accumulate(Score::tie(5), Score::lose(3), Score::tie(5));
+        check_accumulate(Score::tie(5), Score::lose(7), Score::tie(5));
+        check_accumulate(Score::tie(5), Score::optimal_lose(3), Score::tie(5));
+        check_accumulate(Score::tie(5), Score::optimal_lose(7), Score::tie(5));
+
+        check_accumulate(Score::lose(5), Score::lose(3), Score::lose(5));
+        check_accumulate(
+          Score::optimal_lose(5),
+          Score::lose(3),
+          Score::optimal_lose(5),
+        );
+        check_accumulate(
+          Score::optimal_lose(5),
+          Score::optimal_lose(3),
+          Score::optimal_lose(5),
+        );
+        check_accumulate(
+          Score::lose(5),
+          Score::optimal_lose(3),
+          Score::new(false, 2, 5),
+        );
+      }
+
+      #[gtest]
+      fn test_score() {
+        expect_eq!(Score::win(4).score(), ScoreValue::CurrentPlayerWins);
+        expect_eq!(Score::optimal_win(7).score(), ScoreValue::CurrentPlayerWins);
+        expect_eq!(Score::win(1).score(), ScoreValue::CurrentPlayerWins);
+
+        expect_eq!(Score::lose(4).score(), ScoreValue::OtherPlayerWins);
+        expect_eq!(Score::optimal_lose(7).score(), ScoreValue::OtherPlayerWins);
+        expect_eq!(Score::lose(1).score(), ScoreValue::OtherPlayerWins);
+
+        expect_eq!(Score::tie(4).score(), ScoreValue::Tie);
+        expect_eq!(Score::guaranteed_tie().score(), ScoreValue::Tie);
+        expect_eq!(Score::tie(1).score(), ScoreValue::Tie);
+        expect_eq!(Score::NO_INFO.score(), ScoreValue::Tie);
+      }
+
+      #[gtest]
+      fn test_determined_depth() {
+        expect_eq!(Score::win(3).determined_depth(), 3);
+        expect_eq!(Score::optimal_win(3).determined_depth(), 3);
+        expect_eq!(Score::lose(3).determined_depth(), 3);
+        expect_eq!(Score::optimal_lose(3).determined_depth(), 3);
+        expect_eq!(Score::tie(3).determined_depth(), 3);
+
+        expect_eq!(Score::NO_INFO.determined_depth(), 0);
+        expect_eq!(Score::guaranteed_tie().determined_depth(), u32::MAX);
+      }
+
+      #[gtest]
+      fn test_determined_depth_boundaries() {
+        let max_win = Score::MAX_WIN_DEPTH;
+        let max_tie = Score::MAX_TIE_DEPTH;
+
+        expect_eq!(Score::win(1).determined_depth(), 1);
+        expect_eq!(Score::lose(1).determined_depth(), 1);
+        expect_eq!(Score::optimal_win(1).determined_depth(), 1);
+        expect_eq!(Score::optimal_lose(1).determined_depth(), 1);
+        expect_eq!(Score::win(max_win).determined_depth(), max_win);
+        expect_eq!(Score::lose(max_win).determined_depth(), max_win);
+        expect_eq!(Score::optimal_win(max_win).determined_depth(), max_win);
+        expect_eq!(Score::optimal_lose(max_win).determined_depth(), max_win);
+
+        expect_eq!(Score::tie(1).determined_depth(), 1);
+        expect_eq!(Score::tie(max_tie - 1).determined_depth(), max_tie - 1);
+        expect_eq!(Score::tie(max_tie).determined_depth(), u32::MAX);
+        expect_eq!(
+          Score::guaranteed_tie().backstep().determined_depth(),
+          u32::MAX
+        );
+      }
+
+      #[gtest]
+      fn test_score_at_depth() {
+        expect_eq!(
+          Score::win(3).score_at_depth(3),
+          ScoreValue::CurrentPlayerWins
+        );
+        expect_eq!(
+          Score::win(3).score_at_depth(10),
+          ScoreValue::CurrentPlayerWins
+        );
+
+        expect_eq!(
+          Score::optimal_win(3).score_at_depth(3),
+          ScoreValue::CurrentPlayerWins
+        );
+        expect_eq!(
+          Score::optimal_win(3).score_at_depth(10),
+          ScoreValue::CurrentPlayerWins
+        );
+        expect_eq!(Score::optimal_win(3).score_at_depth(2), ScoreValue::Tie);
+
+        expect_eq!(
+          Score::lose(3).score_at_depth(3),
+          ScoreValue::OtherPlayerWins
+        );
+        expect_eq!(
+          Score::lose(3).score_at_depth(10),
+          ScoreValue::OtherPlayerWins
+        );
+
+        expect_eq!(
+          Score::optimal_lose(3).score_at_depth(3),
+          ScoreValue::OtherPlayerWins
+        );
+        expect_eq!(
+          Score::optimal_lose(3).score_at_depth(10),
+          ScoreValue::OtherPlayerWins
+        );
+        expect_eq!(Score::optimal_lose(3).score_at_depth(2), ScoreValue::Tie);
+
+        expect_eq!(Score::tie(3).score_at_depth(3), ScoreValue::Tie);
+        expect_eq!(Score::tie(3).score_at_depth(0), ScoreValue::Tie);
+
+        expect_eq!(Score::guaranteed_tie().score_at_depth(100), ScoreValue::Tie);
+        expect_eq!(Score::guaranteed_tie().score_at_depth(0), ScoreValue::Tie);
+
+        expect_eq!(Score::NO_INFO.score_at_depth(0), ScoreValue::Tie);
+      }
+
+      #[gtest]
+      fn test_try_score_at_depth() {
+        expect_that!(
+          Score::optimal_win(3).try_score_at_depth(2),
+          some(eq(ScoreValue::Tie))
+        );
+        expect_that!(
+          Score::optimal_win(3).try_score_at_depth(3),
+          some(eq(ScoreValue::CurrentPlayerWins))
+        );
+        expect_that!(Score::win(3).try_score_at_depth(2), none());
+        expect_that!(
+          Score::win(3).try_score_at_depth(4),
+          some(eq(ScoreValue::CurrentPlayerWins))
+        );
+        expect_that!(
+          Score::lose(3).try_score_at_depth(3),
+          some(eq(ScoreValue::OtherPlayerWins))
+        );
+
+        expect_that!(
+          Score::tie(3).try_score_at_depth(3),
+          some(eq(ScoreValue::Tie))
+        );
+        expect_that!(Score::tie(3).try_score_at_depth(4), none());
+        expect_that!(Score::NO_INFO.try_score_at_depth(1), none());
+        expect_that!(
+          Score::guaranteed_tie().try_score_at_depth(u32::MAX),
+          some(eq(ScoreValue::Tie))
+        );
+      }
+
+      #[cfg(feature = "score-validate")]
+      #[test]
+      #[should_panic(expected = "win depth is not greater than tie depth")]
+      fn test_validate_malformed_merge() {
+        let malformed = Score::from_raw(Score::pack(true, 5, 2));
+        malformed.merge(Score::NO_INFO);
+      }
+
+      #[cfg(feature = "score-validate")]
+      #[gtest]
+      fn test_validate_well_formed() {
+        for score in [
+          Score::win(3),
+          Score::lose(3),
+          Score::optimal_win(3),
+          Score::optimal_lose(3),
+          Score::tie(3),
+          Score::guaranteed_tie(),
+          Score::NO_INFO,
+        ] {
+          expect_that!(score.invariant_violation(), none());
+          expect_that!(score.backstep().invariant_violation(), none());
+          expect_eq!(Score::from_raw(score.raw()), score);
+        }
+      }
+
+      #[gtest]
+      fn test_display() {
+        expect_eq!(format!("{}", Score::win(10)), "[tie:0,cur:10]");
+        expect_eq!(
+          format!("{:#}", Score::win(10)),
+          "current player wins in 10 moves"
+        );
+
+        expect_eq!(format!("{}", Score::optimal_win(1)), "[tie:0,cur:1]");
+        expect_eq!(
+          format!("{:#}", Score::optimal_win(1)),
+          "current player wins in 1 move"
+        );
+
+        let score = Score::win(10).merge(Score::tie(5));
+        expect_eq!(format!("{score}"), "[tie:5,cur:10]");
+        expect_eq!(
+          format!("{score:#}"),
+          "current player wins in 10 moves, proven tied through depth 5"
+        );
+
+        expect_eq!(format!("{}", Score::optimal_lose(4)), "[tie:3,oth:4]");
+        expect_eq!(
+          format!("{:#}", Score::optimal_lose(4)),
+          "other player wins in 4 moves, proven tied through depth 3"
+        );
+
+        expect_eq!(format!("{}", Score::tie(6)), "[tie:6]");
+        expect_eq!(format!("{:#}", Score::tie(6)), "tied through depth 6");
+
+        expect_eq!(format!("{}", Score::guaranteed_tie()), "[tie:∞]");
+        expect_eq!(format!("{:#}", Score::guaranteed_tie()), "guaranteed tie");
+
+        expect_eq!(format!("{}", Score::NO_INFO), "[tie:0]");
+        expect_eq!(format!("{:#}", Score::NO_INFO), "no information");
+
+        expect_eq!(format!("{}", Score::ANCESTOR), "[ancestor]");
+        expect_eq!(
+          format!("{:#}", Score::ANCESTOR),
+          "ancestor of the current search"
+        );
+      }
 
-    // Winning is better than tying.
-    expect_gt!(Score::win(1), Score::tie(1));
-    expect_gt!(Score::win(1), Score::guaranteed_tie());
+      #[gtest]
+      fn test_best_and_worst() {
+        let max_win = Score::MAX_WIN_DEPTH;
+        let scores = [
+          Score::win(1),
+          Score::win(2),
+          Score::win(max_win),
+          Score::lose(1),
+          Score::lose(2),
+          Score::lose(max_win),
+          Score::optimal_win(1),
+          Score::optimal_win(2),
+          Score::optimal_win(max_win),
+          Score::optimal_lose(1),
+          Score::optimal_lose(2),
+          Score::optimal_lose(max_win),
+          Score::win(max_win).merge(Score::tie(max_win - 1)),
+          Score::tie(1),
+          Score::tie(Score::MAX_TIE_DEPTH - 1),
+          Score::guaranteed_tie(),
+          Score::NO_INFO,
+        ];
+
+        for s in scores {
+          expect_false!(Score::WORST.better(s), "{s}");
+          expect_false!(s.better(Score::BEST), "{s}");
+          if s != Score::WORST {
+            expect_true!(s.better(Score::WORST), "{s}");
+          }
+          if s != Score::BEST {
+            expect_true!(Score::BEST.better(s), "{s}");
+          }
+        }
+      }
 
-    // Winning is better than no info.
-    expect_gt!(Score::win(1), Score::NO_INFO);
+      #[gtest]
+      fn test_to_centiscore() {
+        const HORIZON: u32 = 10;
+        let centiscore = |score: Score| score.to_centiscore(HORIZON);
+
+        expect_gt!(centiscore(Score::win(1)), centiscore(Score::win(5)));
+        expect_gt!(
+          centiscore(Score::win(5)),
+          centiscore(Score::guaranteed_tie())
+        );
+        expect_gt!(
+          centiscore(Score::guaranteed_tie()),
+          centiscore(Score::lose(5))
+        );
+        expect_gt!(centiscore(Score::lose(5)), centiscore(Score::lose(1)));
+
+        expect_eq!(centiscore(Score::win(1)), 19);
+        expect_eq!(centiscore(Score::optimal_lose(1)), -19);
+        expect_eq!(centiscore(Score::tie(3)), 0);
+        expect_eq!(centiscore(Score::NO_INFO), 0);
+
+        // Wins beyond twice the horizon are clamped, but still beat ties.
+        expect_eq!(centiscore(Score::win(100)), 1);
+        expect_eq!(centiscore(Score::lose(100)), -1);
+        expect_eq!(Score::win(1).to_centiscore(u32::MAX), i32::MAX - 1);
+      }
 
-    // Winning in fewer moves is better than more moves.
-    expect_gt!(Score::win(5), Score::win(10));
+      #[gtest]
+      fn test_backstep() {
+        expect_eq!(Score::win(1).backstep(), Score::optimal_lose(2));
+        expect_eq!(Score::lose(1).backstep(), Score::optimal_win(2));
+        expect_eq!(Score::tie(1).backstep(), Score::tie(2));
 
-    // If the number of moves to a win is equal, prefer the move with a higher
-    // discovered tie depth, which has a higher chance of pruning when
-    // searching.
-    expect_gt!(Score::optimal_win(5), Score::win(5));
+        expect_eq!(Score::NO_INFO.backstep(), Score::tie(1));
+        expect_eq!(Score::guaranteed_tie().backstep(), Score::guaranteed_tie());
+      }
 
-    // Tying is better than losing.
-    expect_gt!(Score::tie(1), Score::lose(1));
-    expect_gt!(Score::tie(10), Score::lose(1));
-    expect_gt!(Score::tie(1), Score::lose(10));
+      #[gtest]
+      fn test_flip_perspective() {
+        for score in [
+          Score::win(3),
+          Score::lose(3),
+          Score::optimal_win(5),
+          Score::optimal_lose(5),
+          Score::new(true, 2, 7),
+          Score::tie(4),
+          Score::guaranteed_tie(),
+          Score::NO_INFO,
+          Score::ANCESTOR,
+        ] {
+          expect_eq!(score.flip_perspective(), opposite_score(score), "{score}");
+          expect_eq!(score.flip_perspective().flip_perspective(), score);
+        }
+
+        expect_eq!(Score::win(3).flip_perspective(), Score::lose(3));
+        expect_eq!(
+          Score::optimal_lose(4).flip_perspective(),
+          Score::optimal_win(4)
+        );
+      }
 
-    // Given two ties, prefer the one with a deeper discovered depth.
-    expect_gt!(Score::tie(10), Score::tie(5));
-    expect_gt!(Score::guaranteed_tie(), Score::tie(10));
-    expect_gt!(Score::tie(5), Score::NO_INFO);
+      #[gtest]
+      fn test_as_seen_by() {
+        use crate::GamePlayer;
+
+        let score = Score::optimal_win(3);
+        expect_eq!(
+          score.as_seen_by(GamePlayer::Player1, GamePlayer::Player1),
+          score
+        );
+        expect_eq!(
+          score.as_seen_by(GamePlayer::Player2, GamePlayer::Player2),
+          score
+        );
+        expect_eq!(
+          score.as_seen_by(GamePlayer::Player2, GamePlayer::Player1),
+          Score::optimal_lose(3)
+        );
+        expect_eq!(
+          Score::tie(4).as_seen_by(GamePlayer::Player1, GamePlayer::Player2),
+          Score::tie(4)
+        );
+      }
 
-    // Losing is worse than no info.
-    expect_gt!(Score::NO_INFO, Score::lose(10));
+      #[gtest]
+      fn test_forwardstep() {
+        expect_eq!(Score::win(2).forwardstep(), Score::lose(1));
+        expect_eq!(Score::lose(2).forwardstep(), Score::win(1));
 
-    // Given two losing scores, prefer the deeper one.
-    expect_gt!(Score::lose(10), Score::lose(5));
+        expect_eq!(Score::win(1).forwardstep(), Score::lose(1));
+        expect_eq!(Score::lose(1).forwardstep(), Score::win(1));
+
+        expect_eq!(Score::NO_INFO.forwardstep(), Score::NO_INFO);
+        expect_eq!(Score::tie(1).forwardstep(), Score::NO_INFO);
+        expect_eq!(
+          Score::guaranteed_tie().forwardstep(),
+          Score::guaranteed_tie()
+        );
+      }
+
+      #[gtest]
+      fn test_step_between() {
+        use crate::GamePlayer;
+
+        let (p1, p2) = (GamePlayer::Player1, GamePlayer::Player2);
+        for score in [
+          Score::win(3),
+          Score::optimal_lose(4),
+          Score::tie(2),
+          Score::NO_INFO,
+          Score::guaranteed_tie(),
+        ] {
+          expect_eq!(score.backstep_between(p1, p2), score.backstep());
+          expect_eq!(score.forwardstep_between(p2, p1), score.forwardstep());
+        }
+
+        // An extra turn keeps the perspective of the score.
+        expect_eq!(
+          Score::win(1).backstep_between(p1, p1),
+          Score::optimal_win(2)
+        );
+        expect_eq!(
+          Score::optimal_lose(2).backstep_between(p2, p2),
+          Score::optimal_lose(3)
+        );
+        expect_eq!(Score::tie(1).backstep_between(p1, p1), Score::tie(2));
+        expect_eq!(Score::win(3).forwardstep_between(p1, p1), Score::win(2));
+        expect_eq!(Score::BEST.forwardstep_between(p1, p1), Score::BEST);
+        expect_eq!(Score::WORST.forwardstep_between(p1, p1), Score::WORST);
+      }
 
-    // If both scores are losing to the same depth, prefer the one with a
-    // higher discovered tie depth.
-    expect_gt!(Score::optimal_lose(10), Score::lose(10));
+      #[gtest]
+      fn test_better() {
+        // Winning is better than losing.
+        expect_gt!(Score::win(1), Score::lose(1));
+        expect_gt!(Score::win(100), Score::lose(1));
+
+        // Winning is better than tying.
+        expect_gt!(Score::win(1), Score::tie(1));
+        expect_gt!(Score::win(1), Score::guaranteed_tie());
+
+        // Winning is better than no info.
+        expect_gt!(Score::win(1), Score::NO_INFO);
+
+        // Winning in fewer moves is better than more moves.
+        expect_gt!(Score::win(5), Score::win(10));
+
+        // If the number of moves to a win is equal, prefer the move with a higher
+        // discovered tie depth, which has a higher chance of pruning when
+        // searching.
+        expect_gt!(Score::optimal_win(5), Score::win(5));
+
+        // Tying is better than losing.
+        expect_gt!(Score::tie(1), Score::lose(1));
+        expect_gt!(Score::tie(10), Score::lose(1));
+        expect_gt!(Score::tie(1), Score::lose(10));
+
+        // Given two ties, prefer the one with a deeper discovered depth.
+        expect_gt!(Score::tie(10), Score::tie(5));
+        expect_gt!(Score::guaranteed_tie(), Score::tie(10));
+        expect_gt!(Score::tie(5), Score::NO_INFO);
+
+        // Losing is worse than no info.
+        expect_gt!(Score::NO_INFO, Score::lose(10));
+
+        // Given two losing scores, prefer the deeper one.
+        expect_gt!(Score::lose(10), Score::lose(5));
+
+        // If both scores are losing to the same depth, prefer the one with a
+        // higher discovered tie depth.
+        expect_gt!(Score::optimal_lose(10), Score::lose(10));
+      }
+
+      #[gtest]
+      fn test_best_informative() {
+        // A deeper-determined tie is chosen over a shallower one.
+        expect_eq!(
+          Score::best_informative((Score::tie(3), "fast"), (Score::tie(7), "full")),
+          (Score::tie(7), "full")
+        );
+        expect_eq!(
+          Score::best_informative((Score::tie(7), "full"), (Score::tie(3), "fast")),
+          (Score::tie(7), "full")
+        );
+
+        // A solved score is more informative than a compatible partial one.
+        expect_eq!(
+          Score::best_informative((Score::NO_INFO, "fast"), (Score::win(4), "full")),
+          (Score::win(4), "full")
+        );
+
+        // Equally informative scores prefer the first.
+        expect_eq!(
+          Score::best_informative((Score::tie(5), "fast"), (Score::tie(5), "full")),
+          (Score::tie(5), "fast")
+        );
+
+        // Conflicting scores choose the strictly better one.
+        expect_eq!(
+          Score::best_informative((Score::lose(3), "fast"), (Score::win(5), "full")),
+          (Score::win(5), "full")
+        );
+        expect_eq!(
+          Score::best_informative((Score::tie(6), "fast"), (Score::lose(2), "full")),
+          (Score::tie(6), "fast")
+        );
+      }
+
+      #[gtest]
+      fn test_break_early() {
+        expect_eq!(Score::win(3).break_early(), Score::win(3));
+        expect_eq!(Score::optimal_win(3).break_early(), Score::win(3));
+        expect_eq!(Score::lose(3).break_early(), Score::NO_INFO);
+        expect_eq!(Score::optimal_lose(3).break_early(), Score::NO_INFO);
+        expect_eq!(Score::tie(5).break_early(), Score::NO_INFO);
+        expect_eq!(Score::guaranteed_tie().break_early(), Score::NO_INFO);
+        expect_eq!(Score::NO_INFO.break_early(), Score::NO_INFO);
+      }
+    };
   }
 
-  #[gtest]
-  fn test_break_early() {
-    expect_eq!(Score::win(3).break_early(), Score::win(3));
-    expect_eq!(Score::optimal_win(3).break_early(), Score::win(3));
-    expect_eq!(Score::lose(3).break_early(), Score::NO_INFO);
-    expect_eq!(Score::optimal_lose(3).break_early(), Score::NO_INFO);
-    expect_eq!(Score::tie(5).break_early(), Score::NO_INFO);
-    expect_eq!(Score::guaranteed_tie().break_early(), Score::NO_INFO);
-    expect_eq!(Score::NO_INFO.break_early(), Score::NO_INFO);
+  mod score32 {
+    score_tests!(crate::Score);
+  }
+
+  mod score64 {
+    score_tests!(crate::Score64);
   }
 }
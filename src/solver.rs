@@ -1,12 +1,157 @@
-use std::iter::successors;
+use std::{
+  cmp::{Ordering, Reverse},
+  collections::{BTreeMap, HashSet},
+  fmt::Display,
+  iter::successors,
+  time::Instant,
+};
 
-use crate::{Game, GameResult, Score};
+use rand::Rng;
+
+use crate::{determined_score::DeterminedScore, Game, GamePlayer, GameResult, Score, ScoreValue};
 
 pub enum MoveLoss {
   Equivalent,
   Worse,
 }
 
+/// How a move compares to the best move in a position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoveLossKind {
+  /// The move is as good as the best move, as far as was searched.
+  Equivalent,
+  /// The move changes the outcome of the game, e.g. turning a win into a tie
+  /// or a loss.
+  SwitchedOutcome,
+  /// The move still wins, but takes longer to.
+  SlowerWin,
+  /// The move still loses, but sooner.
+  FasterLoss,
+}
+
+/// The scores of the best move and of a chosen move in a position, from the
+/// perspective of the player making the move, as returned by
+/// `Solver::move_loss_detailed`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MoveLossDetail {
+  pub best: Score,
+  pub actual: Score,
+  pub kind: MoveLossKind,
+}
+
+/// Counts of the scores of the leaves of a search, bucketed by outcome and by
+/// the depth each score is determined to.
+///
+/// A search whose horizon leaves mostly land in one bucket is getting little
+/// guidance from its evaluation.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LeafHistogram {
+  buckets: BTreeMap<(ScoreValue, u32), u64>,
+}
+
+impl LeafHistogram {
+  pub fn record(&mut self, score: Score) {
+    *self
+      .buckets
+      .entry((score.score(), score.determined_depth()))
+      .or_default() += 1;
+  }
+
+  /// The number of leaves recorded with outcome `outcome`, determined to
+  /// `depth`.
+  pub fn count(&self, outcome: ScoreValue, depth: u32) -> u64 {
+    self
+      .buckets
+      .get(&(outcome, depth))
+      .copied()
+      .unwrap_or_default()
+  }
+
+  /// The total number of leaves recorded.
+  pub fn total(&self) -> u64 {
+    self.buckets.values().sum()
+  }
+
+  /// Each non-empty bucket as `((outcome, depth), count)`, in increasing
+  /// order.
+  pub fn buckets(&self) -> impl Iterator<Item = ((ScoreValue, u32), u64)> + '_ {
+    self.buckets.iter().map(|(&bucket, &count)| (bucket, count))
+  }
+}
+
+/// Counters collected by a solver over the course of a search.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchStats {
+  /// The number of game states whose moves were searched.
+  pub nodes_expanded: u64,
+  /// The number of game states which were scored without searching their
+  /// moves, i.e. finished games and states at the search horizon.
+  pub leaves_evaluated: u64,
+  /// The number of game states whose scores were found in a cache.
+  pub cache_hits: u64,
+  /// The number of times alpha-beta pruning skipped the rest of a state's
+  /// moves.
+  pub beta_cutoffs: u64,
+  /// The depth of the deepest search that these stats were collected over.
+  pub depth: u32,
+  /// The scores of the leaves of the search, for solvers which were asked to
+  /// collect them.
+  pub leaf_histogram: Option<LeafHistogram>,
+}
+
+impl SearchStats {
+  /// The effective branching factor of a search `depth` moves deep, i.e. the
+  /// branching factor of a uniform tree with as many nodes as were visited.
+  /// Lower is better, and is a measure of how effectively a solver prunes.
+  pub fn effective_branching_factor(&self, depth: u32) -> f64 {
+    debug_assert!(depth > 0);
+    (self.nodes_expanded as f64).powf(1.0 / depth as f64)
+  }
+}
+
+impl Display for SearchStats {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{} nodes to depth {}", self.nodes_expanded, self.depth)?;
+    if self.depth > 0 {
+      write!(
+        f,
+        " (effective branching factor {:.2})",
+        self.effective_branching_factor(self.depth)
+      )?;
+    }
+    Ok(())
+  }
+}
+
+/// The result of a search, along with what the search covered.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SearchResult<M> {
+  pub score: Score,
+  pub best_move: Option<M>,
+  /// The depth the position was searched to.
+  pub searched_depth: u32,
+  /// True if the score is a `DeterminedScore`: a win or loss known to be the
+  /// fastest possible, or a tie to a known depth. Wins found without proving
+  /// there's no faster one, and `NO_INFO`, aren't exact.
+  pub exact: bool,
+  /// True if the score is the outcome of the game under perfect play: an
+  /// exact win or loss, or a guaranteed tie. Unlike `exact`, ties only known
+  /// up to the search depth aren't proven.
+  pub proven: bool,
+}
+
+impl<M> SearchResult<M> {
+  pub fn new(score: Score, best_move: Option<M>, searched_depth: u32) -> Self {
+    Self {
+      score,
+      best_move,
+      searched_depth,
+      exact: DeterminedScore::from_score(score).is_some(),
+      proven: score.fully_determined(),
+    }
+  }
+}
+
 pub trait Solver {
   type Game: Game;
 
@@ -16,30 +161,157 @@ pub trait Solver {
     depth: u32,
   ) -> (Score, Option<<Self::Game as Game>::Move>);
 
+  /// Like `best_move`, but when several moves share the best score, returns
+  /// the one which `cmp` orders first, e.g. the smallest move with `Ord::cmp`.
+  /// Moves share the best score if they reach the same outcome in the same
+  /// number of moves, so a slower win never replaces a faster one.
+  /// This makes the choice independent of the order moves are generated in,
+  /// at the cost of scoring every move separately.
+  fn best_move_with_tiebreak<F>(
+    &mut self,
+    game: &Self::Game,
+    depth: u32,
+    mut cmp: F,
+  ) -> (Score, Option<<Self::Game as Game>::Move>)
+  where
+    Self: Sized,
+    F: FnMut(&<Self::Game as Game>::Move, &<Self::Game as Game>::Move) -> Ordering,
+  {
+    let (score, best) = self.best_move(game, depth);
+    if best.is_none() {
+      return (score, None);
+    }
+
+    let tied = game
+      .each_move()
+      .filter(|&m| {
+        let move_score = self.evaluate_move(game, m, depth);
+        move_score.same_outcome(score) && move_score.determined_depth() == score.determined_depth()
+      })
+      .min_by(|m1, m2| cmp(m1, m2));
+    (score, tied.or(best))
+  }
+
+  /// Finds the best move in each of `games`, in order. By default this calls
+  /// `best_move` on each game in turn, but solvers may override it to share
+  /// work across the batch.
+  fn best_moves(
+    &mut self,
+    games: &[Self::Game],
+    depth: u32,
+  ) -> Vec<(Score, Option<<Self::Game as Game>::Move>)> {
+    games
+      .iter()
+      .map(|game| self.best_move(game, depth))
+      .collect()
+  }
+
+  /// Like `best_move`, but returns the result along with the depth searched
+  /// and whether the score is exact.
+  fn search(&mut self, game: &Self::Game, depth: u32) -> SearchResult<<Self::Game as Game>::Move> {
+    let (score, m) = self.best_move(game, depth);
+    SearchResult::new(score, m, depth)
+  }
+
+  /// Searches `game` at depths `1..=max_depth`, returning as soon as the
+  /// outcome of the game is proven, or the result of the deepest search
+  /// otherwise.
+  fn best_move_iterative(
+    &mut self,
+    game: &Self::Game,
+    max_depth: u32,
+  ) -> (Score, Option<<Self::Game as Game>::Move>) {
+    let result = iterative_search(self, game, max_depth, || false);
+    (result.score, result.best_move)
+  }
+
+  /// Searches `game` at increasing depths like `best_move_iterative`, until
+  /// either the outcome of the game is proven or `deadline` passes. The
+  /// deadline is only checked between searches, so this may overrun it by up
+  /// to the length of one search. At least one search of depth 1 is always
+  /// made.
+  fn best_move_timed(
+    &mut self,
+    game: &Self::Game,
+    deadline: Instant,
+  ) -> (Score, Option<<Self::Game as Game>::Move>) {
+    let (score, m, _) = self.best_move_bounded(game, deadline);
+    (score, m)
+  }
+
+  /// Like `best_move_timed`, but also returns whether the score is the proven
+  /// outcome of the game, as in `SearchResult::proven`, rather than only the
+  /// best found before the deadline passed.
+  fn best_move_bounded(
+    &mut self,
+    game: &Self::Game,
+    deadline: Instant,
+  ) -> (Score, Option<<Self::Game as Game>::Move>, bool) {
+    let result = iterative_search(self, game, Score::MAX_WIN_DEPTH, || {
+      Instant::now() >= deadline
+    });
+    (result.score, result.best_move, result.proven)
+  }
+
+  /// Returns the score of the game from the perspective of the current player.
+  fn evaluate(&mut self, game: &Self::Game, depth: u32) -> Score {
+    self.best_move(game, depth).0
+  }
+
+  /// Returns the score of the game from the perspective of the current player,
+  /// followed by the same score from the perspective of the other player.
+  fn evaluate_both(&mut self, game: &Self::Game, depth: u32) -> (Score, Score) {
+    let score = self.evaluate(game, depth);
+    (score, score.flip_perspective())
+  }
+
   fn move_loss(
     &mut self,
     m: <Self::Game as Game>::Move,
     game: &Self::Game,
     depth: u32,
   ) -> MoveLoss {
+    match self.move_loss_detailed(m, game, depth).kind {
+      MoveLossKind::Equivalent => MoveLoss::Equivalent,
+      _ => MoveLoss::Worse,
+    }
+  }
+
+  /// Like `move_loss`, but also returns the scores of the best move and of
+  /// `m`, and how `m` falls short of the best move, if it does.
+  fn move_loss_detailed(
+    &mut self,
+    m: <Self::Game as Game>::Move,
+    game: &Self::Game,
+    depth: u32,
+  ) -> MoveLossDetail {
     debug_assert!(!game.finished().is_finished());
-    let (cur_score, _) = self.best_move(game, depth);
-    let (move_score, _) = self.best_move(&game.with_move(m), depth - 1);
-    let move_score = move_score.backstep();
+    let (best, _) = self.best_move(game, depth);
+    let actual = self.evaluate_move(game, m, depth);
 
-    if cur_score.compatible(move_score) {
-      MoveLoss::Equivalent
+    let kind = if best.compatible(actual) {
+      MoveLossKind::Equivalent
     } else {
-      debug_assert!(cur_score.better(move_score));
-      MoveLoss::Worse
-    }
+      debug_assert!(best.better(actual));
+      if !best.same_outcome(actual) {
+        MoveLossKind::SwitchedOutcome
+      } else if best.is_winning() {
+        MoveLossKind::SlowerWin
+      } else {
+        MoveLossKind::FasterLoss
+      }
+    };
+    MoveLossDetail { best, actual, kind }
   }
 
   fn playout(
     &mut self,
     game: &Self::Game,
     depth: u32,
-  ) -> impl Iterator<Item = (Self::Game, <Self::Game as Game>::Move)> {
+  ) -> impl Iterator<Item = (Self::Game, <Self::Game as Game>::Move)>
+  where
+    Self: Sized,
+  {
     let (_, m) = self.best_move(game, depth);
     successors(m.map(|m| (game.with_move(m), m)), move |(game, _)| {
       if matches!(game.finished(), GameResult::Win(_) | GameResult::Tie) {
@@ -50,4 +322,939 @@ pub trait Solver {
       m.map(|m| (game.with_move(m), m))
     })
   }
+
+  /// Returns the score of `game` along with the principal variation, the line
+  /// of play which `best_move` follows from `game` until the game finishes or
+  /// `depth` moves have been made. If `game` is already finished, the
+  /// variation is empty.
+  fn principal_variation(
+    &mut self,
+    game: &Self::Game,
+    depth: u32,
+  ) -> (Score, Vec<<Self::Game as Game>::Move>) {
+    let (score, mut m) = self.best_move(game, depth);
+    let mut game = game.clone();
+    let mut variation = Vec::new();
+    for depth in (1..depth).rev() {
+      let Some(next_move) = m.filter(|_| !game.finished().is_finished()) else {
+        return (score, variation);
+      };
+      game.make_move(next_move);
+      variation.push(next_move);
+      m = self.best_move(&game, depth).1;
+    }
+    // The last move needs no search after it, since nothing follows it.
+    variation.extend(m.filter(|_| depth > 0 && !game.finished().is_finished()));
+    (score, variation)
+  }
+
+  /// Returns the score of making move `m` in the unfinished `game`, from the
+  /// perspective of the current player, searching the resulting position
+  /// `depth - 1` moves deep. This is the score `rank_moves` gives `m`.
+  fn evaluate_move(
+    &mut self,
+    game: &Self::Game,
+    m: <Self::Game as Game>::Move,
+    depth: u32,
+  ) -> Score {
+    move_score(self, game, m, depth)
+  }
+
+  /// Returns every move paired with its score from the perspective of the
+  /// current player, searching `depth` moves deep, sorted from best to worst.
+  /// Finished games have no moves to rank.
+  fn rank_moves(
+    &mut self,
+    game: &Self::Game,
+    depth: u32,
+  ) -> Vec<(<Self::Game as Game>::Move, Score)> {
+    if game.finished().is_finished() {
+      return Vec::new();
+    }
+
+    let mut ranked = game
+      .each_move()
+      .map(|m| (m, self.evaluate_move(game, m, depth)))
+      .collect::<Vec<_>>();
+    ranked.sort_by_key(|&(_, score)| Reverse(score));
+    ranked
+  }
+
+  /// Returns the move a practical player would make against the imperfect
+  /// opponent modeled by `opp`. In won positions this is the best move, but
+  /// otherwise, among the moves with the same outcome as the best move, it is
+  /// the one leaving the opponent the most replies which lose for them (when
+  /// searched `depth` moves deep by this solver) but which `opp` doesn't
+  /// recognize as losing. Ties go to the best move.
+  fn best_practical_move(
+    &mut self,
+    game: &Self::Game,
+    depth: u32,
+    opp: &mut impl Solver<Game = Self::Game>,
+  ) -> Option<<Self::Game as Game>::Move>
+  where
+    Self: Sized,
+  {
+    let (score, best) = self.best_move(game, depth);
+    if score.is_winning() || depth < 2 {
+      return best;
+    }
+
+    let best = best?;
+    let mut practical = (
+      best,
+      count_traps(self, opp, &game.with_move(best), depth - 1),
+    );
+    for m in game.each_move() {
+      if m == best || !move_score(self, game, m, depth).same_outcome(score) {
+        continue;
+      }
+      let m_traps = count_traps(self, opp, &game.with_move(m), depth - 1);
+      if m_traps > practical.1 {
+        practical = (m, m_traps);
+      }
+    }
+    Some(practical.0)
+  }
+
+  /// Returns every move which doesn't lose for the current player within
+  /// `depth` moves, i.e. the moves a cautious player should choose among.
+  fn non_losing_moves(&mut self, game: &Self::Game, depth: u32) -> Vec<<Self::Game as Game>::Move> {
+    game
+      .each_move()
+      .filter(|&m| !move_score(self, game, m, depth).is_losing())
+      .collect()
+  }
+
+  /// Follows the best line of play like `playout`, but stops as soon as the
+  /// outcome of the game from the current position has been proven, since the
+  /// rest of the line is a formality.
+  fn playout_until_determined(
+    &mut self,
+    game: &Self::Game,
+    depth: u32,
+  ) -> Vec<(Self::Game, <Self::Game as Game>::Move)> {
+    let mut line = Vec::new();
+    let mut game = game.clone();
+    while !game.finished().is_finished() {
+      let (score, m) = self.best_move(&game, depth);
+      let Some(m) = m.filter(|_| !score.is_solved()) else {
+        break;
+      };
+      game.make_move(m);
+      line.push((game.clone(), m));
+    }
+    line
+  }
+
+  /// Returns the outcome of the game, along with the number of nodes in the
+  /// proof tree establishing it: the smallest subtree which includes only the
+  /// chosen move wherever a player is proving they can achieve at least the
+  /// outcome, and every move wherever a player is proving they can do no
+  /// better. Proving a tie takes both.
+  ///
+  /// Terminal positions and positions at the search horizon count as leaves.
+  fn prove(&mut self, game: &Self::Game, depth: u32) -> (DeterminedScore, u64) {
+    let score = self.evaluate(game, depth);
+    let determined = DeterminedScore::from_score(score)
+      .unwrap_or_else(|| panic!("Expected a determined score, got {score}"));
+    let proof_nodes = if score.is_winning() {
+      proof_nodes(self, game, score, depth, ProofBound::Lower)
+    } else if score.is_losing() {
+      proof_nodes(self, game, score, depth, ProofBound::Upper)
+    } else {
+      // Both proofs share the root.
+      proof_nodes(self, game, score, depth, ProofBound::Lower)
+        + proof_nodes(self, game, score, depth, ProofBound::Upper)
+        - 1
+    };
+    (determined, proof_nodes)
+  }
+}
+
+/// The side of a position's score that a proof tree establishes, from the
+/// perspective of the current player.
+#[derive(Clone, Copy)]
+enum ProofBound {
+  /// The current player can achieve at least the score.
+  Lower,
+  /// The current player can achieve no better than the score.
+  Upper,
+}
+
+/// Counts the nodes in the proof tree establishing `bound` on `score`, the
+/// score of `game` searched to `depth`.
+fn proof_nodes<S: Solver + ?Sized>(
+  solver: &mut S,
+  game: &S::Game,
+  score: Score,
+  depth: u32,
+  bound: ProofBound,
+) -> u64 {
+  if depth == 0 {
+    return 1;
+  }
+
+  let child_proof_nodes = |solver: &mut S, m| {
+    let child = game.with_move(m);
+    if child.finished().is_finished() || depth == 1 {
+      1
+    } else {
+      let child_score = solver.evaluate(&child, depth - 1);
+      let child_bound = match bound {
+        ProofBound::Lower => ProofBound::Upper,
+        ProofBound::Upper => ProofBound::Lower,
+      };
+      proof_nodes(solver, &child, child_score, depth - 1, child_bound)
+    }
+  };
+
+  match bound {
+    // Nothing is worse than losing, or better than winning.
+    ProofBound::Lower if score.is_losing() => 1,
+    ProofBound::Upper if score.is_winning() => 1,
+    ProofBound::Lower => match solver.best_move(game, depth).1 {
+      Some(m) => 1 + child_proof_nodes(solver, m),
+      None => 1,
+    },
+    ProofBound::Upper => {
+      1 + game
+        .each_move()
+        .map(|m| child_proof_nodes(solver, m))
+        .sum::<u64>()
+    }
+  }
+}
+
+/// Counts the moves in `game` which lose for the player making them when
+/// searched `depth` moves deep by `solver`, but which `opp` doesn't see as
+/// losing.
+fn count_traps<S: Solver + ?Sized, O: Solver<Game = S::Game>>(
+  solver: &mut S,
+  opp: &mut O,
+  game: &S::Game,
+  depth: u32,
+) -> usize {
+  if game.finished().is_finished() {
+    return 0;
+  }
+  game
+    .each_move()
+    .filter(|&m| {
+      move_score(solver, game, m, depth).is_losing() && !move_score(opp, game, m, depth).is_losing()
+    })
+    .count()
+}
+
+/// Returns the outcomes of the game, from the perspective of the current
+/// player, which are reachable under any line of play (not only optimal play)
+/// finishing within `depth` moves. No solver is needed, since every line is
+/// explored.
+pub fn reachable_outcomes<G: Game>(game: &G, depth: u32) -> HashSet<ScoreValue> {
+  let mut outcomes = HashSet::new();
+  collect_reachable_outcomes(game, game.current_player(), depth, &mut outcomes);
+  outcomes
+}
+
+fn collect_reachable_outcomes<G: Game>(
+  game: &G,
+  player: GamePlayer,
+  depth: u32,
+  outcomes: &mut HashSet<ScoreValue>,
+) {
+  match game.finished() {
+    GameResult::Win(winner) => {
+      outcomes.insert(if winner == player {
+        ScoreValue::CurrentPlayerWins
+      } else {
+        ScoreValue::OtherPlayerWins
+      });
+    }
+    GameResult::Tie => {
+      outcomes.insert(ScoreValue::Tie);
+    }
+    GameResult::NotFinished => {
+      if depth == 0 {
+        return;
+      }
+      for m in game.each_move() {
+        // Every outcome has been found, so there's no need to keep looking.
+        if outcomes.len() == 3 {
+          return;
+        }
+        collect_reachable_outcomes(&game.with_move(m), player, depth - 1, outcomes);
+      }
+    }
+  }
+}
+
+/// Searches `game` at depths `1..=max_depth`, stopping early once the outcome
+/// is proven, there is no move to search for, or `out_of_time` returns true
+/// after a search. Returns the result of the last search made, or `NO_INFO`
+/// if `max_depth` is 0.
+fn iterative_search<S: Solver + ?Sized>(
+  solver: &mut S,
+  game: &S::Game,
+  max_depth: u32,
+  mut out_of_time: impl FnMut() -> bool,
+) -> SearchResult<<S::Game as Game>::Move> {
+  let finished = game.finished().is_finished();
+  let mut result = SearchResult::new(Score::NO_INFO, None, 0);
+  for depth in 1..=max_depth {
+    result = solver.search(game, depth);
+    if finished || result.best_move.is_none() || result.score.is_solved() || out_of_time() {
+      break;
+    }
+  }
+  result
+}
+
+/// Returns the score of making move `m` in `game`, from the perspective of the
+/// player making the move. If the move doesn't finish the game, the resulting
+/// position is searched `depth - 1` moves deep.
+pub(crate) fn move_score<S: Solver + ?Sized>(
+  solver: &mut S,
+  game: &S::Game,
+  m: <S::Game as Game>::Move,
+  depth: u32,
+) -> Score {
+  debug_assert!(depth > 0);
+  let child = game.with_move(m);
+  if !child.is_terminal() {
+    return solver
+      .best_move(&child, depth - 1)
+      .0
+      .backstep_between(game.current_player(), child.current_player());
+  }
+  match child.finished() {
+    GameResult::Win(player) => {
+      if player == game.current_player() {
+        Score::optimal_win(1)
+      } else {
+        Score::optimal_lose(1)
+      }
+    }
+    GameResult::Tie => Score::guaranteed_tie(),
+    GameResult::NotFinished => unreachable!("Terminal games have finished"),
+  }
+}
+
+/// Plays uniformly random moves in `game` until it finishes, returning the
+/// result. Moves are reservoir sampled from `each_move`, so unlike
+/// `test_util::make_deterministic_random_move` this doesn't require
+/// `G::Move: Ord`, though the moves chosen for a given seed depend on the
+/// order they are generated in.
+pub fn rollout<G: Game>(game: &mut G, rng: &mut impl Rng) -> GameResult {
+  loop {
+    let result = game.finished();
+    if result.is_finished() {
+      return result;
+    }
+
+    game.resolve_chance(rng);
+    let mut chosen = None;
+    for (i, m) in game.each_move().enumerate() {
+      if rng.random_range(0..=i) == 0 {
+        chosen = Some(m);
+      }
+    }
+    let Some(m) = chosen else {
+      return result;
+    };
+    game.make_move(m);
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::time::{Duration, Instant};
+
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+  use rand::{rngs::StdRng, SeedableRng};
+
+  use crate::{
+    determined_score::DeterminedScore,
+    solver::{move_score, reachable_outcomes, rollout},
+    solvers::MinimaxSolver,
+    test_games::{ConnectMove, ConnectN, Nim, TTTMove, TicTacToe},
+    test_util::{NegamaxSolver, ReversedMoveOrder},
+    Game, GamePlayer, GameResult, GameSerialize, MoveLossDetail, MoveLossKind, Score, ScoreValue,
+    SearchStats, Solver,
+  };
+
+  /// Models an opponent which never looks more than `max_depth` moves ahead.
+  struct ShallowSolver<S> {
+    solver: S,
+    max_depth: u32,
+  }
+
+  impl<S: Solver> Solver for ShallowSolver<S> {
+    type Game = S::Game;
+
+    fn best_move(
+      &mut self,
+      game: &S::Game,
+      depth: u32,
+    ) -> (Score, Option<<S::Game as Game>::Move>) {
+      self.solver.best_move(game, depth.min(self.max_depth))
+    }
+  }
+
+  /// Reports wins and losses without knowing they are the fastest possible, as
+  /// solvers which prune with bounds may.
+  struct LooseBoundSolver<S>(S);
+
+  impl<S: Solver> Solver for LooseBoundSolver<S> {
+    type Game = S::Game;
+
+    fn best_move(
+      &mut self,
+      game: &S::Game,
+      depth: u32,
+    ) -> (Score, Option<<S::Game as Game>::Move>) {
+      let (score, m) = self.0.best_move(game, depth);
+      let score = if score.is_winning() {
+        Score::win(score.determined_depth())
+      } else if score.is_losing() {
+        Score::lose(score.determined_depth())
+      } else {
+        score
+      };
+      (score, m)
+    }
+  }
+
+  #[gtest]
+  fn test_evaluate_both() {
+    let mut solver = NegamaxSolver::new();
+    for sticks in 1..=8 {
+      let game = Nim::new(sticks);
+      let (score, flipped) = solver.evaluate_both(&game, 10);
+      expect_eq!(score, solver.evaluate(&game, 10));
+      expect_eq!(flipped, score.flip_perspective());
+      expect_eq!(flipped.flip_perspective(), score);
+    }
+
+    let (score, flipped) = solver.evaluate_both(&Nim::new(4), 10);
+    expect_eq!(score, Score::optimal_win(3));
+    expect_eq!(flipped, Score::optimal_lose(3));
+  }
+
+  #[gtest]
+  fn test_best_move_independent_of_move_order() {
+    // Both moves lose in 2 from 3 sticks.
+    let (score, m) = NegamaxSolver::new().best_move(&Nim::new(3), 10);
+    expect_eq!(score, Score::optimal_lose(2));
+    expect_that!(m, some(eq(1)));
+    expect_eq!(
+      NegamaxSolver::new().best_move(&ReversedMoveOrder(Nim::new(3)), 10),
+      (score, m)
+    );
+
+    // X can force a win in 5 several different ways here.
+    let mut game = TicTacToe::new();
+    game.make_move(TTTMove::new((0, 0)));
+    game.make_move(TTTMove::new((1, 0)));
+    let (score, m) = NegamaxSolver::new().best_move(&game, 10);
+    expect_eq!(score, Score::optimal_win(5));
+    expect_that!(m, some(eq(TTTMove::new((0, 1)))));
+    expect_that!(
+      game
+        .each_move()
+        .filter(|&m| move_score(&mut NegamaxSolver::new(), &game, m, 10) == score)
+        .collect_vec(),
+      unordered_elements_are![
+        eq(&TTTMove::new((0, 1))),
+        eq(&TTTMove::new((1, 1))),
+        eq(&TTTMove::new((0, 2)))
+      ]
+    );
+    expect_eq!(
+      NegamaxSolver::new().best_move(&ReversedMoveOrder(game), 10),
+      (score, m)
+    );
+  }
+
+  #[gtest]
+  fn test_move_loss_detailed() {
+    // Taking 1 of 4 sticks wins, but taking 2 leaves the opponent a win.
+    let game = Nim::new(4);
+    expect_eq!(
+      MinimaxSolver::new().move_loss_detailed(1, &game, 10),
+      MoveLossDetail {
+        best: Score::optimal_win(3),
+        actual: Score::optimal_win(3),
+        kind: MoveLossKind::Equivalent,
+      }
+    );
+    expect_eq!(
+      MinimaxSolver::new().move_loss_detailed(2, &game, 10),
+      MoveLossDetail {
+        best: Score::optimal_win(3),
+        actual: Score::optimal_lose(2),
+        kind: MoveLossKind::SwitchedOutcome,
+      }
+    );
+  }
+
+  #[gtest]
+  fn test_move_loss_detailed_margins() {
+    // X wins immediately in the top-left corner, or in 3 moves with the center.
+    let game: TicTacToe = ".../X../XOO".parse().unwrap();
+    expect_eq!(
+      MinimaxSolver::new().move_loss_detailed(TTTMove::new((1, 1)), &game, 10),
+      MoveLossDetail {
+        best: Score::optimal_win(1),
+        actual: Score::optimal_win(3),
+        kind: MoveLossKind::SlowerWin,
+      }
+    );
+
+    // O can only delay losing by blocking the left column.
+    let game: TicTacToe = ".../X../XO.".parse().unwrap();
+    expect_eq!(
+      MinimaxSolver::new().move_loss_detailed(TTTMove::new((1, 1)), &game, 10),
+      MoveLossDetail {
+        best: Score::optimal_lose(4),
+        actual: Score::optimal_lose(2),
+        kind: MoveLossKind::FasterLoss,
+      }
+    );
+    expect_eq!(
+      MinimaxSolver::new()
+        .move_loss_detailed(TTTMove::new((0, 2)), &game, 10)
+        .kind,
+      MoveLossKind::Equivalent
+    );
+  }
+
+  #[gtest]
+  fn test_best_move_with_tiebreak() {
+    // Every opening move ties in tic-tac-toe.
+    let game = TicTacToe::new();
+    let (score, m) = MinimaxSolver::new().best_move_with_tiebreak(&game, 9, Ord::cmp);
+    expect_eq!(score, Score::guaranteed_tie());
+    expect_that!(m, some(eq(TTTMove::new((0, 0)))));
+    expect_eq!(
+      MinimaxSolver::new().best_move_with_tiebreak(&ReversedMoveOrder(game.clone()), 9, Ord::cmp),
+      (score, m)
+    );
+    expect_eq!(
+      MinimaxSolver::new().best_move_with_tiebreak(&game, 9, |m1, m2| m2.cmp(m1)),
+      (score, Some(TTTMove::new((2, 2))))
+    );
+
+    // Only the winning moves are candidates.
+    let mut game = TicTacToe::new();
+    game.make_move(TTTMove::new((0, 0)));
+    game.make_move(TTTMove::new((1, 0)));
+    expect_eq!(
+      MinimaxSolver::new().best_move_with_tiebreak(&game, 10, |m1, m2| m2.cmp(m1)),
+      (Score::optimal_win(5), Some(TTTMove::new((0, 2))))
+    );
+
+    // X wins fastest in column 2, and more slowly in column 3. The slower win
+    // must not be a candidate, even from a solver which doesn't know the
+    // faster win is the fastest possible.
+    let game = ConnectN::from_notation("5x4:3:...../...../.O.../.X...").unwrap();
+    let mut solver = LooseBoundSolver(MinimaxSolver::new());
+    expect_eq!(
+      solver.best_move_with_tiebreak(&game, 9, |m1, m2| m2.cmp(m1)),
+      (Score::win(3), Some(ConnectMove { col: 2 }))
+    );
+    let slow_win = solver.evaluate_move(&game, ConnectMove { col: 3 }, 9);
+    expect_true!(slow_win.is_winning());
+    expect_eq!(slow_win.determined_depth(), 9);
+  }
+
+  #[gtest]
+  fn test_playout_until_determined() {
+    const DEPTH: u32 = 4;
+    let mut solver = NegamaxSolver::new();
+    let game = Nim::new(10);
+
+    let full = solver.playout(&game, DEPTH).collect_vec();
+    let truncated = solver.playout_until_determined(&game, DEPTH);
+    expect_lt!(truncated.len(), full.len());
+    expect_eq!(truncated, full[..truncated.len()]);
+
+    // The line stops at the first position whose outcome is proven.
+    let (last, _) = truncated.last().unwrap();
+    expect_true!(solver.evaluate(last, DEPTH).is_solved());
+    for (game, _) in &truncated[..truncated.len() - 1] {
+      expect_false!(solver.evaluate(game, DEPTH).is_solved());
+    }
+    expect_false!(solver.evaluate(&game, DEPTH).is_solved());
+
+    // With enough depth to solve the starting position, nothing is played.
+    expect_that!(solver.playout_until_determined(&game, 20), is_empty());
+  }
+
+  #[gtest]
+  fn test_effective_branching_factor() {
+    let stats = SearchStats {
+      nodes_expanded: 1000,
+      depth: 3,
+      ..SearchStats::default()
+    };
+    expect_that!(stats.effective_branching_factor(3), near(10.0, 1e-9));
+    expect_that!(stats.effective_branching_factor(1), near(1000.0, 1e-9));
+    expect_that!(
+      SearchStats {
+        nodes_expanded: 1 << 20,
+        depth: 4,
+        ..SearchStats::default()
+      }
+      .effective_branching_factor(4),
+      near(32.0, 1e-9)
+    );
+    expect_eq!(
+      format!("{stats}"),
+      "1000 nodes to depth 3 (effective branching factor 10.00)"
+    );
+  }
+
+  #[gtest]
+  fn test_negamax_stats() {
+    let mut solver = NegamaxSolver::new();
+    solver.best_move(&Nim::new(3), 10);
+    // Visits 3, 2, and 1 twice (after 3 -> 2 -> 1 and 3 -> 1). Finished states
+    // aren't searched.
+    expect_eq!(
+      solver.stats(),
+      SearchStats {
+        nodes_expanded: 4,
+        depth: 10,
+        ..SearchStats::default()
+      }
+    );
+
+    solver.reset_stats();
+    expect_eq!(solver.stats(), SearchStats::default());
+  }
+
+  #[gtest]
+  fn test_reachable_outcomes() {
+    let mut solver = NegamaxSolver::new();
+
+    // Taking 1 stick forces a win, but taking 2 lets the opponent win.
+    let game = Nim::new(4);
+    expect_eq!(solver.evaluate(&game, 10), Score::optimal_win(3));
+    expect_that!(
+      reachable_outcomes(&game, 10),
+      unordered_elements_are![
+        eq(&ScoreValue::CurrentPlayerWins),
+        eq(&ScoreValue::OtherPlayerWins)
+      ]
+    );
+
+    // No line of play finishes the game within a single move.
+    expect_that!(reachable_outcomes(&game, 1), is_empty());
+  }
+
+  #[gtest]
+  fn test_non_losing_moves() {
+    // With X in opposite corners and O in the center, O loses by taking a
+    // corner, but draws by taking an edge.
+    let mut game = TicTacToe::new();
+    game.make_move(TTTMove::new((0, 0)));
+    game.make_move(TTTMove::new((1, 1)));
+    game.make_move(TTTMove::new((2, 2)));
+
+    expect_that!(
+      NegamaxSolver::new().non_losing_moves(&game, 10),
+      unordered_elements_are![
+        eq(&TTTMove::new((1, 0))),
+        eq(&TTTMove::new((0, 1))),
+        eq(&TTTMove::new((2, 1))),
+        eq(&TTTMove::new((1, 2)))
+      ]
+    );
+  }
+
+  #[gtest]
+  fn test_prove() {
+    let mut solver = NegamaxSolver::new();
+
+    // Taking the last stick is the whole proof.
+    expect_eq!(solver.prove(&Nim::new(1), 10), (DeterminedScore::win(1), 2));
+    // Both moves must be refuted, each with one winning reply.
+    expect_eq!(
+      solver.prove(&Nim::new(3), 10),
+      (DeterminedScore::lose(2), 5)
+    );
+  }
+
+  #[gtest]
+  fn test_proof_smaller_than_search() {
+    let game = Nim::new(10);
+    let mut solver = NegamaxSolver::new();
+    solver.best_move(&game, 12);
+    let searched_nodes = solver.stats().nodes_expanded;
+
+    let (score, proof_nodes) = NegamaxSolver::new().prove(&game, 12);
+    expect_eq!(score, DeterminedScore::win(7));
+    expect_lt!(proof_nodes, searched_nodes);
+  }
+
+  #[gtest]
+  fn test_best_move_iterative() {
+    for sticks in [4, 5, 10, 11] {
+      let game = Nim::new(sticks);
+      let mut solver = NegamaxSolver::new();
+      let (score, m) = solver.best_move_iterative(&game, 30);
+      expect_true!(score.is_winning());
+      expect_eq!(m, NegamaxSolver::new().best_move(&game, 30).1);
+
+      // Stops as soon as the win is found, well before the maximum depth.
+      expect_lt!(solver.stats().depth, 30);
+    }
+  }
+
+  #[gtest]
+  fn test_best_move_iterative_unproven() {
+    // No win is found within 2 moves, so every depth is searched.
+    let game = Nim::new(10);
+    let mut solver = NegamaxSolver::new();
+    let result = solver.best_move_iterative(&game, 2);
+    expect_eq!(result, NegamaxSolver::new().best_move(&game, 2));
+    expect_eq!(solver.stats().depth, 2);
+  }
+
+  #[gtest]
+  fn test_best_move_timed() {
+    let game = Nim::new(10);
+    let deadline = Instant::now() + Duration::from_secs(60);
+    let (score, m) = NegamaxSolver::new().best_move_timed(&game, deadline);
+    expect_true!(score.is_winning());
+    expect_eq!(m, NegamaxSolver::new().best_move(&game, 30).1);
+
+    // A passed deadline still completes a search of depth 1.
+    let mut solver = NegamaxSolver::new();
+    let (_, m) = solver.best_move_timed(&game, Instant::now());
+    expect_that!(m, some(anything()));
+    expect_eq!(solver.stats().depth, 1);
+  }
+
+  #[gtest]
+  fn test_best_move_bounded() {
+    let game = ConnectN::new(3, 3, 3);
+
+    // A passed deadline stops after a search of depth 1, which can't prove
+    // the outcome.
+    let mut solver = NegamaxSolver::new();
+    let (_, m, proven) = solver.best_move_bounded(&game, Instant::now());
+    expect_false!(proven);
+    expect_that!(m, some(anything()));
+    expect_eq!(solver.stats().depth, 1);
+
+    let deadline = Instant::now() + Duration::from_secs(60);
+    let (score, m, proven) = MinimaxSolver::new().best_move_bounded(&game, deadline);
+    expect_true!(proven);
+    expect_eq!((score, m), MinimaxSolver::new().best_move(&game, 9));
+  }
+
+  #[gtest]
+  fn test_best_move_bounded_finished() {
+    // There is nothing to search, so this returns at once instead of
+    // searching ever deeper until the deadline.
+    let deadline = Instant::now() + Duration::from_secs(60);
+    let mut solver = NegamaxSolver::new();
+    let (_, m, proven) = solver.best_move_bounded(&Nim::new(0), deadline);
+    expect_that!(m, none());
+    expect_false!(proven);
+    expect_eq!(solver.stats().depth, 1);
+    expect_lt!(Instant::now(), deadline);
+  }
+
+  #[gtest]
+  fn test_principal_variation() {
+    let game = TicTacToe::new();
+    let (score, variation) = NegamaxSolver::new().principal_variation(&game, 9);
+    expect_true!(score.is_guaranteed_tie());
+    expect_eq!(variation.len(), 9);
+
+    let end = variation.iter().fold(game, |game, &m| game.with_move(m));
+    expect_eq!(end.finished(), GameResult::Tie);
+  }
+
+  #[gtest]
+  fn test_principal_variation_win() {
+    let (score, variation) = NegamaxSolver::new().principal_variation(&Nim::new(5), 10);
+    expect_eq!(score, Score::optimal_win(3));
+    expect_eq!(variation, vec![2, 1, 2]);
+  }
+
+  #[gtest]
+  fn test_principal_variation_depth_limited() {
+    let (_, variation) = NegamaxSolver::new().principal_variation(&TicTacToe::new(), 3);
+    expect_eq!(variation.len(), 3);
+
+    let (_, variation) = NegamaxSolver::new().principal_variation(&TicTacToe::new(), 1);
+    expect_eq!(variation.len(), 1);
+    let (_, variation) = NegamaxSolver::new().principal_variation(&TicTacToe::new(), 0);
+    expect_that!(variation, is_empty());
+  }
+
+  #[gtest]
+  fn test_principal_variation_finished() {
+    let (_, variation) = NegamaxSolver::new().principal_variation(&Nim::new(0), 5);
+    expect_that!(variation, is_empty());
+  }
+
+  #[gtest]
+  fn test_spectator_score() {
+    // Player 1 wins 4-stick Nim by taking 1, whichever player's turn it is.
+    let mut solver = NegamaxSolver::new();
+    let mut game = Nim::new(4);
+    for m in [1, 2] {
+      let score = solver.evaluate(&game, 10);
+      let p1_score = score.as_seen_by(game.current_player(), GamePlayer::Player1);
+      expect_true!(p1_score.is_winning(), "{game}: {p1_score}");
+      game.make_move(m);
+    }
+  }
+
+  #[gtest]
+  fn test_rank_moves() {
+    let mut game = TicTacToe::new();
+    for coord in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+      game.make_move(TTTMove::new(coord));
+    }
+
+    let ranked = NegamaxSolver::new().rank_moves(&game, 5);
+    expect_eq!(ranked.len(), 5);
+    expect_eq!(ranked[0], (TTTMove::new((2, 0)), Score::optimal_win(1)));
+    expect_true!(ranked
+      .iter()
+      .tuple_windows()
+      .all(|((_, score1), (_, score2))| !score2.better(*score1)));
+
+    game.make_move(TTTMove::new((2, 0)));
+    expect_that!(NegamaxSolver::new().rank_moves(&game, 5), is_empty());
+  }
+
+  #[gtest]
+  fn test_evaluate_move() {
+    let game = Nim::new(4);
+    let mut solver = NegamaxSolver::new();
+    let (score, m) = solver.best_move(&game, 10);
+    expect_eq!(score, Score::optimal_win(3));
+    expect_eq!(solver.evaluate_move(&game, m.unwrap(), 10), score);
+    // Taking 2 sticks leaves a winning position for the opponent.
+    expect_eq!(m, Some(1));
+    expect_eq!(solver.evaluate_move(&game, 2, 10), Score::optimal_lose(2));
+
+    let mut game = TicTacToe::new();
+    for coord in [(0, 0), (0, 1), (1, 0), (1, 1)] {
+      game.make_move(TTTMove::new(coord));
+    }
+    let mut solver = MinimaxSolver::new();
+    let (score, m) = solver.best_move(&game, 5);
+    expect_eq!(solver.evaluate_move(&game, m.unwrap(), 5), score);
+    let worse = TTTMove::new((2, 2));
+    expect_true!(score.better(solver.evaluate_move(&game, worse, 5)));
+  }
+
+  #[gtest]
+  fn test_best_moves() {
+    let games = (0..12)
+      .map(|sticks| Nim::with_max_take(sticks, 3))
+      .chain([Nim::new(5).with_move(1), Nim::new_misere(7)])
+      .collect_vec();
+    let expected = games
+      .iter()
+      .map(|game| MinimaxSolver::new().best_move(game, 12))
+      .collect_vec();
+    expect_eq!(MinimaxSolver::new().best_moves(&games, 12), expected);
+    expect_that!(MinimaxSolver::<Nim>::new().best_moves(&[], 12), is_empty());
+  }
+
+  #[gtest]
+  fn test_search() {
+    let mut solver = MinimaxSolver::new();
+    let result = solver.search(&Nim::new(4), 10);
+    expect_eq!(result.score, Score::optimal_win(3));
+    expect_eq!(result.best_move, Some(1));
+    expect_eq!(result.searched_depth, 10);
+    expect_true!(result.exact);
+    expect_true!(result.proven);
+
+    // The tie is only known up to the search depth.
+    let mut solver = MinimaxSolver::new();
+    let result = solver.search(&ConnectN::new(7, 6, 4), 4);
+    expect_eq!(result.score, Score::tie(4));
+    expect_eq!(result.searched_depth, 4);
+    expect_true!(result.exact);
+    expect_false!(result.proven);
+
+    // X wins in 3, but the solver doesn't know there's no faster win.
+    let game = ConnectN::from_notation("5x4:3:...../...../.O.../.X...").unwrap();
+    let result = LooseBoundSolver(MinimaxSolver::new()).search(&game, 4);
+    expect_eq!(result.score, Score::win(3));
+    expect_false!(result.exact);
+    expect_false!(result.proven);
+  }
+
+  #[gtest]
+  fn test_rollout() {
+    let mut rng = StdRng::seed_from_u64(1281);
+    let results = (0..20)
+      .map(|_| {
+        let mut game = Nim::new(10);
+        let result = rollout(&mut game, &mut rng);
+        expect_eq!(game.finished(), result);
+        result
+      })
+      .collect_vec();
+    expect_that!(results, contains(eq(&GameResult::Win(GamePlayer::Player1))));
+    expect_that!(results, contains(eq(&GameResult::Win(GamePlayer::Player2))));
+  }
+
+  #[gtest]
+  fn test_best_practical_move() {
+    // Every move from this drawn position ties, but O in the corner leaves X
+    // replies which lose that X can't see coming with only a move of lookahead.
+    let game = TicTacToe::from_notation(".X./.../...").unwrap();
+    let mut solver = NegamaxSolver::new();
+    let (score, best) = solver.best_move(&game, 9);
+    expect_true!(score.is_guaranteed_tie());
+    expect_that!(best, some(eq(TTTMove::new((1, 0)))));
+    expect_eq!(
+      move_score(&mut solver, &game, TTTMove::new((0, 2)), 9),
+      score
+    );
+
+    let mut shallow = ShallowSolver {
+      solver: NegamaxSolver::new(),
+      max_depth: 1,
+    };
+    expect_that!(
+      solver.best_practical_move(&game, 9, &mut shallow),
+      some(eq(TTTMove::new((0, 2))))
+    );
+    // A perfect opponent never falls for a trap, so there's no reason to
+    // deviate from the best move.
+    expect_eq!(
+      solver.best_practical_move(&game, 9, &mut NegamaxSolver::new()),
+      best
+    );
+  }
+
+  #[gtest]
+  fn test_best_practical_move_winning() {
+    let mut shallow = ShallowSolver {
+      solver: NegamaxSolver::new(),
+      max_depth: 1,
+    };
+    for sticks in [4, 5, 7] {
+      let game = Nim::new(sticks);
+      let mut solver = NegamaxSolver::new();
+      expect_eq!(
+        solver.best_practical_move(&game, 10, &mut shallow),
+        solver.best_move(&game, 10).1
+      );
+    }
+  }
 }
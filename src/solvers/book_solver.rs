@@ -0,0 +1,239 @@
+use std::{
+  collections::HashMap,
+  io::{self, BufRead, Write},
+};
+
+use crate::{Game, Score, Solver};
+
+/// The move to play from a position in an opening book, and its score from
+/// the perspective of the player to move.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MoveEntry {
+  /// The `Debug` representation of the move, e.g. `2` for taking 2 sticks in
+  /// `Nim`.
+  pub move_string: String,
+  pub score: Score,
+}
+
+/// A serialization of opening books, which map `Game::position_key`s to the
+/// move to play from that position.
+pub trait BookFormat {
+  fn read_book<R: BufRead>(&self, reader: R) -> io::Result<HashMap<u64, MoveEntry>>;
+
+  fn write_book<W: Write>(&self, writer: W, book: &HashMap<u64, MoveEntry>) -> io::Result<()>;
+}
+
+/// A line-based text book format, with one entry per line:
+///
+/// ```text
+/// <position_key_hex> <move_string> <score>
+/// ```
+///
+/// The position key and score are hex integers, and the move string is
+/// everything between them, so it may contain spaces. The score is the raw
+/// representation of a `Score` (see `Score::raw`). Blank lines and lines
+/// starting with `#` are ignored.
+///
+/// Keys are `Game::position_key`, so books produced by other tools only match
+/// if they derive keys the same way. Unless a game overrides `position_key`,
+/// the key is the low 64 bits of the 128-bit FNV-1a hash of the UTF-8 bytes of
+/// the position's `Debug` representation, with offset basis
+/// `0x6c62272e07bb014262b821756295c58d` and prime
+/// `0x0000000001000000000000000000013b`.
+pub struct TextBookFormat;
+
+impl TextBookFormat {
+  fn parse_line(line: &str) -> Option<(u64, MoveEntry)> {
+    let (key, rest) = line.split_once(' ')?;
+    let (move_string, score) = rest.rsplit_once(' ')?;
+    if move_string.is_empty() {
+      return None;
+    }
+    let key = u64::from_str_radix(key, 16).ok()?;
+    let score = Score::from_raw(u32::from_str_radix(score, 16).ok()?);
+    Some((
+      key,
+      MoveEntry {
+        move_string: move_string.to_owned(),
+        score,
+      },
+    ))
+  }
+}
+
+impl BookFormat for TextBookFormat {
+  fn read_book<R: BufRead>(&self, reader: R) -> io::Result<HashMap<u64, MoveEntry>> {
+    let mut book = HashMap::new();
+    for (line_number, line) in reader.lines().enumerate() {
+      let line = line?;
+      let line = line.trim();
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+      let (key, entry) = Self::parse_line(line).ok_or_else(|| {
+        io::Error::new(
+          io::ErrorKind::InvalidData,
+          format!("Malformed book entry on line {}: {line}", line_number + 1),
+        )
+      })?;
+      book.insert(key, entry);
+    }
+    Ok(book)
+  }
+
+  fn write_book<W: Write>(&self, mut writer: W, book: &HashMap<u64, MoveEntry>) -> io::Result<()> {
+    let mut entries = book.iter().collect::<Vec<_>>();
+    entries.sort_by_key(|&(&key, _)| key);
+    for (key, entry) in entries {
+      writeln!(
+        writer,
+        "{key:016x} {} {:08x}",
+        entry.move_string,
+        entry.score.raw()
+      )?;
+    }
+    Ok(())
+  }
+}
+
+/// Wraps a solver, playing moves from an opening book in the positions it
+/// covers. Book entries are trusted, and returned regardless of the search
+/// depth. Positions outside the book, and entries whose move isn't legal in
+/// the position, are passed to the wrapped solver.
+pub struct BookSolver<S: Solver> {
+  solver: S,
+  book: HashMap<u64, MoveEntry>,
+}
+
+impl<S: Solver> BookSolver<S> {
+  pub fn new(solver: S, book: HashMap<u64, MoveEntry>) -> Self {
+    Self { solver, book }
+  }
+
+  /// Reads a book in `format` from `reader`.
+  pub fn load<F: BookFormat, R: BufRead>(solver: S, format: &F, reader: R) -> io::Result<Self> {
+    Ok(Self::new(solver, format.read_book(reader)?))
+  }
+
+  pub fn solver(&self) -> &S {
+    &self.solver
+  }
+
+  pub fn book(&self) -> &HashMap<u64, MoveEntry> {
+    &self.book
+  }
+
+  /// The book move and its score for `game`, if the book has a legal move for
+  /// it.
+  pub fn book_move(&self, game: &S::Game) -> Option<(Score, <S::Game as Game>::Move)> {
+    let entry = self.book.get(&game.position_key())?;
+    game
+      .each_move()
+      .find(|m| format!("{m:?}") == entry.move_string)
+      .map(|m| (entry.score, m))
+  }
+}
+
+impl<S: Solver> Solver for BookSolver<S> {
+  type Game = S::Game;
+
+  fn best_move(&mut self, game: &S::Game, depth: u32) -> (Score, Option<<S::Game as Game>::Move>) {
+    match self.book_move(game) {
+      Some((score, m)) => (score, Some(m)),
+      None => self.solver.best_move(game, depth),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{collections::HashMap, io::ErrorKind};
+
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    solvers::{BookFormat, BookSolver, MinimaxSolver, MoveEntry, TextBookFormat},
+    test_games::{Nim, TTTMove, TicTacToe},
+    Game, Score, Solver,
+  };
+
+  fn entry(move_string: &str, score: Score) -> MoveEntry {
+    MoveEntry {
+      move_string: move_string.to_owned(),
+      score,
+    }
+  }
+
+  #[gtest]
+  fn test_text_round_trip() {
+    let book = HashMap::from([
+      (
+        Nim::new(5).position_key(),
+        entry("2", Score::optimal_win(3)),
+      ),
+      (
+        TicTacToe::new().position_key(),
+        entry(
+          &format!("{:?}", TTTMove::new((1, 1))),
+          Score::guaranteed_tie(),
+        ),
+      ),
+      (u64::MAX, entry("a move", Score::NO_INFO)),
+    ]);
+
+    let mut text = Vec::new();
+    TextBookFormat.write_book(&mut text, &book).unwrap();
+    let read = TextBookFormat.read_book(text.as_slice()).unwrap();
+    expect_eq!(read, book);
+  }
+
+  #[gtest]
+  fn test_read_skips_comments() {
+    let text = format!(
+      "# A Nim book\n\n{:x} 1 {:08x}\n",
+      Nim::new(4).position_key(),
+      Score::optimal_win(1).raw()
+    );
+    let book = TextBookFormat.read_book(text.as_bytes()).unwrap();
+    expect_eq!(
+      book,
+      HashMap::from([(
+        Nim::new(4).position_key(),
+        entry("1", Score::optimal_win(1))
+      )])
+    );
+  }
+
+  #[gtest]
+  fn test_read_malformed() {
+    for text in ["1f 2", "xyz 2 0", "1f 2 score", "1f  0"] {
+      let error = TextBookFormat.read_book(text.as_bytes()).unwrap_err();
+      expect_eq!(error.kind(), ErrorKind::InvalidData, "{text}");
+    }
+  }
+
+  #[gtest]
+  fn test_book_solver() {
+    // Taking 1 stick from 5 is a losing book move, which a search would never
+    // choose.
+    let book = HashMap::from([(Nim::new(5).position_key(), entry("1", Score::NO_INFO))]);
+    let mut solver = BookSolver::new(MinimaxSolver::new(), book);
+    expect_eq!(
+      solver.best_move(&Nim::new(5), 10),
+      (Score::NO_INFO, Some(1))
+    );
+
+    // Positions outside the book are searched.
+    let (score, m) = solver.best_move(&Nim::new(4), 10);
+    expect_true!(score.is_winning());
+    expect_eq!(m, Some(1));
+  }
+
+  #[gtest]
+  fn test_book_solver_illegal_move() {
+    let book = HashMap::from([(Nim::new(1).position_key(), entry("2", Score::NO_INFO))]);
+    let mut solver = BookSolver::new(MinimaxSolver::new(), book);
+    expect_that!(solver.book_move(&Nim::new(1)), none());
+    expect_eq!(solver.best_move(&Nim::new(1), 1).1, Some(1));
+  }
+}
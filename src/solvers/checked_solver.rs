@@ -0,0 +1,102 @@
+use crate::{Game, Score, Solver};
+
+/// Runs a solver under test alongside a trusted reference solver, panicking if
+/// their scores disagree on the outcome of the game. The results of the solver
+/// under test are returned.
+///
+/// Checking is enabled by default in debug builds only, since it's as slow as
+/// the reference solver.
+pub struct CheckedSolver<F, S> {
+  fast: F,
+  reference: S,
+  check: bool,
+}
+
+impl<F, S> CheckedSolver<F, S> {
+  pub fn new(fast: F, reference: S) -> Self {
+    Self {
+      fast,
+      reference,
+      check: cfg!(debug_assertions),
+    }
+  }
+
+  /// Enables or disables checking against the reference solver.
+  pub fn with_checking(mut self, check: bool) -> Self {
+    self.check = check;
+    self
+  }
+
+  pub fn fast(&self) -> &F {
+    &self.fast
+  }
+
+  pub fn reference(&self) -> &S {
+    &self.reference
+  }
+}
+
+impl<G, F, S> Solver for CheckedSolver<F, S>
+where
+  G: Game,
+  F: Solver<Game = G>,
+  S: Solver<Game = G>,
+{
+  type Game = G;
+
+  fn best_move(&mut self, game: &G, depth: u32) -> (Score, Option<G::Move>) {
+    let (score, m) = self.fast.best_move(game, depth);
+    if self.check {
+      let (expected, _) = self.reference.best_move(game, depth);
+      assert!(
+        score.same_outcome(expected),
+        "Solver returned {score} at depth {depth}, but the reference solver returned {expected} for:\n{game:?}"
+      );
+    }
+    (score, m)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    solvers::{CheckedSolver, MemoSolver},
+    test_games::Nim,
+    test_util::NegamaxSolver,
+    Score, Solver,
+  };
+
+  /// A solver which thinks every position is lost.
+  struct PessimistSolver;
+
+  impl Solver for PessimistSolver {
+    type Game = Nim;
+
+    fn best_move(&mut self, _game: &Nim, _depth: u32) -> (Score, Option<u32>) {
+      (Score::optimal_lose(1), Some(1))
+    }
+  }
+
+  #[gtest]
+  fn test_agreeing_solvers() {
+    let mut solver =
+      CheckedSolver::new(MemoSolver::new(NegamaxSolver::new()), NegamaxSolver::new())
+        .with_checking(true);
+    for sticks in 1..=10 {
+      let game = Nim::new(sticks);
+      expect_eq!(
+        solver.best_move(&game, 12),
+        NegamaxSolver::new().best_move(&game, 12)
+      );
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "but the reference solver returned")]
+  fn test_disagreeing_solvers() {
+    let mut solver = CheckedSolver::new(PessimistSolver, NegamaxSolver::new()).with_checking(true);
+    solver.best_move(&Nim::new(4), 10);
+  }
+}
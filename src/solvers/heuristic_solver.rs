@@ -0,0 +1,204 @@
+use std::marker::PhantomData;
+
+use crate::{solver::move_score, Game, LeafHistogram, Score, SearchStats, Solver};
+
+/// A depth-limited negamax solver which evaluates positions at the search
+/// horizon with a heuristic, instead of treating them as having no
+/// information.
+///
+/// Heuristic scores are guesses, and must not be determined to any depth a
+/// search could prove something at, so they can't be mistaken for proofs. For
+/// example, a heuristic might return `Score::win(n)` for a large `n` to favor
+/// a position, but never `Score::optimal_win(n)`.
+///
+/// Among equally scored moves, the first move generated is chosen.
+pub struct HeuristicSolver<G, H> {
+  heuristic: H,
+  stats: SearchStats,
+  _game: PhantomData<G>,
+}
+
+impl<G, H> HeuristicSolver<G, H>
+where
+  G: Game,
+  H: Fn(&G) -> Score,
+{
+  pub fn new(heuristic: H) -> Self {
+    Self {
+      heuristic,
+      stats: SearchStats::default(),
+      _game: PhantomData,
+    }
+  }
+
+  /// Collects a histogram of leaf scores into the stats. Leaves are the
+  /// positions at the search horizon and finished games, and are recorded with
+  /// the score of the move into them, from the perspective of the player
+  /// making that move.
+  pub fn with_leaf_histogram(mut self) -> Self {
+    self.stats.leaf_histogram = Some(LeafHistogram::default());
+    self
+  }
+
+  /// The stats accumulated over all searches since construction or the last
+  /// call to `reset_stats`.
+  pub fn stats(&self) -> SearchStats {
+    self.stats.clone()
+  }
+
+  pub fn reset_stats(&mut self) {
+    let leaf_histogram = self
+      .stats
+      .leaf_histogram
+      .as_ref()
+      .map(|_| LeafHistogram::default());
+    self.stats = SearchStats { leaf_histogram, ..SearchStats::default() };
+  }
+
+  /// Evaluates `game`, which is at the search horizon, with the heuristic. The
+  /// score must not be determined to `depth`.
+  pub fn evaluate_leaf(&self, game: &G, depth: u32) -> Score {
+    let score = (self.heuristic)(game);
+    debug_assert!(
+      !score.determined(depth),
+      "Heuristic score {score} is determined to depth {depth} for:\n{game:?}"
+    );
+    score
+  }
+}
+
+impl<G, H> Solver for HeuristicSolver<G, H>
+where
+  G: Game,
+  H: Fn(&G) -> Score,
+{
+  type Game = G;
+
+  fn best_move(&mut self, game: &G, depth: u32) -> (Score, Option<G::Move>) {
+    if depth == 0 {
+      // Nothing at all has been searched, so not even a search of depth 1 can
+      // be claimed.
+      return (self.evaluate_leaf(game, 1), None);
+    }
+    self.stats.nodes_expanded += 1;
+    self.stats.depth = self.stats.depth.max(depth);
+
+    game
+      .each_move()
+      .map(|m| {
+        let score = move_score(self, game, m, depth);
+        if let Some(histogram) = &mut self.stats.leaf_histogram {
+          // Every move at depth 1 leads to a leaf, but deeper moves only do
+          // when they finish the game.
+          if depth == 1 || game.with_move(m).is_terminal() {
+            histogram.record(score);
+          }
+        }
+        (score, m)
+      })
+      .fold(
+        None,
+        |best: Option<(Score, G::Move)>, (score, m)| match best {
+          Some((best_score, _)) if !score.better(best_score) => best,
+          _ => Some((score, m)),
+        },
+      )
+      .map_or((Score::NO_INFO, None), |(score, m)| (score, Some(m)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::cell::Cell;
+
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    solvers::HeuristicSolver,
+    test_games::{TTTMove, TicTacToe},
+    Game, Score, ScoreValue, Solver,
+  };
+
+  /// Favors positions where the center has been taken by the player who just
+  /// moved.
+  fn center_heuristic(game: &TicTacToe) -> Score {
+    if game.is_empty((1, 1)) {
+      Score::NO_INFO
+    } else {
+      Score::lose(9)
+    }
+  }
+
+  #[gtest]
+  fn test_horizon_uses_heuristic() {
+    let leaves = Cell::new(0);
+    let mut solver = HeuristicSolver::new(|game: &TicTacToe| {
+      leaves.set(leaves.get() + 1);
+      center_heuristic(game)
+    });
+
+    let (score, m) = solver.best_move(&TicTacToe::new(), 1);
+    expect_eq!(leaves.get(), 9);
+    expect_that!(m, some(eq(TTTMove::new((1, 1)))));
+    expect_eq!(score, Score::lose(9).backstep());
+    expect_true!(score.is_winning());
+    expect_false!(score.determined(2));
+  }
+
+  #[gtest]
+  fn test_proofs_override_heuristic() {
+    // X can win immediately, which beats any heuristic.
+    let mut game = TicTacToe::new();
+    for coord in [(0, 0), (1, 1), (1, 0), (2, 2)] {
+      game.make_move(TTTMove::new(coord));
+    }
+    let (score, m) = HeuristicSolver::new(center_heuristic).best_move(&game, 3);
+    expect_eq!(score, Score::optimal_win(1));
+    expect_that!(m, some(eq(TTTMove::new((2, 0)))));
+  }
+
+  #[gtest]
+  fn test_leaf_histogram() {
+    let mut game = TicTacToe::new();
+    for coord in [(0, 0), (1, 1), (1, 0), (2, 2)] {
+      game.make_move(TTTMove::new(coord));
+    }
+    let mut solver = HeuristicSolver::new(center_heuristic).with_leaf_histogram();
+    solver.best_move(&game, 3);
+
+    let stats = solver.stats();
+    let histogram = stats.leaf_histogram.as_ref().unwrap();
+    // Completing the top row wins immediately.
+    expect_gt!(histogram.count(ScoreValue::CurrentPlayerWins, 1), 0);
+    let winning_leaves = histogram
+      .buckets()
+      .filter(|((outcome, _), _)| outcome.is_winning())
+      .map(|(_, count)| count)
+      .sum::<u64>();
+    expect_gt!(winning_leaves, 0);
+    expect_eq!(
+      histogram.total(),
+      histogram.buckets().map(|(_, count)| count).sum::<u64>()
+    );
+    expect_eq!(stats.depth, 3);
+
+    solver.reset_stats();
+    expect_that!(solver.stats().leaf_histogram, some(eq(&Default::default())));
+  }
+
+  #[gtest]
+  fn test_leaf_histogram_disabled() {
+    let mut solver = HeuristicSolver::new(center_heuristic);
+    solver.best_move(&TicTacToe::new(), 2);
+    expect_that!(solver.stats().leaf_histogram, none());
+    expect_gt!(solver.stats().nodes_expanded, 0);
+  }
+
+  #[test]
+  #[cfg(debug_assertions)]
+  #[should_panic(expected = "is determined to depth 1")]
+  fn test_heuristic_must_not_prove() {
+    let mut solver = HeuristicSolver::new(|_: &TicTacToe| Score::optimal_win(1));
+    solver.best_move(&TicTacToe::new(), 1);
+  }
+}
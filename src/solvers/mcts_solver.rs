@@ -0,0 +1,235 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{rollout, Game, GamePlayer, GameResult, MoveLimited, Score, Solver};
+
+struct Node<M> {
+  /// The move which led to this node, or `None` for the root.
+  m: Option<M>,
+  parent: Option<usize>,
+  /// The player who made `m`.
+  mover: GamePlayer,
+  children: Vec<usize>,
+  /// Moves from this node which haven't been expanded into children yet.
+  untried: Vec<M>,
+  visits: u32,
+  /// The total reward of every playout through this node, from the
+  /// perspective of `mover`: 1 for a win, 1/2 for a tie, and 0 for a loss.
+  reward: f64,
+}
+
+impl<M> Node<M> {
+  fn win_rate(&self) -> f64 {
+    self.reward / self.visits as f64
+  }
+}
+
+/// An anytime solver which estimates the value of moves with Monte Carlo tree
+/// search, using UCB1 to balance exploring new moves against exploiting good
+/// ones, and uniformly random playouts to evaluate new positions.
+///
+/// Playouts prove nothing about the outcome, so every score is reported as no
+/// information, which iterative deepening and caching solvers won't mistake
+/// for a proof. How likely the chosen move is to win is available from
+/// `win_rate` instead.
+pub struct MctsSolver<G: Game> {
+  iterations: u32,
+  exploration: f64,
+  rng: StdRng,
+  /// The win rate of the move chosen by the last search.
+  win_rate: Option<f64>,
+  tree: Vec<Node<G::Move>>,
+}
+
+impl<G: Game> MctsSolver<G> {
+  /// The UCB1 exploration constant which is optimal for rewards in `[0, 1]`.
+  pub const DEFAULT_EXPLORATION: f64 = std::f64::consts::SQRT_2;
+
+  /// Constructs a solver which runs `iterations` playouts per search, with
+  /// randomness seeded by `seed`.
+  pub fn new(iterations: u32, seed: u64) -> Self {
+    Self {
+      iterations,
+      exploration: Self::DEFAULT_EXPLORATION,
+      rng: StdRng::seed_from_u64(seed),
+      win_rate: None,
+      tree: Vec::new(),
+    }
+  }
+
+  /// Sets the UCB1 exploration constant. Larger values explore more.
+  pub fn with_exploration(mut self, exploration: f64) -> Self {
+    self.exploration = exploration;
+    self
+  }
+
+  /// The fraction of playouts won by the move chosen in the last search, from
+  /// the perspective of the player making it, or `None` if no move was chosen.
+  pub fn win_rate(&self) -> Option<f64> {
+    self.win_rate
+  }
+
+  fn add_node(&mut self, game: &G, m: Option<G::Move>, parent: Option<usize>, mover: GamePlayer) {
+    let untried = if game.finished().is_finished() {
+      Vec::new()
+    } else {
+      game.each_move().collect()
+    };
+    let idx = self.tree.len();
+    self.tree.push(Node {
+      m,
+      parent,
+      mover,
+      children: Vec::new(),
+      untried,
+      visits: 0,
+      reward: 0.0,
+    });
+    if let Some(parent) = parent {
+      self.tree[parent].children.push(idx);
+    }
+  }
+
+  /// The child of `node` with the highest upper confidence bound.
+  fn select_child(&self, node: usize) -> usize {
+    let ln_visits = (self.tree[node].visits as f64).ln();
+    let ucb = |child: usize| {
+      let child = &self.tree[child];
+      child.win_rate() + self.exploration * (ln_visits / child.visits as f64).sqrt()
+    };
+    self.tree[node]
+      .children
+      .iter()
+      .copied()
+      .max_by(|&child1, &child2| ucb(child1).total_cmp(&ucb(child2)))
+      .unwrap()
+  }
+
+  /// Runs one iteration of selection, expansion, playout, and
+  /// backpropagation from `root`, searching at most `depth` moves deep.
+  fn iterate(&mut self, root: &G, depth: u32) {
+    let mut game = root.clone();
+    let mut node = 0;
+    let mut plies = 0;
+
+    while self.tree[node].untried.is_empty() && !self.tree[node].children.is_empty() {
+      node = self.select_child(node);
+      game.make_move(self.tree[node].m.unwrap());
+      plies += 1;
+    }
+
+    if plies < depth && !self.tree[node].untried.is_empty() {
+      let untried = &mut self.tree[node].untried;
+      let m = untried.swap_remove(self.rng.random_range(0..untried.len()));
+      let mover = game.current_player();
+      game.make_move(m);
+      self.add_node(&game, Some(m), Some(node), mover);
+      node = self.tree.len() - 1;
+      plies += 1;
+    }
+
+    // Playouts stop at the search horizon, which `MoveLimited` declares a tie.
+    let result = rollout(&mut MoveLimited::new(game, depth - plies), &mut self.rng);
+    let mut node = Some(node);
+    while let Some(idx) = node {
+      let node_ref = &mut self.tree[idx];
+      node_ref.visits += 1;
+      node_ref.reward += match result {
+        GameResult::Win(player) if player == node_ref.mover => 1.0,
+        GameResult::Win(_) => 0.0,
+        // Games which stopped with no moves left are scored like ties.
+        GameResult::Tie | GameResult::NotFinished => 0.5,
+      };
+      node = node_ref.parent;
+    }
+  }
+}
+
+impl<G: Game> Solver for MctsSolver<G> {
+  type Game = G;
+
+  fn best_move(&mut self, game: &G, depth: u32) -> (Score, Option<G::Move>) {
+    self.win_rate = None;
+    if depth == 0 || game.finished().is_finished() {
+      return (Score::NO_INFO, None);
+    }
+
+    self.tree.clear();
+    self.add_node(game, None, None, game.current_player().opposite());
+    for _ in 0..self.iterations {
+      self.iterate(game, depth);
+    }
+
+    let Some(best) = self.tree[0]
+      .children
+      .iter()
+      .map(|&child| &self.tree[child])
+      // `max_by_key` returns the last maximum, so this prefers earlier moves.
+      .rev()
+      .max_by_key(|child| child.visits)
+    else {
+      return (Score::NO_INFO, None);
+    };
+
+    self.win_rate = Some(best.win_rate());
+    (Score::NO_INFO, best.m)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    solvers::MctsSolver,
+    test_games::{Nim, TTTMove, TicTacToe},
+    Game, Solver,
+  };
+
+  #[gtest]
+  fn test_nim_forced_win() {
+    for sticks in [4, 5, 7, 8, 10] {
+      let mut solver = MctsSolver::new(2000, 1275);
+      let (score, m) = solver.best_move(&Nim::new(sticks), 20);
+      expect_that!(m, some(eq(sticks % 3)), "{sticks} sticks");
+      expect_true!(score.has_no_info(), "{sticks} sticks: {score}");
+      expect_that!(solver.win_rate(), some(ge(0.9)), "{sticks} sticks");
+    }
+  }
+
+  #[gtest]
+  fn test_search_not_exact() {
+    // Even a move which wins nearly every playout isn't proven to win.
+    let mut solver = MctsSolver::new(2000, 1275);
+    let result = solver.search(&Nim::new(4), 20);
+    expect_that!(result.best_move, some(eq(1)));
+    expect_false!(result.exact);
+    expect_false!(result.score.is_solved());
+  }
+
+  #[gtest]
+  fn test_tic_tac_toe_blocks() {
+    // O must block X's top row.
+    let mut game = TicTacToe::new();
+    for coord in [(0, 2), (1, 1), (1, 2)] {
+      game.make_move(TTTMove::new(coord));
+    }
+    let (_, m) = MctsSolver::new(5000, 1275).best_move(&game, 9);
+    expect_that!(m, some(eq(TTTMove::new((2, 2)))));
+  }
+
+  #[gtest]
+  fn test_deterministic() {
+    let game = TicTacToe::new();
+    let mut solver1 = MctsSolver::new(500, 1);
+    let mut solver2 = MctsSolver::new(500, 1);
+    expect_eq!(solver1.best_move(&game, 9), solver2.best_move(&game, 9));
+    expect_eq!(solver1.win_rate(), solver2.win_rate());
+  }
+
+  #[gtest]
+  fn test_finished() {
+    let mut solver = MctsSolver::new(100, 1);
+    expect_that!(solver.best_move(&Nim::new(0), 5).1, none());
+    expect_that!(solver.win_rate(), none());
+  }
+}
@@ -0,0 +1,141 @@
+use std::collections::HashMap;
+
+use crate::{Game, Score, SearchStats, Solver};
+
+/// A cached result of `best_move`, along with the depth it was searched to.
+type Entry<M> = (Score, u32, Option<M>);
+
+/// Counts of cache lookups made by a `MemoSolver`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+  /// Searches answered from the cache.
+  pub hits: u64,
+  /// Searches passed to the wrapped solver, either because the position wasn't
+  /// cached or because its cached score wasn't determined to the requested
+  /// depth.
+  pub misses: u64,
+}
+
+/// Wraps a solver, caching the results of `best_move` by `Game::position_key`.
+/// Cached scores are only reused for searches they are determined to.
+pub struct MemoSolver<S: Solver> {
+  solver: S,
+  table: HashMap<u64, Entry<<S::Game as Game>::Move>>,
+  stats: MemoStats,
+  last_stats: SearchStats,
+}
+
+impl<S: Solver> MemoSolver<S> {
+  pub fn new(solver: S) -> Self {
+    Self {
+      solver,
+      table: HashMap::new(),
+      stats: MemoStats::default(),
+      last_stats: SearchStats::default(),
+    }
+  }
+
+  pub fn solver(&self) -> &S {
+    &self.solver
+  }
+
+  /// The number of positions cached.
+  pub fn len(&self) -> usize {
+    self.table.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.table.is_empty()
+  }
+
+  pub fn clear(&mut self) {
+    self.table.clear();
+  }
+
+  /// The cache hits and misses since this solver was constructed.
+  pub fn stats(&self) -> MemoStats {
+    self.stats
+  }
+
+  /// The stats of the last call to `best_move`. Only cache hits are counted,
+  /// since the wrapped solver's searches are opaque.
+  pub fn last_stats(&self) -> SearchStats {
+    self.last_stats.clone()
+  }
+}
+
+impl<S: Solver> Solver for MemoSolver<S> {
+  type Game = S::Game;
+
+  fn best_move(&mut self, game: &S::Game, depth: u32) -> (Score, Option<<S::Game as Game>::Move>) {
+    self.last_stats = SearchStats { depth, ..SearchStats::default() };
+    let key = game.position_key();
+    if let Some(&(score, _, m)) = self.table.get(&key) {
+      if score.determined(depth) {
+        self.stats.hits += 1;
+        self.last_stats.cache_hits += 1;
+        return (score, m);
+      }
+    }
+    self.stats.misses += 1;
+
+    let (score, m) = self.solver.best_move(game, depth);
+    // Keep whichever of the cached and new results was searched deeper.
+    if self
+      .table
+      .get(&key)
+      .is_none_or(|&(_, cached_depth, _)| depth >= cached_depth)
+    {
+      self.table.insert(key, (score, depth, m));
+    }
+    (score, m)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    solvers::{MemoSolver, MemoStats},
+    test_games::{ConnectN, Nim},
+    test_util::NegamaxSolver,
+    Solver,
+  };
+
+  #[gtest]
+  fn test_matches_inner_solver() {
+    let mut solver = MemoSolver::new(NegamaxSolver::new());
+    for sticks in 1..=10 {
+      let game = Nim::new(sticks);
+      let expected = NegamaxSolver::new().best_move(&game, 12);
+      expect_eq!(solver.best_move(&game, 12), expected);
+      expect_eq!(solver.best_move(&game, 12), expected);
+    }
+    expect_eq!(solver.len(), 10);
+  }
+
+  #[gtest]
+  fn test_cache_hit() {
+    let game = ConnectN::new(3, 3, 3);
+    let mut solver = MemoSolver::new(NegamaxSolver::new());
+    let result = solver.best_move(&game, 4);
+    let nodes = solver.solver().stats().nodes_expanded;
+    expect_eq!(solver.stats(), MemoStats { hits: 0, misses: 1 });
+
+    expect_eq!(solver.best_move(&game, 4), result);
+    expect_eq!(solver.solver().stats().nodes_expanded, nodes);
+    expect_eq!(solver.stats(), MemoStats { hits: 1, misses: 1 });
+    expect_eq!(solver.last_stats().cache_hits, 1);
+
+    // A deeper search isn't answered by the shallower cached score.
+    solver.best_move(&game, 5);
+    expect_gt!(solver.solver().stats().nodes_expanded, nodes);
+    expect_eq!(solver.stats(), MemoStats { hits: 1, misses: 2 });
+    expect_eq!(solver.last_stats().cache_hits, 0);
+
+    // The deeper result answers shallower searches.
+    expect_true!(solver.best_move(&game, 4).0.determined(4));
+    expect_eq!(solver.stats(), MemoStats { hits: 2, misses: 2 });
+  }
+}
@@ -0,0 +1,356 @@
+use std::{
+  marker::PhantomData,
+  sync::{Arc, Mutex},
+};
+
+use itertools::Itertools;
+
+use crate::{complete_solver::CompleteSolver, Game, GameResult, Score, SearchStats, Solver};
+
+/// A depth-limited negamax solver with alpha-beta pruning.
+///
+/// Internally, every position is valued as an optimal win/loss in some number
+/// of moves, or a tie, with positions at the search horizon valued as ties.
+/// A tie at the root is reported as `Score::tie(depth)` if the horizon was
+/// reached during the search, and `Score::guaranteed_tie()` otherwise.
+///
+/// Among equally good moves, the smallest move is chosen.
+///
+/// Clones share the callback set with `set_on_visit`.
+#[derive(Clone)]
+pub struct MinimaxSolver<G> {
+  /// True if the current search reached a position at the search horizon.
+  hit_horizon: bool,
+  /// If false, every move is searched, for comparison against pruned search.
+  pruning: bool,
+  stats: SearchStats,
+  /// Called on entering each node of the search, see `set_on_visit`.
+  on_visit: Option<Arc<Mutex<VisitCallback<G>>>>,
+  _game: PhantomData<G>,
+}
+
+type VisitCallback<G> = dyn FnMut(&G, u32, Score) + Send;
+
+impl<G> MinimaxSolver<G> {
+  pub fn new() -> Self {
+    Self {
+      hit_horizon: false,
+      pruning: true,
+      stats: SearchStats::default(),
+      on_visit: None,
+      _game: PhantomData,
+    }
+  }
+
+  /// Enables or disables alpha-beta pruning. Disabling it gives the same
+  /// results more slowly, and is only useful for measuring pruning.
+  pub fn with_pruning(mut self, pruning: bool) -> Self {
+    self.pruning = pruning;
+    self
+  }
+
+  /// Sets a callback to call on entering each node of the search, e.g. to
+  /// visualize it. The callback is given the position, the depth it will be
+  /// searched to, and the best score the player to move is already assured of
+  /// from elsewhere in the search, which is `Score::WORST` at the root.
+  pub fn set_on_visit(&mut self, on_visit: impl FnMut(&G, u32, Score) + Send + 'static) {
+    self.on_visit = Some(Arc::new(Mutex::new(on_visit)));
+  }
+
+  /// Removes the callback set with `set_on_visit`.
+  pub fn clear_on_visit(&mut self) {
+    self.on_visit = None;
+  }
+
+  fn visit(&self, game: &G, depth: u32, alpha: Score) {
+    if let Some(on_visit) = &self.on_visit {
+      (on_visit.lock().unwrap())(game, depth, alpha);
+    }
+  }
+
+  /// The stats of the last call to `best_move`.
+  pub fn last_stats(&self) -> SearchStats {
+    self.stats.clone()
+  }
+}
+
+impl<G> Default for MinimaxSolver<G> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<G: Game> MinimaxSolver<G> {
+  /// The value of making move `m` in `game`, from the perspective of the
+  /// player making the move. Only distinguishes values strictly between
+  /// `alpha` and `beta`.
+  fn move_value(&mut self, game: &G, m: G::Move, depth: u32, alpha: Score, beta: Score) -> Score {
+    let child = game.with_move(m);
+    if !child.is_terminal() && depth > 1 {
+      let (mover, next) = (game.current_player(), child.current_player());
+      let (alpha, beta) = (
+        alpha.forwardstep_between(mover, next),
+        beta.forwardstep_between(mover, next),
+      );
+      // The window is only negated if the turn passes to the other player.
+      let (alpha, beta) = if mover == next {
+        (alpha, beta)
+      } else {
+        (beta, alpha)
+      };
+      return self
+        .search(&child, depth - 1, alpha, beta)
+        .backstep_between(mover, next);
+    }
+
+    self.stats.leaves_evaluated += 1;
+    match child.finished() {
+      GameResult::Win(player) => {
+        if player == game.current_player() {
+          Score::optimal_win(1)
+        } else {
+          Score::optimal_lose(1)
+        }
+      }
+      GameResult::Tie => Score::guaranteed_tie(),
+      GameResult::NotFinished => {
+        self.hit_horizon = true;
+        Score::guaranteed_tie()
+      }
+    }
+  }
+
+  /// The value of `game` for the current player, searching `depth` moves deep.
+  /// Values outside of `(alpha, beta)` are only bounds on the true value.
+  fn search(&mut self, game: &G, depth: u32, mut alpha: Score, beta: Score) -> Score {
+    self.stats.nodes_expanded += 1;
+    self.visit(game, depth, alpha);
+    let mut best = Score::WORST;
+    for m in game.each_move() {
+      let value = self.move_value(game, m, depth, alpha, beta);
+      if value.better(best) {
+        best = value;
+        if best.better(alpha) {
+          alpha = best;
+        }
+        if self.pruning && !beta.better(alpha) {
+          self.stats.beta_cutoffs += 1;
+          break;
+        }
+      }
+    }
+    best
+  }
+}
+
+impl<G: Game> Solver for MinimaxSolver<G>
+where
+  G::Move: Ord,
+{
+  type Game = G;
+
+  fn best_move(&mut self, game: &G, depth: u32) -> (Score, Option<G::Move>) {
+    self.stats = SearchStats { depth, ..SearchStats::default() };
+    if depth == 0 {
+      return (Score::NO_INFO, None);
+    }
+
+    self.stats.nodes_expanded += 1;
+    self.visit(game, depth, Score::WORST);
+    self.hit_horizon = false;
+    let mut alpha = Score::WORST;
+    let mut best = None;
+    for m in game.each_move().sorted() {
+      let value = self.move_value(game, m, depth, alpha, Score::BEST);
+      if best.is_none_or(|(best_value, _)| value.better(best_value)) {
+        best = Some((value, m));
+        if value.better(alpha) {
+          alpha = value;
+        }
+      }
+    }
+
+    match best {
+      Some((value, m)) => {
+        let score = if value.is_tie() && self.hit_horizon {
+          Score::tie(depth)
+        } else {
+          value
+        };
+        (score, Some(m))
+      }
+      None => (Score::NO_INFO, None),
+    }
+  }
+}
+
+impl<G: Game> CompleteSolver for MinimaxSolver<G> where G::Move: Ord {}
+
+#[cfg(test)]
+mod tests {
+  use std::sync::{Arc, Mutex};
+
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+  use rand::{rngs::StdRng, SeedableRng};
+
+  use crate::{
+    complete_solver::CompleteSolver,
+    determined_score::DeterminedScore,
+    solvers::MinimaxSolver,
+    test_games::{ConnectN, Nim, TTTMove, TicTacToe},
+    test_util::{diff_rankings, generate_deterministic_random_unfinished_states, NegamaxSolver},
+    Game, Score, Solver,
+  };
+
+  #[gtest]
+  fn test_nim_parity() {
+    let mut solver = MinimaxSolver::new();
+    for sticks in 1..=30 {
+      let (score, m) = solver.best_move(&Nim::new(sticks), 40);
+      if sticks % 3 == 0 {
+        expect_true!(score.is_losing(), "{sticks} sticks: {score}");
+      } else {
+        expect_true!(score.is_winning(), "{sticks} sticks: {score}");
+        expect_that!(m, some(eq(sticks % 3)));
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_nim_matches_negamax() {
+    for sticks in 1..=12 {
+      let game = Nim::new(sticks);
+      for depth in 1..=10 {
+        expect_eq!(
+          MinimaxSolver::new().best_move(&game, depth),
+          NegamaxSolver::new().best_move(&game, depth),
+          "{sticks} sticks to depth {depth}"
+        );
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_tic_tac_toe_tie() {
+    let mut solver = MinimaxSolver::new();
+    let (score, m) = solver.best_move_determined(&TicTacToe::new(), 9);
+    expect_eq!(score, DeterminedScore::guaranteed_tie());
+    expect_that!(m, some(anything()));
+
+    // Nothing is decided in the first few moves.
+    expect_eq!(solver.best_move(&TicTacToe::new(), 4).0, Score::tie(4));
+  }
+
+  #[gtest]
+  fn test_on_visit() {
+    let game = TicTacToe::new()
+      .with_move(TTTMove::new((1, 1)))
+      .with_move(TTTMove::new((0, 0)));
+    let visits = Arc::new(Mutex::new(Vec::new()));
+    let mut solver = MinimaxSolver::new();
+    let recorded = visits.clone();
+    solver.set_on_visit(move |game: &TicTacToe, depth, _| {
+      recorded.lock().unwrap().push((game.clone(), depth));
+    });
+
+    let result = solver.best_move(&game, 7);
+    expect_eq!(result, MinimaxSolver::new().best_move(&game, 7));
+    let first_visits = visits.lock().unwrap().drain(..).collect_vec();
+    expect_eq!(
+      first_visits.len() as u64,
+      solver.last_stats().nodes_expanded
+    );
+    expect_eq!(first_visits[0], (game.clone(), 7));
+    expect_true!(first_visits.iter().all(|&(_, depth)| depth <= 7));
+
+    // Searching again visits the same nodes in the same order.
+    solver.best_move(&game, 7);
+    expect_eq!(*visits.lock().unwrap(), first_visits);
+  }
+
+  #[gtest]
+  fn test_tic_tac_toe_matches_negamax() {
+    let mut rng = StdRng::seed_from_u64(1270);
+    let states =
+      generate_deterministic_random_unfinished_states(&TicTacToe::new(), 50, 3, &mut rng).unwrap();
+    for game in states {
+      expect_eq!(
+        MinimaxSolver::new().best_move(&game, 9),
+        NegamaxSolver::new().best_move(&game, 9),
+        "{game:?}"
+      );
+    }
+  }
+
+  #[gtest]
+  fn test_connect_n_matches_negamax_outcome() {
+    let mut rng = StdRng::seed_from_u64(1270);
+    let states =
+      generate_deterministic_random_unfinished_states(&ConnectN::new(4, 4, 3), 20, 6, &mut rng)
+        .unwrap();
+    for game in states {
+      let (score, _) = MinimaxSolver::new().best_move(&game, 4);
+      let (expected, _) = NegamaxSolver::new().best_move(&game, 4);
+      expect_true!(
+        score.compatible(expected),
+        "{score} vs {expected}: {game:?}"
+      );
+      expect_true!(
+        score.same_outcome(expected),
+        "{score} vs {expected}: {game:?}"
+      );
+    }
+  }
+
+  #[gtest]
+  fn test_connect_n_rankings_match_negamax() {
+    let mut rng = StdRng::seed_from_u64(1285);
+    let states =
+      generate_deterministic_random_unfinished_states(&ConnectN::new(4, 4, 3), 10, 6, &mut rng)
+        .unwrap();
+    for game in states {
+      let diff = diff_rankings(
+        &mut MinimaxSolver::new(),
+        &mut NegamaxSolver::new(),
+        &game,
+        4,
+      );
+      expect_eq!(diff.len(), game.each_move().count(), "{game:?}");
+      for (m, score, expected) in diff {
+        expect_true!(
+          score.same_outcome(expected),
+          "{m:?}: {score} vs {expected}: {game:?}"
+        );
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_stats() {
+    let game = TicTacToe::new();
+    let mut solver = MinimaxSolver::new();
+    solver.best_move(&game, 9);
+    let stats = solver.last_stats();
+    expect_eq!(stats.depth, 9);
+    expect_that!(stats.nodes_expanded, gt(100));
+    expect_gt!(stats.beta_cutoffs, 0);
+    expect_gt!(stats.leaves_evaluated, 0);
+    expect_eq!(stats.cache_hits, 0);
+
+    // Stats are per search, and deterministic.
+    solver.best_move(&game, 9);
+    expect_eq!(solver.last_stats(), stats);
+
+    let mut unpruned = MinimaxSolver::new().with_pruning(false);
+    expect_eq!(unpruned.best_move(&game, 9), solver.best_move(&game, 9));
+    // Every unfinished position in the game tree, counting transpositions
+    // separately.
+    expect_eq!(unpruned.last_stats().nodes_expanded, 294778);
+    expect_eq!(unpruned.last_stats().beta_cutoffs, 0);
+    expect_lt!(
+      stats.nodes_expanded,
+      unpruned.last_stats().nodes_expanded / 10
+    );
+  }
+}
@@ -0,0 +1,19 @@
+mod book_solver;
+mod checked_solver;
+mod heuristic_solver;
+mod mcts_solver;
+mod memo_solver;
+mod minimax_solver;
+#[cfg(feature = "rayon")]
+mod parallel_solver;
+mod tablebase_solver;
+
+pub use book_solver::*;
+pub use checked_solver::*;
+pub use heuristic_solver::*;
+pub use mcts_solver::*;
+pub use memo_solver::*;
+pub use minimax_solver::*;
+#[cfg(feature = "rayon")]
+pub use parallel_solver::*;
+pub use tablebase_solver::*;
@@ -0,0 +1,96 @@
+use itertools::Itertools;
+use rayon::prelude::*;
+
+use crate::{solver::move_score, Game, Score, Solver};
+
+/// Wraps a solver, searching each of the root's moves in parallel on its own
+/// clone of the wrapped solver.
+///
+/// Among equally good moves, the smallest move is chosen, so results don't
+/// depend on the order in which threads finish.
+pub struct ParallelSolver<S> {
+  solver: S,
+}
+
+impl<S> ParallelSolver<S> {
+  pub fn new(solver: S) -> Self {
+    Self { solver }
+  }
+
+  pub fn solver(&self) -> &S {
+    &self.solver
+  }
+}
+
+impl<S> Solver for ParallelSolver<S>
+where
+  S: Solver + Clone + Send + Sync,
+  S::Game: Sync,
+  <S::Game as Game>::Move: Ord + Send + Sync,
+{
+  type Game = S::Game;
+
+  fn best_move(&mut self, game: &S::Game, depth: u32) -> (Score, Option<<S::Game as Game>::Move>) {
+    if depth == 0 {
+      return (Score::NO_INFO, None);
+    }
+
+    let moves = game.each_move().sorted().collect_vec();
+    let scores = moves
+      .par_iter()
+      .map(|&m| move_score(&mut self.solver.clone(), game, m, depth))
+      .collect::<Vec<_>>();
+
+    moves
+      .into_iter()
+      .zip(scores)
+      .fold(None, |best: Option<(Score, _)>, (m, score)| match best {
+        Some((best_score, _)) if !score.better(best_score) => best,
+        _ => Some((score, m)),
+      })
+      .map_or((Score::NO_INFO, None), |(score, m)| (score, Some(m)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use rand::{rngs::StdRng, SeedableRng};
+
+  use crate::{
+    solvers::{MinimaxSolver, ParallelSolver},
+    test_games::{Nim, TicTacToe},
+    test_util::generate_deterministic_random_unfinished_states,
+    Solver,
+  };
+
+  #[gtest]
+  fn test_matches_minimax() {
+    let mut rng = StdRng::seed_from_u64(1276);
+    let mut states =
+      generate_deterministic_random_unfinished_states(&TicTacToe::new(), 20, 3, &mut rng).unwrap();
+    states.push(TicTacToe::new());
+
+    let mut solver = ParallelSolver::new(MinimaxSolver::new());
+    for game in states {
+      expect_eq!(
+        solver.best_move(&game, 9),
+        MinimaxSolver::new().best_move(&game, 9),
+        "{game:?}"
+      );
+    }
+  }
+
+  #[gtest]
+  fn test_nim() {
+    let mut solver = ParallelSolver::new(MinimaxSolver::new());
+    for sticks in 1..=12 {
+      let game = Nim::new(sticks);
+      expect_eq!(
+        solver.best_move(&game, 15),
+        MinimaxSolver::new().best_move(&game, 15),
+        "{sticks} sticks"
+      );
+    }
+  }
+}
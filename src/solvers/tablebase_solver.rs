@@ -0,0 +1,201 @@
+use std::collections::HashMap;
+
+use crate::{determined_score::DeterminedScore, Game, GameResult, Score, Solver};
+
+/// A solved position, along with the best move from it, if it isn't finished.
+type Entry<M> = (DeterminedScore, Option<M>);
+
+/// A solver which looks up positions in a table of every position reachable
+/// from some root, solved ahead of time. The game must be finite, i.e. every
+/// line of play from the root must eventually finish.
+///
+/// Positions are keyed by `Game::position_key`. Positions which aren't in the
+/// table can't be solved.
+pub struct TablebaseSolver<G: Game> {
+  table: HashMap<u64, Entry<G::Move>>,
+}
+
+impl<G: Game> TablebaseSolver<G> {
+  /// Builds a tablebase of every unfinished position reachable from `root`.
+  pub fn build(root: &G) -> Self {
+    let mut solver = Self { table: HashMap::new() };
+    if !root.finished().is_finished() {
+      solver.solve(root);
+    }
+    solver
+  }
+
+  /// The number of positions in the table.
+  pub fn len(&self) -> usize {
+    self.table.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.table.is_empty()
+  }
+
+  /// Returns the score of `game`, or `None` if it isn't in the table.
+  pub fn lookup(&self, game: &G) -> Option<DeterminedScore> {
+    self
+      .table
+      .get(&game.position_key())
+      .map(|&(score, _)| score)
+  }
+
+  /// Looks up each of `games`, with `None` for each position which isn't in
+  /// the table.
+  pub fn lookup_batch(&self, games: &[G]) -> Vec<Option<DeterminedScore>> {
+    games.iter().map(|game| self.lookup(game)).collect()
+  }
+
+  /// Solves the unfinished position `game` and every position reachable from
+  /// it, returning its exact score.
+  fn solve(&mut self, game: &G) -> Score {
+    if let Some(&(score, _)) = self.table.get(&game.position_key()) {
+      return score.into_score();
+    }
+
+    let mut best: Option<(Score, G::Move)> = None;
+    for (m, child) in game.successors() {
+      let score = match child.finished() {
+        GameResult::Win(player) => {
+          if player == game.current_player() {
+            Score::optimal_win(1)
+          } else {
+            Score::optimal_lose(1)
+          }
+        }
+        GameResult::Tie => Score::guaranteed_tie(),
+        GameResult::NotFinished => self
+          .solve(&child)
+          .backstep_between(game.current_player(), child.current_player()),
+      };
+      if best.is_none_or(|(best_score, _)| score.better(best_score)) {
+        best = Some((score, m));
+      }
+    }
+
+    let (score, m) = match best {
+      Some((score, m)) => (score, Some(m)),
+      // Games with no moves that haven't finished can't progress, so are ties.
+      None => (Score::guaranteed_tie(), None),
+    };
+    let determined = DeterminedScore::from_score(score)
+      .unwrap_or_else(|| panic!("Expected a determined score, got {score}"));
+    self.table.insert(game.position_key(), (determined, m));
+    score
+  }
+}
+
+impl<G: Game> Solver for TablebaseSolver<G> {
+  type Game = G;
+
+  /// Returns the exact score of positions in the table regardless of `depth`,
+  /// since it's known anyway.
+  fn best_move(&mut self, game: &G, _depth: u32) -> (Score, Option<G::Move>) {
+    match self.table.get(&game.position_key()) {
+      Some(&(score, m)) => (score.into_score(), m),
+      None => (Score::NO_INFO, None),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+  use rand::{rngs::StdRng, SeedableRng};
+
+  use crate::{
+    determined_score::DeterminedScore,
+    solvers::{MinimaxSolver, TablebaseSolver},
+    test_games::{DotsAndBoxes, Nim, TTTMove, TicTacToe},
+    test_util::{generate_deterministic_random_unfinished_states, NegamaxSolver},
+    Game, Solver,
+  };
+
+  #[gtest]
+  fn test_nim() {
+    let game = Nim::new(10);
+    let tablebase = TablebaseSolver::build(&game);
+    expect_that!(tablebase.lookup(&game), some(eq(DeterminedScore::win(7))));
+    expect_that!(
+      tablebase.lookup(&game.with_move(1)),
+      some(eq(DeterminedScore::lose(6)))
+    );
+    // Player 1 can never be left with 9 sticks.
+    expect_that!(tablebase.lookup(&Nim::new(9)), none());
+  }
+
+  #[gtest]
+  fn test_tic_tac_toe_matches_negamax() {
+    let mut tablebase = TablebaseSolver::build(&TicTacToe::new());
+    expect_that!(
+      tablebase.lookup(&TicTacToe::new()),
+      some(eq(DeterminedScore::guaranteed_tie()))
+    );
+
+    let mut rng = StdRng::seed_from_u64(1275);
+    let states =
+      generate_deterministic_random_unfinished_states(&TicTacToe::new(), 50, 4, &mut rng).unwrap();
+    for game in states {
+      let (score, _) = tablebase.best_move(&game, 9);
+      expect_eq!(
+        score,
+        NegamaxSolver::new().best_move(&game, 9).0,
+        "{game:?}"
+      );
+
+      // Shallower searches are answered with the full solution.
+      let (shallow_score, _) = NegamaxSolver::new().best_move(&game, 2);
+      expect_true!(score.compatible(shallow_score), "{game:?}");
+    }
+  }
+
+  #[gtest]
+  fn test_dots_and_boxes_matches_minimax() {
+    // Completing a box grants an extra turn, so the same board is reachable
+    // with either player to move, and those positions must be keyed apart.
+    let root = DotsAndBoxes::new(2, 2);
+    let mut tablebase = TablebaseSolver::build(&root);
+
+    let mut rng = StdRng::seed_from_u64(1285);
+    let states = generate_deterministic_random_unfinished_states(&root, 100, 6, &mut rng).unwrap();
+    for game in states {
+      let (score, _) = tablebase.best_move(&game, 12);
+      expect_eq!(
+        score,
+        MinimaxSolver::new().best_move(&game, 12).0,
+        "{game:?}"
+      );
+    }
+  }
+
+  #[gtest]
+  fn test_lookup_batch() {
+    let mut x_center = TicTacToe::new();
+    x_center.make_move(TTTMove::new((1, 1)));
+    let tablebase = TablebaseSolver::build(&x_center);
+
+    let mut x_corner = TicTacToe::new();
+    x_corner.make_move(TTTMove::new((0, 0)));
+
+    // X has already won, so play can't continue.
+    let mut finished = TicTacToe::new();
+    for coord in [(0, 0), (0, 1), (1, 0), (1, 1), (2, 0)] {
+      finished.make_move(TTTMove::new(coord));
+    }
+    let mut past_finished = finished.clone();
+    past_finished.make_move(TTTMove::new((2, 1)));
+
+    let mut o_corner = x_center.clone();
+    o_corner.make_move(TTTMove::new((0, 0)));
+
+    let scores = tablebase.lookup_batch(&[x_center, x_corner, finished, past_finished, o_corner]);
+    expect_eq!(
+      scores.iter().map(Option::is_some).collect_vec(),
+      vec![true, false, false, false, true]
+    );
+    expect_that!(scores[0], some(eq(DeterminedScore::guaranteed_tie())));
+  }
+}
@@ -3,7 +3,9 @@ use std::{
   hint::unreachable_unchecked,
 };
 
-use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+use itertools::Itertools;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult, GameSerialize, UndoableGame};
 
 trait InARow<U> {
   fn in_a_row(self, n: u32) -> Option<U>;
@@ -78,16 +80,33 @@ impl From<TileState> for Option<GamePlayer> {
   }
 }
 
+/// A pseudorandom key for a player's piece being at board index `idx`, for
+/// Zobrist hashing.
+const fn zobrist_key(idx: u32, player: GamePlayer) -> u64 {
+  // splitmix64
+  let seed = ((idx as u64) << 1) | matches!(player, GamePlayer::Player2) as u64;
+  let mut z = seed.wrapping_add(0x9e3779b97f4a7c15);
+  z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+  z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+  z ^ (z >> 31)
+}
+
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct ConnectN {
   board: Vec<u32>,
   width: u32,
   height: u32,
   in_a_row: u32,
+  /// The Zobrist hash of the pieces on the board.
+  hash: u64,
 }
 
 impl ConnectN {
+  /// The widest supported board, which bounds the number of legal moves.
+  pub const MAX_WIDTH: u32 = 32;
+
   pub fn new(width: u32, height: u32, in_a_row: u32) -> Self {
+    debug_assert!(width <= Self::MAX_WIDTH);
     debug_assert!(in_a_row <= width);
     debug_assert!(in_a_row <= height);
     Self {
@@ -95,6 +114,7 @@ impl ConnectN {
       width,
       height,
       in_a_row,
+      hash: 0,
     }
   }
 
@@ -106,6 +126,20 @@ impl ConnectN {
     self.height
   }
 
+  /// The player with a piece at column `x`, row `y`, if any. Rows are counted
+  /// from the bottom, so pieces dropped into an empty column land in row 0.
+  pub fn cell(&self, x: u32, y: u32) -> Option<GamePlayer> {
+    self.at((x, y)).into()
+  }
+
+  /// The occupants of every cell, indexed by `[y][x]` with row 0 at the
+  /// bottom, as in `cell`.
+  pub fn rows(&self) -> Vec<Vec<Option<GamePlayer>>> {
+    (0..self.height)
+      .map(|y| (0..self.width).map(|x| self.cell(x, y)).collect())
+      .collect()
+  }
+
   fn pos_to_idx(&self, pos: (u32, u32)) -> (u32, usize) {
     debug_assert!((0..self.width).contains(&pos.0));
     debug_assert!((0..self.height).contains(&pos.1));
@@ -125,6 +159,7 @@ impl ConnectN {
 
   fn set(&mut self, pos: (u32, u32), player: GamePlayer) {
     debug_assert_eq!(self.at(pos), TileState::Empty);
+    self.hash ^= zobrist_key(pos.0 + pos.1 * self.width, player);
     let (bit_idx, v_idx) = self.pos_to_idx(pos);
     self.board[v_idx] += match player {
       GamePlayer::Player1 => 0x1,
@@ -132,19 +167,171 @@ impl ConnectN {
     } << bit_idx;
   }
 
+  fn clear(&mut self, pos: (u32, u32)) {
+    if let Some(player) = self.at(pos).into() {
+      self.hash ^= zobrist_key(pos.0 + pos.1 * self.width, player);
+    }
+    let (bit_idx, v_idx) = self.pos_to_idx(pos);
+    self.board[v_idx] &= !(0x3 << bit_idx);
+  }
+
   fn n_moves_made(&self) -> u32 {
     self.board.iter().map(|b| b.count_ones()).sum()
   }
+
+  /// Builds a position from its rows, listed from the top of the board down,
+  /// with `X` for player 1's pieces, `O` for player 2's pieces, and `.` for
+  /// empty cells. Since player 1 moves first, the player to move is implied by
+  /// the number of pieces.
+  ///
+  /// Fails if the dimensions don't match, or if the position couldn't arise
+  /// in play: pieces must rest on the bottom of the board or another piece,
+  /// player 1 must have as many pieces as player 2 or one more, and only the
+  /// last player to move may have made a line, with their last piece.
+  pub fn from_layout(
+    width: u32,
+    height: u32,
+    in_a_row: u32,
+    rows: &[&str],
+  ) -> Result<Self, String> {
+    if width == 0
+      || width > Self::MAX_WIDTH
+      || height == 0
+      || in_a_row == 0
+      || in_a_row > width
+      || in_a_row > height
+    {
+      return Err(format!(
+        "Invalid dimensions {width}x{height} for {in_a_row} in a row"
+      ));
+    }
+    if rows.len() != height as usize {
+      return Err(format!("Expected {height} rows, found {}", rows.len()));
+    }
+
+    let mut game = Self::new(width, height, in_a_row);
+    for (row, y) in rows.iter().zip((0..height).rev()) {
+      if row.chars().count() != width as usize {
+        return Err(format!("Expected {width} tiles in row \"{row}\""));
+      }
+      for (tile, x) in row.chars().zip(0..) {
+        let player = match tile {
+          '.' => continue,
+          'X' => GamePlayer::Player1,
+          'O' => GamePlayer::Player2,
+          _ => return Err(format!("Unknown tile '{tile}'")),
+        };
+        game.set((x, y), player);
+      }
+    }
+
+    if game.has_floating_piece() {
+      return Err("A piece is floating above an empty cell".to_owned());
+    }
+    if !game.has_legal_piece_counts() {
+      return Err("X must have as many pieces as O or one more".to_owned());
+    }
+    if !game.has_legal_lines() {
+      return Err("Play would have stopped once the first line was made".to_owned());
+    }
+    Ok(game)
+  }
+
+  /// True if any piece is above an empty cell.
+  fn has_floating_piece(&self) -> bool {
+    (0..self.width)
+      .cartesian_product(1..self.height)
+      .any(|(x, y)| self.at((x, y)) != TileState::Empty && self.at((x, y - 1)) == TileState::Empty)
+  }
+
+  /// True if X, who moves first, has the same number of pieces as O or one
+  /// more.
+  fn has_legal_piece_counts(&self) -> bool {
+    let count = |tile| {
+      (0..self.width)
+        .cartesian_product(0..self.height)
+        .filter(|&pos| self.at(pos) == tile)
+        .count()
+    };
+    let (p1_tiles, p2_tiles) = (count(TileState::P1), count(TileState::P2));
+    p1_tiles == p2_tiles || p1_tiles == p2_tiles + 1
+  }
+
+  /// True if the lines on the board could have been made in play: since play
+  /// stops once a line is made, at most one player may have a line, which the
+  /// last player to move made through the piece they played, the top piece of
+  /// some column.
+  fn has_legal_lines(&self) -> bool {
+    let has_line = |player| {
+      (0..self.width)
+        .cartesian_product(0..self.height)
+        .any(|(x, y)| self.cell(x, y) == Some(player) && self.in_line((x, y)))
+    };
+    let winners = [GamePlayer::Player1, GamePlayer::Player2]
+      .into_iter()
+      .filter(|&player| has_line(player))
+      .collect_vec();
+    match *winners.as_slice() {
+      [] => true,
+      [winner] => winner == self.current_player().opposite() && self.winning_line().is_some(),
+      _ => false,
+    }
+  }
 }
 
 impl Game for ConnectN {
   type Move = ConnectMove;
   type MoveGenerator = ConnectMoveGen;
 
+  const MAX_MOVES: usize = Self::MAX_WIDTH as usize;
+
   fn move_generator(&self) -> ConnectMoveGen {
     ConnectMoveGen { col: 0 }
   }
 
+  fn format_move(&self, m: ConnectMove) -> String {
+    format!("column {}", m.col)
+  }
+
+  fn is_legal_move(&self, m: ConnectMove) -> bool {
+    m.col < self.width && self.at((m.col, self.height - 1)) == TileState::Empty
+  }
+
+  fn position_key(&self) -> u64 {
+    self.hash
+  }
+
+  /// The board and its mirror image.
+  fn symmetries(&self) -> impl Iterator<Item = Self> {
+    [self.clone(), self.mirrored()].into_iter()
+  }
+
+  fn canonical_form(&self) -> Self {
+    let mirrored = self.mirrored();
+    if mirrored.board < self.board {
+      mirrored
+    } else {
+      self.clone()
+    }
+  }
+
+  /// Pieces must rest on the bottom of the board or another piece, X, who
+  /// moves first, must have the same number of pieces as O or one more, and
+  /// only the last player to move may have made a line, with their last piece.
+  fn is_legal_state(&self) -> bool {
+    !self.has_floating_piece() && self.has_legal_piece_counts() && self.has_legal_lines()
+  }
+
+  /// Since play stops once a line is made, any line on the board must pass
+  /// through the last piece played, which is the top piece of some column.
+  /// Only lines through the top of each column need to be checked. Positions
+  /// breaking this, e.g. with a line buried under later pieces, aren't
+  /// `is_legal_state`.
+  fn is_terminal(&self) -> bool {
+    self.n_moves_made() == self.width * self.height
+      || (0..self.width).any(|x| self.top(x).is_some_and(|y| self.in_line((x, y))))
+  }
+
   fn make_move(&mut self, m: ConnectMove) {
     let y = (0..)
       .find(|&y| self.at((m.col, y)) == TileState::Empty)
@@ -220,6 +407,124 @@ impl Game for ConnectN {
   }
 }
 
+impl ConnectN {
+  /// Returns the coordinates of the `in_a_row` pieces which won the game, or
+  /// `None` if no one has won. Like `is_terminal`, this relies on play having
+  /// stopped once a line was made, so the line passes through the top piece
+  /// of some column, which holds for every `is_legal_state` position. If the
+  /// line is longer than `in_a_row`, only `in_a_row` of its cells are
+  /// returned.
+  pub fn winning_line(&self) -> Option<Vec<(u32, u32)>> {
+    (0..self.width).find_map(|x| self.line_through((x, self.top(x)?)))
+  }
+
+  /// Returns the height of the top piece in column `x`, if there are any.
+  fn top(&self, x: u32) -> Option<u32> {
+    (0..self.height)
+      .rev()
+      .find(|&y| self.at((x, y)) != TileState::Empty)
+  }
+
+  /// Returns true if the piece at `pos` is part of `in_a_row` pieces of the
+  /// same player in a row in any direction.
+  fn in_line(&self, pos: (u32, u32)) -> bool {
+    self.line_through(pos).is_some()
+  }
+
+  /// Returns `in_a_row` cells in a row containing `pos` which all hold the
+  /// same player's pieces as `pos`, if there are any.
+  fn line_through(&self, pos: (u32, u32)) -> Option<Vec<(u32, u32)>> {
+    let tile = self.at(pos);
+    debug_assert_ne!(tile, TileState::Empty);
+    let run_length = |(dx, dy): (i32, i32)| {
+      (1..)
+        .map(|i| (pos.0 as i32 + i * dx, pos.1 as i32 + i * dy))
+        .take_while(|&(x, y)| {
+          (0..self.width as i32).contains(&x)
+            && (0..self.height as i32).contains(&y)
+            && self.at((x as u32, y as u32)) == tile
+        })
+        .count() as u32
+    };
+    [(1, 0), (0, 1), (1, 1), (1, -1)]
+      .into_iter()
+      .find_map(|(dx, dy)| {
+        let forward = run_length((dx, dy)).min(self.in_a_row - 1);
+        let backward = (self.in_a_row - 1 - forward).min(run_length((-dx, -dy)));
+        (1 + forward + backward >= self.in_a_row).then(|| {
+          (-(backward as i32)..=forward as i32)
+            .map(|i| {
+              (
+                (pos.0 as i32 + i * dx) as u32,
+                (pos.1 as i32 + i * dy) as u32,
+              )
+            })
+            .collect()
+        })
+      })
+  }
+
+  /// Returns this board reflected across its vertical center line.
+  fn mirrored(&self) -> Self {
+    let mut mirrored = Self::new(self.width, self.height, self.in_a_row);
+    for (x, y) in (0..self.width).cartesian_product(0..self.height) {
+      if let Some(player) = self.at((x, y)).into() {
+        mirrored.set((self.width - 1 - x, y), player);
+      }
+    }
+    mirrored
+  }
+}
+
+impl UndoableGame for ConnectN {
+  fn undo_move(&mut self, m: ConnectMove) {
+    let y = (0..self.height)
+      .rev()
+      .find(|&y| self.at((m.col, y)) != TileState::Empty)
+      .unwrap();
+    self.clear((m.col, y));
+  }
+}
+
+/// The notation is `{width}x{height}:{in_a_row}:{rows}`, where `rows` lists the
+/// rows of the board from top to bottom separated by `/`, e.g.
+/// `3x3:3:.../.O./XX.`.
+impl GameSerialize for ConnectN {
+  fn to_notation(&self) -> String {
+    let rows = (0..self.height)
+      .rev()
+      .map(|y| {
+        (0..self.width)
+          .map(|x| match self.at((x, y)) {
+            TileState::Empty => '.',
+            TileState::P1 => 'X',
+            TileState::P2 => 'O',
+          })
+          .collect::<String>()
+      })
+      .join("/");
+    format!("{}x{}:{}:{rows}", self.width, self.height, self.in_a_row)
+  }
+
+  fn from_notation(notation: &str) -> Result<Self, String> {
+    let parse_num = |num: &str| {
+      num
+        .parse::<u32>()
+        .map_err(|err| format!("Invalid number \"{num}\": {err}"))
+    };
+
+    let Some((dims, in_a_row, rows)) = notation.split(':').collect_tuple() else {
+      return Err(format!("Expected \"WxH:N:rows\", found \"{notation}\""));
+    };
+    let Some((width, height)) = dims.split('x').collect_tuple() else {
+      return Err(format!("Expected dimensions \"WxH\", found \"{dims}\""));
+    };
+    let (width, height, in_a_row) = (parse_num(width)?, parse_num(height)?, parse_num(in_a_row)?);
+    Self::from_layout(width, height, in_a_row, &rows.split('/').collect_vec())
+      .map_err(|err| format!("Illegal position \"{notation}\": {err}"))
+  }
+}
+
 impl Debug for ConnectN {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(f, "{self}")
@@ -252,13 +557,17 @@ impl Display for ConnectN {
 
 #[cfg(test)]
 mod tests {
+  use std::collections::HashSet;
+
   use crate::{
     test_games::{ConnectMove, ConnectN},
-    Game, GamePlayer, GameResult,
+    test_util::make_deterministic_random_move,
+    Game, GamePlayer, GameResult, GameSerialize, UndoableGame,
   };
 
   use googletest::{gtest, prelude::*};
   use itertools::Itertools;
+  use rand::{rngs::StdRng, Rng, SeedableRng};
 
   #[gtest]
   fn test_first_moves() {
@@ -317,6 +626,27 @@ mod tests {
     );
   }
 
+  #[gtest]
+  fn test_is_legal_move() {
+    let mut connect_four = ConnectN::new(7, 6, 4);
+    for _ in 0..6 {
+      connect_four.make_move(ConnectMove { col: 4 });
+    }
+    connect_four.make_move(ConnectMove { col: 0 });
+
+    let legal_moves = connect_four.each_move().collect_vec();
+    for col in 0..10 {
+      let m = ConnectMove { col };
+      expect_eq!(
+        connect_four.is_legal_move(m),
+        legal_moves.contains(&m),
+        "{m:?}"
+      );
+    }
+    expect_false!(connect_four.is_legal_move(ConnectMove { col: 4 }));
+    expect_false!(connect_four.is_legal_move(ConnectMove { col: 7 }));
+  }
+
   #[gtest]
   fn test_not_finished_empty() {
     let connect_four = ConnectN::new(7, 6, 4);
@@ -330,6 +660,20 @@ mod tests {
     expect_eq!(connect_four.finished(), GameResult::NotFinished);
   }
 
+  #[gtest]
+  fn test_no_winning_line() {
+    let mut connect_four = ConnectN::new(7, 6, 4);
+    expect_that!(connect_four.winning_line(), none());
+    for col in [3, 3, 4, 4, 5, 5] {
+      connect_four.make_move(ConnectMove { col });
+      expect_that!(connect_four.winning_line(), none());
+    }
+
+    let tie = ConnectN::from_notation("3x3:3:XOX/XOX/OXO").unwrap();
+    expect_eq!(tie.finished(), GameResult::Tie);
+    expect_that!(tie.winning_line(), none());
+  }
+
   #[gtest]
   fn test_not_finished_one_move_edge() {
     let mut connect_four = ConnectN::new(5, 4, 3);
@@ -352,6 +696,15 @@ mod tests {
       connect_four.finished(),
       GameResult::Win(GamePlayer::Player1)
     );
+    expect_that!(
+      connect_four.winning_line(),
+      some(elements_are![
+        eq(&(0, 0)),
+        eq(&(1, 0)),
+        eq(&(2, 0)),
+        eq(&(3, 0))
+      ])
+    );
   }
 
   #[gtest]
@@ -369,6 +722,15 @@ mod tests {
       connect_four.finished(),
       GameResult::Win(GamePlayer::Player1)
     );
+    expect_that!(
+      connect_four.winning_line(),
+      some(elements_are![
+        eq(&(3, 0)),
+        eq(&(3, 1)),
+        eq(&(3, 2)),
+        eq(&(3, 3))
+      ])
+    );
   }
 
   #[gtest]
@@ -390,6 +752,15 @@ mod tests {
       connect_four.finished(),
       GameResult::Win(GamePlayer::Player1)
     );
+    expect_that!(
+      connect_four.winning_line(),
+      some(elements_are![
+        eq(&(3, 0)),
+        eq(&(4, 1)),
+        eq(&(5, 2)),
+        eq(&(6, 3))
+      ])
+    );
   }
 
   #[gtest]
@@ -411,5 +782,354 @@ mod tests {
       connect_four.finished(),
       GameResult::Win(GamePlayer::Player1)
     );
+    expect_that!(
+      connect_four.winning_line(),
+      some(elements_are![
+        eq(&(0, 3)),
+        eq(&(1, 2)),
+        eq(&(2, 1)),
+        eq(&(3, 0))
+      ])
+    );
+  }
+
+  #[gtest]
+  fn test_undo_move() {
+    let mut rng = StdRng::seed_from_u64(1267);
+    for _ in 0..20 {
+      let mut game = ConnectN::new(7, 6, 4);
+      let mut history = vec![game.clone()];
+      while !game.finished().is_finished() {
+        let m = make_deterministic_random_move(&mut game, &mut rng).unwrap();
+        let mut undone = game.clone();
+        undone.undo_move(m);
+        expect_eq!(&undone, history.last().unwrap());
+        history.push(game.clone());
+      }
+
+      // Unwind the whole game.
+      history.pop();
+      while let Some(prev) = history.pop() {
+        let m = prev
+          .each_move()
+          .find(|&m| prev.with_move(m) == game)
+          .unwrap();
+        game.undo_move(m);
+        expect_eq!(game, prev);
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_position_key() {
+    let mut game1 = ConnectN::new(7, 6, 4);
+    let mut game2 = ConnectN::new(7, 6, 4);
+    for col in [0, 1, 2] {
+      game1.make_move(ConnectMove { col });
+    }
+    for col in [2, 1, 0] {
+      game2.make_move(ConnectMove { col });
+    }
+    expect_eq!(game1.position_key(), game2.position_key());
+
+    let mut undone = game1.clone();
+    undone.make_move(ConnectMove { col: 3 });
+    undone.undo_move(ConnectMove { col: 3 });
+    expect_eq!(undone.position_key(), game1.position_key());
+  }
+
+  #[gtest]
+  fn test_position_key_distinct() {
+    let mut rng = StdRng::seed_from_u64(1268);
+    let mut states = HashSet::new();
+    for _ in 0..200 {
+      let mut game = ConnectN::new(7, 6, 4);
+      for _ in 0..rng.random_range(0..12) {
+        if game.finished().is_finished() {
+          break;
+        }
+        make_deterministic_random_move(&mut game, &mut rng);
+      }
+      states.insert(game);
+    }
+
+    let keys = states
+      .iter()
+      .map(Game::position_key)
+      .collect::<HashSet<_>>();
+    expect_eq!(keys.len(), states.len());
+  }
+
+  #[gtest]
+  fn test_canonical_form() {
+    let mut game = ConnectN::new(7, 6, 4);
+    game.make_move(ConnectMove { col: 1 });
+    game.make_move(ConnectMove { col: 2 });
+    let mut mirrored = ConnectN::new(7, 6, 4);
+    mirrored.make_move(ConnectMove { col: 5 });
+    mirrored.make_move(ConnectMove { col: 4 });
+
+    expect_ne!(game, mirrored);
+    expect_that!(
+      game.symmetries().collect_vec(),
+      unordered_elements_are![eq(&game), eq(&mirrored)]
+    );
+    expect_eq!(game.canonical_form(), mirrored.canonical_form());
+    expect_eq!(
+      game.canonical_form().position_key(),
+      mirrored.canonical_form().position_key()
+    );
+  }
+
+  #[gtest]
+  fn test_notation() {
+    let mut game = ConnectN::new(3, 3, 3);
+    game.make_move(ConnectMove { col: 0 });
+    game.make_move(ConnectMove { col: 1 });
+    game.make_move(ConnectMove { col: 1 });
+    expect_eq!(game.to_notation(), "3x3:3:.../.X./XO.");
+    expect_that!(ConnectN::from_notation("3x3:3:.../.X./XO."), ok(eq(&game)));
+  }
+
+  #[gtest]
+  fn test_invalid_notation() {
+    expect_that!(ConnectN::from_notation("3x3:3"), err(anything()));
+    expect_that!(
+      ConnectN::from_notation("3x3:4:.../.../..."),
+      err(anything())
+    );
+    expect_that!(ConnectN::from_notation("3x3:3:.../..."), err(anything()));
+    expect_that!(
+      ConnectN::from_notation("3x3:3:.../.../X.O."),
+      err(anything())
+    );
+    expect_that!(
+      ConnectN::from_notation("3x3:3:.../.../XZ."),
+      err(anything())
+    );
+    // Floating tile.
+    expect_that!(
+      ConnectN::from_notation("3x3:3:.../X../.O."),
+      err(anything())
+    );
+    // O moved first.
+    expect_that!(
+      ConnectN::from_notation("3x3:3:.../.../O.."),
+      err(anything())
+    );
+    // Buried line.
+    expect_that!(
+      ConnectN::from_notation("4x3:3:..../OXO./XXXO"),
+      err(anything())
+    );
+  }
+
+  #[gtest]
+  fn test_repro_code() {
+    let mut rng = StdRng::seed_from_u64(1271);
+    for _ in 0..20 {
+      let mut game = ConnectN::new(5, 4, 3);
+      for _ in 0..rng.random_range(0..12) {
+        if game.finished().is_finished() {
+          break;
+        }
+        make_deterministic_random_move(&mut game, &mut rng);
+      }
+
+      let repro_code = game.repro_code();
+      let notation = repro_code
+        .strip_prefix("ConnectN::from_notation(\"")
+        .and_then(|code| code.strip_suffix("\").unwrap()"));
+      expect_that!(notation, some(anything()), "{repro_code}");
+      if let Some(notation) = notation {
+        expect_that!(ConnectN::from_notation(notation), ok(eq(&game)));
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_cells() {
+    let mut game = ConnectN::new(4, 3, 3);
+    for col in [1, 1, 3, 1] {
+      game.make_move(ConnectMove { col });
+    }
+
+    expect_eq!(game.cell(1, 0), Some(GamePlayer::Player1));
+    expect_eq!(game.cell(1, 1), Some(GamePlayer::Player2));
+    expect_eq!(game.cell(1, 2), Some(GamePlayer::Player2));
+    expect_eq!(game.cell(3, 0), Some(GamePlayer::Player1));
+    expect_eq!(game.cell(0, 0), None);
+    expect_eq!(game.cell(3, 1), None);
+
+    const X: Option<GamePlayer> = Some(GamePlayer::Player1);
+    const O: Option<GamePlayer> = Some(GamePlayer::Player2);
+    expect_eq!(
+      game.rows(),
+      vec![
+        vec![None, X, None, X],
+        vec![None, O, None, None],
+        vec![None, O, None, None],
+      ]
+    );
+  }
+
+  /// Builds a game with the given rows, listed from the top down, without
+  /// validating the position.
+  fn unchecked_layout(width: u32, height: u32, in_a_row: u32, rows: &[&str]) -> ConnectN {
+    let mut game = ConnectN::new(width, height, in_a_row);
+    for (row, y) in rows.iter().zip((0..height).rev()) {
+      for (tile, x) in row.chars().zip(0..) {
+        match tile {
+          'X' => game.set((x, y), GamePlayer::Player1),
+          'O' => game.set((x, y), GamePlayer::Player2),
+          _ => {}
+        }
+      }
+    }
+    game
+  }
+
+  #[gtest]
+  fn test_is_legal_state() {
+    let mut rng = StdRng::seed_from_u64(1276);
+    for _ in 0..20 {
+      let mut game = ConnectN::new(5, 4, 3);
+      expect_true!(game.is_legal_state(), "{game:?}");
+      while !game.finished().is_finished() {
+        make_deterministic_random_move(&mut game, &mut rng);
+        expect_true!(game.is_legal_state(), "{game:?}");
+      }
+    }
+
+    // X's line is buried under later moves.
+    expect_false!(unchecked_layout(4, 3, 3, &["....", "OXO.", "XXXO"]).is_legal_state());
+    // Both players have a line.
+    expect_false!(unchecked_layout(3, 3, 3, &["...", "OOO", "XXX"]).is_legal_state());
+    // X has a line, but O moved last.
+    expect_false!(unchecked_layout(4, 3, 3, &["....", "OO..", "XXXO"]).is_legal_state());
+    // O made a line on the last move.
+    expect_true!(unchecked_layout(4, 3, 3, &["...O", "X..O", "XX.O"]).is_legal_state());
+  }
+
+  /// Every legal position on a board of the given size, found by filling each
+  /// column with every sequence of pieces.
+  fn legal_layouts(width: u32, height: u32, in_a_row: u32) -> Vec<ConnectN> {
+    let columns = (0..=height)
+      .flat_map(|len| {
+        (0..len)
+          .map(|_| [GamePlayer::Player1, GamePlayer::Player2])
+          .multi_cartesian_product()
+      })
+      .collect_vec();
+    (0..width)
+      .map(|_| columns.iter())
+      .multi_cartesian_product()
+      .map(|columns| {
+        let mut game = ConnectN::new(width, height, in_a_row);
+        for (x, column) in (0..).zip(columns) {
+          for (y, &player) in (0..).zip(column) {
+            game.set((x, y), player);
+          }
+        }
+        game
+      })
+      .filter(ConnectN::is_legal_state)
+      .collect()
+  }
+
+  #[gtest]
+  fn test_is_terminal_matches_finished() {
+    for game in legal_layouts(4, 3, 3) {
+      expect_eq!(
+        game.is_terminal(),
+        game.finished().is_finished(),
+        "{game:?}"
+      );
+    }
+
+    // Only lines through the top of a column are found, so a buried line is
+    // missed, but such positions aren't legal.
+    let buried = unchecked_layout(4, 3, 3, &["....", "OXO.", "XXXO"]);
+    expect_eq!(buried.finished(), GameResult::Win(GamePlayer::Player1));
+    expect_false!(buried.is_terminal());
+    expect_false!(buried.is_legal_state());
+  }
+
+  #[gtest]
+  fn test_winning_line_matches_finished() {
+    for game in legal_layouts(4, 3, 3) {
+      let winning_line = game.winning_line();
+      expect_eq!(
+        winning_line.is_some(),
+        game.finished().winner().is_some(),
+        "{game:?}"
+      );
+      if let (Some(line), Some(winner)) = (winning_line, game.finished().winner()) {
+        expect_true!(
+          line.iter().all(|&(x, y)| game.cell(x, y) == Some(winner)),
+          "{game:?}"
+        );
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_from_layout() {
+    let game = ConnectN::from_layout(
+      7,
+      6,
+      4,
+      &[
+        ".......", ".......", ".......", "...O...", "...O...", ".XXXO..",
+      ],
+    )
+    .unwrap();
+    expect_eq!(game.current_player(), GamePlayer::Player1);
+    expect_eq!(game.cell(3, 2), Some(GamePlayer::Player2));
+    expect_eq!(game.finished(), GameResult::NotFinished);
+
+    // X can win on either end of the bottom row, and the first is found.
+    expect_eq!(game.search_immediate_win(), Some(ConnectMove { col: 0 }));
+
+    let game = ConnectN::from_layout(4, 3, 3, &["....", "O...", "XX.."]).unwrap();
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+    expect_eq!(game.search_immediate_win(), None);
+  }
+
+  #[gtest]
+  fn test_from_layout_invalid() {
+    // Floating piece.
+    expect_that!(
+      ConnectN::from_layout(3, 3, 3, &[".X.", "...", "O.X"]),
+      err(contains_substring("floating"))
+    );
+    // O moved first.
+    expect_that!(
+      ConnectN::from_layout(3, 3, 3, &["...", "...", "O.."]),
+      err(contains_substring("pieces"))
+    );
+    // X moved twice in a row.
+    expect_that!(
+      ConnectN::from_layout(3, 3, 3, &["...", "...", "XX."]),
+      err(contains_substring("pieces"))
+    );
+    // X's line is buried under later moves.
+    expect_that!(
+      ConnectN::from_layout(4, 3, 3, &["....", "OXO.", "XXXO"]),
+      err(contains_substring("line"))
+    );
+    // Both players have a line.
+    expect_that!(
+      ConnectN::from_layout(3, 3, 3, &["...", "OOO", "XXX"]),
+      err(contains_substring("line"))
+    );
+    expect_that!(
+      ConnectN::from_layout(3, 3, 3, &["...", "..."]),
+      err(contains_substring("rows"))
+    );
+    expect_that!(
+      ConnectN::from_layout(3, 3, 3, &["...", "...", "X?."]),
+      err(contains_substring("Unknown tile"))
+    );
   }
 }
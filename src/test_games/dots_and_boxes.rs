@@ -0,0 +1,382 @@
+use std::{
+  cmp::Ordering,
+  fmt::{Debug, Display},
+};
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+
+/// An edge between two adjacent dots. Boxes are indexed from the bottom-left
+/// corner, and the edges of box `(x, y)` are `Horizontal { x, y }`,
+/// `Horizontal { x, y: y + 1 }`, `Vertical { x, y }` and
+/// `Vertical { x: x + 1, y }`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DotsAndBoxesMove {
+  Horizontal { x: u32, y: u32 },
+  Vertical { x: u32, y: u32 },
+}
+
+pub struct DotsAndBoxesMoveGen {
+  idx: u32,
+}
+
+impl GameMoveIterator for DotsAndBoxesMoveGen {
+  type Game = DotsAndBoxes;
+
+  fn next(&mut self, game: &DotsAndBoxes) -> Option<DotsAndBoxesMove> {
+    while (self.idx as usize) < game.edges.len() {
+      let idx = self.idx;
+      self.idx += 1;
+      if !game.edges[idx as usize] {
+        return Some(game.edge_at(idx));
+      }
+    }
+    None
+  }
+}
+
+/// Dots and Boxes on a grid of `width` x `height` boxes. Players take turns
+/// drawing edges, and a player who completes a box claims it and must move
+/// again. Once every edge is drawn, the player with more boxes wins.
+///
+/// Since completing a box grants an extra turn, the current player does not
+/// always alternate between moves. Solvers account for this by converting
+/// scores between positions with `Score::backstep_between`, which keeps the
+/// perspective of the score across an extra turn.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct DotsAndBoxes {
+  width: u32,
+  height: u32,
+  /// Whether each edge has been drawn, with all horizontal edges followed by
+  /// all vertical edges, each in row-major order.
+  edges: Vec<bool>,
+  /// The owner of each box, in row-major order.
+  boxes: Vec<Option<GamePlayer>>,
+  current_player: GamePlayer,
+}
+
+impl DotsAndBoxes {
+  pub fn new(width: u32, height: u32) -> Self {
+    let edge_count = width * (height + 1) + (width + 1) * height;
+    Self {
+      width,
+      height,
+      edges: vec![false; edge_count as usize],
+      boxes: vec![None; (width * height) as usize],
+      current_player: GamePlayer::Player1,
+    }
+  }
+
+  pub fn width(&self) -> u32 {
+    self.width
+  }
+
+  pub fn height(&self) -> u32 {
+    self.height
+  }
+
+  /// The player who completed the box at `pos`, if any.
+  pub fn owner(&self, pos: (u32, u32)) -> Option<GamePlayer> {
+    debug_assert!(pos.0 < self.width && pos.1 < self.height);
+    self.boxes[(pos.0 + pos.1 * self.width) as usize]
+  }
+
+  /// The number of boxes `player` has completed.
+  pub fn box_count(&self, player: GamePlayer) -> u32 {
+    self
+      .boxes
+      .iter()
+      .filter(|&&owner| owner == Some(player))
+      .count() as u32
+  }
+
+  /// True if `edge` has been drawn.
+  pub fn is_drawn(&self, edge: DotsAndBoxesMove) -> bool {
+    self.edges[self.edge_index(edge) as usize]
+  }
+
+  fn horizontal_edge_count(&self) -> u32 {
+    self.width * (self.height + 1)
+  }
+
+  fn edge_index(&self, edge: DotsAndBoxesMove) -> u32 {
+    match edge {
+      DotsAndBoxesMove::Horizontal { x, y } => {
+        debug_assert!(x < self.width && y <= self.height);
+        x + y * self.width
+      }
+      DotsAndBoxesMove::Vertical { x, y } => {
+        debug_assert!(x <= self.width && y < self.height);
+        self.horizontal_edge_count() + x + y * (self.width + 1)
+      }
+    }
+  }
+
+  fn edge_at(&self, idx: u32) -> DotsAndBoxesMove {
+    if idx < self.horizontal_edge_count() {
+      DotsAndBoxesMove::Horizontal { x: idx % self.width, y: idx / self.width }
+    } else {
+      let idx = idx - self.horizontal_edge_count();
+      DotsAndBoxesMove::Vertical {
+        x: idx % (self.width + 1),
+        y: idx / (self.width + 1),
+      }
+    }
+  }
+
+  /// The boxes on either side of `edge`.
+  fn adjacent_boxes(&self, edge: DotsAndBoxesMove) -> impl Iterator<Item = (u32, u32)> {
+    let (before, after) = match edge {
+      DotsAndBoxesMove::Horizontal { x, y } => (
+        (y > 0).then(|| (x, y - 1)),
+        (y < self.height).then_some((x, y)),
+      ),
+      DotsAndBoxesMove::Vertical { x, y } => (
+        (x > 0).then(|| (x - 1, y)),
+        (x < self.width).then_some((x, y)),
+      ),
+    };
+    before.into_iter().chain(after)
+  }
+
+  fn is_complete(&self, (x, y): (u32, u32)) -> bool {
+    [
+      DotsAndBoxesMove::Horizontal { x, y },
+      DotsAndBoxesMove::Horizontal { x, y: y + 1 },
+      DotsAndBoxesMove::Vertical { x, y },
+      DotsAndBoxesMove::Vertical { x: x + 1, y },
+    ]
+    .into_iter()
+    .all(|edge| self.is_drawn(edge))
+  }
+}
+
+impl Game for DotsAndBoxes {
+  type Move = DotsAndBoxesMove;
+  type MoveGenerator = DotsAndBoxesMoveGen;
+
+  fn move_generator(&self) -> DotsAndBoxesMoveGen {
+    DotsAndBoxesMoveGen { idx: 0 }
+  }
+
+  fn make_move(&mut self, m: DotsAndBoxesMove) {
+    let idx = self.edge_index(m) as usize;
+    debug_assert!(!self.edges[idx]);
+    self.edges[idx] = true;
+
+    let mut completed_box = false;
+    for pos in self.adjacent_boxes(m).collect::<Vec<_>>() {
+      if self.is_complete(pos) {
+        self.boxes[(pos.0 + pos.1 * self.width) as usize] = Some(self.current_player);
+        completed_box = true;
+      }
+    }
+
+    // Completing a box grants an extra turn.
+    if !completed_box {
+      self.current_player = self.current_player.opposite();
+    }
+  }
+
+  fn current_player(&self) -> GamePlayer {
+    self.current_player
+  }
+
+  fn finished(&self) -> GameResult {
+    if !self.edges.iter().all(|&drawn| drawn) {
+      return GameResult::NotFinished;
+    }
+
+    let player1_count = self.box_count(GamePlayer::Player1);
+    let player2_count = self.box_count(GamePlayer::Player2);
+    match player1_count.cmp(&player2_count) {
+      Ordering::Greater => GameResult::Win(GamePlayer::Player1),
+      Ordering::Less => GameResult::Win(GamePlayer::Player2),
+      Ordering::Equal => GameResult::Tie,
+    }
+  }
+}
+
+/// Includes the player to move, which the board alone doesn't determine since
+/// completing a box grants an extra turn.
+impl Debug for DotsAndBoxes {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{self}\n{:?} to move", self.current_player)
+  }
+}
+
+impl Display for DotsAndBoxes {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    for y in (0..=self.height).rev() {
+      for x in 0..self.width {
+        let drawn = self.is_drawn(DotsAndBoxesMove::Horizontal { x, y });
+        write!(f, "+{}", if drawn { '-' } else { ' ' })?;
+      }
+      write!(f, "+")?;
+
+      if y == 0 {
+        break;
+      }
+      writeln!(f)?;
+      let y = y - 1;
+      for x in 0..=self.width {
+        let drawn = self.is_drawn(DotsAndBoxesMove::Vertical { x, y });
+        write!(f, "{}", if drawn { '|' } else { ' ' })?;
+        if x < self.width {
+          let owner = match self.owner((x, y)) {
+            None => ' ',
+            Some(GamePlayer::Player1) => 'X',
+            Some(GamePlayer::Player2) => 'O',
+          };
+          write!(f, "{owner}")?;
+        }
+      }
+      writeln!(f)?;
+    }
+    Ok(())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+
+  use crate::{
+    solvers::MinimaxSolver,
+    test_games::{DotsAndBoxes, DotsAndBoxesMove},
+    Game, GamePlayer, GameResult, Solver,
+  };
+
+  fn h(x: u32, y: u32) -> DotsAndBoxesMove {
+    DotsAndBoxesMove::Horizontal { x, y }
+  }
+
+  fn v(x: u32, y: u32) -> DotsAndBoxesMove {
+    DotsAndBoxesMove::Vertical { x, y }
+  }
+
+  fn play(width: u32, height: u32, moves: &[DotsAndBoxesMove]) -> DotsAndBoxes {
+    let mut game = DotsAndBoxes::new(width, height);
+    for &m in moves {
+      game.make_move(m);
+    }
+    game
+  }
+
+  /// The result of `game` under perfect play, found by exhaustive search
+  /// without `Score`.
+  fn perfect_play_result(game: &DotsAndBoxes) -> GameResult {
+    let result = game.finished();
+    if result.is_finished() {
+      return result;
+    }
+
+    let player = game.current_player();
+    let results = game
+      .each_move()
+      .map(|m| perfect_play_result(&game.with_move(m)))
+      .collect::<Vec<_>>();
+    if results.contains(&GameResult::Win(player)) {
+      GameResult::Win(player)
+    } else if results.contains(&GameResult::Tie) {
+      GameResult::Tie
+    } else {
+      GameResult::Win(player.opposite())
+    }
+  }
+
+  #[gtest]
+  fn test_first_moves() {
+    let game = DotsAndBoxes::new(3, 2);
+    expect_eq!(game.each_move().count(), 17);
+    expect_eq!(game.finished(), GameResult::NotFinished);
+
+    let game = play(3, 2, &[v(3, 1)]);
+    expect_eq!(game.each_move().count(), 16);
+    expect_that!(
+      game.each_move().collect::<Vec<_>>(),
+      not(contains(eq(&v(3, 1))))
+    );
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+  }
+
+  #[gtest]
+  fn test_completing_box_keeps_turn() {
+    let game = play(2, 1, &[h(0, 0), h(0, 1), v(0, 0)]);
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+    expect_eq!(game.owner((0, 0)), None);
+
+    let game = game.with_move(v(1, 0));
+    expect_eq!(game.owner((0, 0)), Some(GamePlayer::Player2));
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+    expect_eq!(
+      game.to_string(),
+      "+-+ +\n\
+       |O|  \n\
+       +-+ +"
+    );
+
+    // An edge which completes no box passes the turn.
+    let game = game.with_move(h(1, 0));
+    expect_eq!(game.current_player(), GamePlayer::Player1);
+  }
+
+  #[gtest]
+  fn test_completing_two_boxes() {
+    let game = play(
+      2,
+      1,
+      &[h(0, 0), h(0, 1), h(1, 0), h(1, 1), v(0, 0), v(2, 0)],
+    );
+    expect_eq!(game.current_player(), GamePlayer::Player1);
+
+    // The middle edge completes both boxes, ending the game.
+    let game = game.with_move(v(1, 0));
+    expect_eq!(game.box_count(GamePlayer::Player1), 2);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+    expect_eq!(game.each_move().count(), 0);
+  }
+
+  #[gtest]
+  fn test_tie() {
+    let game = play(
+      2,
+      1,
+      &[h(0, 0), h(0, 1), v(0, 0), h(1, 0), v(1, 0), h(1, 1)],
+    );
+    expect_eq!(game.box_count(GamePlayer::Player1), 1);
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+
+    let game = game.with_move(v(2, 0));
+    expect_eq!(game.finished(), GameResult::Tie);
+  }
+
+  #[gtest]
+  fn test_position_key_includes_player() {
+    let game = play(2, 1, &[h(0, 0), h(0, 1), v(0, 0)]);
+    let mut other_player = game.clone();
+    other_player.current_player = game.current_player().opposite();
+    expect_ne!(game.position_key(), other_player.position_key());
+  }
+
+  #[gtest]
+  fn test_solver_handles_extra_turns() {
+    let game = DotsAndBoxes::new(2, 1);
+    let mut solver = MinimaxSolver::new();
+    for m in game.each_move() {
+      let child = game.with_move(m);
+      let (score, _) = solver.best_move(&child, 7);
+      let expected = perfect_play_result(&child);
+      let current = child.current_player();
+      expect_eq!(
+        score.is_winning(),
+        expected == GameResult::Win(current),
+        "{m:?}: {score}"
+      );
+      expect_eq!(
+        score.is_losing(),
+        expected == GameResult::Win(current.opposite()),
+        "{m:?}: {score}"
+      );
+    }
+  }
+}
@@ -0,0 +1,274 @@
+use std::fmt::{Debug, Display};
+
+use itertools::Itertools;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult, GridSetup};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GomokuMove {
+  pub x: u32,
+  pub y: u32,
+}
+
+pub struct GomokuMoveGen {
+  idx: u32,
+}
+
+impl GameMoveIterator for GomokuMoveGen {
+  type Game = Gomoku;
+
+  fn next(&mut self, game: &Gomoku) -> Option<GomokuMove> {
+    while self.idx < game.size * game.size {
+      let pos = (self.idx % game.size, self.idx / game.size);
+      self.idx += 1;
+      if game.at(pos).is_none() {
+        return Some(GomokuMove { x: pos.0, y: pos.1 });
+      }
+    }
+    None
+  }
+}
+
+/// N in a row on a square board, where pieces may be placed on any empty
+/// cell. Unlike `ConnectN`, there is no gravity.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Gomoku {
+  board: Vec<Option<GamePlayer>>,
+  size: u32,
+  in_a_row: u32,
+  current_player: GamePlayer,
+}
+
+impl Gomoku {
+  pub fn new(size: u32, in_a_row: u32) -> Self {
+    debug_assert!(in_a_row <= size);
+    Self {
+      board: vec![None; (size * size) as usize],
+      size,
+      in_a_row,
+      current_player: GamePlayer::Player1,
+    }
+  }
+
+  pub fn size(&self) -> u32 {
+    self.size
+  }
+
+  /// The player with a piece at `pos`, if any.
+  pub fn at(&self, pos: (u32, u32)) -> Option<GamePlayer> {
+    debug_assert!(pos.0 < self.size && pos.1 < self.size);
+    self.board[(pos.0 + pos.1 * self.size) as usize]
+  }
+
+  /// Returns the player with `in_a_row` pieces in a row starting from `pos`
+  /// and continuing in direction `(dx, dy)`, if there is one.
+  fn line_from(&self, pos: (u32, u32), (dx, dy): (i32, i32)) -> Option<GamePlayer> {
+    let player = self.at(pos)?;
+    (1..self.in_a_row as i32)
+      .map(|i| (pos.0 as i32 + i * dx, pos.1 as i32 + i * dy))
+      .all(|(x, y)| {
+        (0..self.size as i32).contains(&x)
+          && (0..self.size as i32).contains(&y)
+          && self.at((x as u32, y as u32)) == Some(player)
+      })
+      .then_some(player)
+  }
+}
+
+impl Game for Gomoku {
+  type Move = GomokuMove;
+  type MoveGenerator = GomokuMoveGen;
+
+  fn move_generator(&self) -> GomokuMoveGen {
+    GomokuMoveGen { idx: 0 }
+  }
+
+  fn make_move(&mut self, m: GomokuMove) {
+    debug_assert!(self.at((m.x, m.y)).is_none());
+    self.board[(m.x + m.y * self.size) as usize] = Some(self.current_player);
+    self.current_player = self.current_player.opposite();
+  }
+
+  fn current_player(&self) -> GamePlayer {
+    self.current_player
+  }
+
+  fn finished(&self) -> GameResult {
+    let winner = (0..self.size)
+      .cartesian_product(0..self.size)
+      .cartesian_product([(1, 0), (0, 1), (1, 1), (1, -1)])
+      .find_map(|(pos, dir)| self.line_from(pos, dir));
+
+    if let Some(winner) = winner {
+      GameResult::Win(winner)
+    } else if self.board.iter().all(Option::is_some) {
+      GameResult::Tie
+    } else {
+      GameResult::NotFinished
+    }
+  }
+}
+
+impl GridSetup for Gomoku {
+  fn as_grid(&self) -> Vec<Vec<Option<GamePlayer>>> {
+    self
+      .board
+      .chunks(self.size as usize)
+      .map(<[_]>::to_vec)
+      .collect()
+  }
+
+  fn set_cell(&mut self, pos: (u32, u32), piece: Option<GamePlayer>) {
+    debug_assert!(pos.0 < self.size && pos.1 < self.size);
+    self.board[(pos.0 + pos.1 * self.size) as usize] = piece;
+  }
+
+  fn set_current_player(&mut self, player: GamePlayer) {
+    self.current_player = player;
+  }
+}
+
+impl Debug for Gomoku {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{self}")
+  }
+}
+
+impl Display for Gomoku {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let rows = (0..self.size).rev().map(|y| {
+      (0..self.size)
+        .map(|x| match self.at((x, y)) {
+          None => '.',
+          Some(GamePlayer::Player1) => 'X',
+          Some(GamePlayer::Player2) => 'O',
+        })
+        .collect::<String>()
+    });
+    write!(f, "{}", rows.format("\n"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+
+  use crate::{
+    test_games::{Gomoku, GomokuMove},
+    Game, GamePlayer, GameResult,
+  };
+
+  fn play(size: u32, in_a_row: u32, moves: &[(u32, u32)]) -> Gomoku {
+    let mut game = Gomoku::new(size, in_a_row);
+    for &(x, y) in moves {
+      game.make_move(GomokuMove { x, y });
+    }
+    game
+  }
+
+  #[gtest]
+  fn test_first_moves() {
+    let game = Gomoku::new(5, 4);
+    expect_eq!(game.each_move().count(), 25);
+    expect_eq!(game.finished(), GameResult::NotFinished);
+
+    let game = play(5, 4, &[(2, 3)]);
+    expect_eq!(game.each_move().count(), 24);
+    expect_that!(
+      game.each_move().collect_vec(),
+      not(contains(eq(&GomokuMove { x: 2, y: 3 })))
+    );
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+  }
+
+  #[gtest]
+  fn test_win_row() {
+    let game = play(
+      6,
+      4,
+      &[(1, 2), (1, 3), (2, 2), (2, 3), (3, 2), (3, 3), (4, 2)],
+    );
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+  }
+
+  #[gtest]
+  fn test_win_col() {
+    let game = play(
+      6,
+      4,
+      &[
+        (0, 0),
+        (5, 0),
+        (0, 5),
+        (5, 1),
+        (1, 1),
+        (5, 2),
+        (0, 4),
+        (5, 3),
+      ],
+    );
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player2));
+  }
+
+  #[gtest]
+  fn test_win_diag1() {
+    let game = play(
+      5,
+      4,
+      &[(1, 1), (0, 1), (2, 2), (0, 2), (3, 3), (0, 3), (4, 4)],
+    );
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+  }
+
+  #[gtest]
+  fn test_win_diag2() {
+    let game = play(
+      5,
+      4,
+      &[
+        (0, 4),
+        (1, 3),
+        (4, 4),
+        (2, 2),
+        (4, 3),
+        (3, 1),
+        (0, 0),
+        (4, 0),
+      ],
+    );
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player2));
+  }
+
+  #[gtest]
+  fn test_not_finished_broken_line() {
+    let game = play(
+      5,
+      4,
+      &[(0, 0), (4, 4), (1, 0), (2, 0), (3, 0), (3, 3), (4, 0)],
+    );
+    expect_eq!(game.finished(), GameResult::NotFinished);
+  }
+
+  #[gtest]
+  fn test_tie() {
+    let game = play(
+      3,
+      3,
+      &[
+        (0, 0),
+        (1, 0),
+        (2, 0),
+        (0, 1),
+        (1, 1),
+        (2, 2),
+        (2, 1),
+        (0, 2),
+        (1, 2),
+      ],
+    );
+    expect_eq!(game.to_string(), "OXO\nOXX\nXOX");
+    expect_eq!(game.finished(), GameResult::Tie);
+    expect_eq!(game.each_move().count(), 0);
+  }
+}
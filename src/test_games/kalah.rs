@@ -0,0 +1,371 @@
+use std::{
+  cmp::Ordering,
+  fmt::{Debug, Display},
+  mem,
+};
+
+use itertools::Itertools;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+
+/// The number of pits on each player's side of the board.
+const PITS: usize = 6;
+/// Each player's pits followed by their store, starting with player 1's.
+const BOARD_LEN: usize = 2 * (PITS + 1);
+
+pub struct KalahMoveGen {
+  pit: u32,
+}
+
+impl GameMoveIterator for KalahMoveGen {
+  type Game = Kalah;
+
+  fn next(&mut self, game: &Kalah) -> Option<u32> {
+    while (self.pit as usize) < PITS {
+      let pit = self.pit;
+      self.pit += 1;
+      if game.pit(game.current_player, pit) != 0 {
+        return Some(pit);
+      }
+    }
+    None
+  }
+}
+
+/// Kalah with six pits per side. A move picks one of the current player's
+/// non-empty pits, numbered from `0` to `5` counterclockwise from their left,
+/// and sows its seeds one at a time into the following pits and the player's
+/// own store, skipping the opponent's store.
+///
+/// If the last seed lands in the player's store, they move again. If it lands
+/// in one of their own empty pits and the opposite pit has seeds, both the
+/// last seed and the opposite seeds are captured into their store. The game
+/// ends once either side is empty, when each player sweeps the seeds left on
+/// their side into their store, and the player with more seeds in their store
+/// wins.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Kalah {
+  board: [u8; BOARD_LEN],
+  current_player: GamePlayer,
+}
+
+impl Kalah {
+  /// The most seeds each pit can start with, since a store must be able to
+  /// hold every seed on the board.
+  pub const MAX_SEEDS: u8 = (u8::MAX as usize / (2 * PITS)) as u8;
+
+  /// The standard game, with 4 seeds in each pit.
+  pub fn new() -> Self {
+    Self::with_seeds(4)
+  }
+
+  /// A game starting with `seeds` seeds in each pit, which may be at most
+  /// `MAX_SEEDS`.
+  pub fn with_seeds(seeds: u8) -> Self {
+    assert!(
+      seeds <= Self::MAX_SEEDS,
+      "{seeds} seeds per pit exceeds the maximum of {}",
+      Self::MAX_SEEDS
+    );
+    let mut board = [seeds; BOARD_LEN];
+    board[Self::store_index(GamePlayer::Player1)] = 0;
+    board[Self::store_index(GamePlayer::Player2)] = 0;
+    Self {
+      board,
+      current_player: GamePlayer::Player1,
+    }
+  }
+
+  /// The number of seeds in `player`'s pit `pit`.
+  pub fn pit(&self, player: GamePlayer, pit: u32) -> u8 {
+    debug_assert!((pit as usize) < PITS);
+    self.board[Self::side_offset(player) + pit as usize]
+  }
+
+  /// The number of seeds in `player`'s store.
+  pub fn store(&self, player: GamePlayer) -> u8 {
+    self.board[Self::store_index(player)]
+  }
+
+  const fn side_offset(player: GamePlayer) -> usize {
+    match player {
+      GamePlayer::Player1 => 0,
+      GamePlayer::Player2 => PITS + 1,
+    }
+  }
+
+  const fn store_index(player: GamePlayer) -> usize {
+    Self::side_offset(player) + PITS
+  }
+
+  /// The index of the pit across the board from the pit at `idx`.
+  const fn opposite_index(idx: usize) -> usize {
+    2 * PITS - idx
+  }
+
+  fn side(&self, player: GamePlayer) -> &[u8] {
+    let offset = Self::side_offset(player);
+    &self.board[offset..offset + PITS]
+  }
+
+  fn side_empty(&self, player: GamePlayer) -> bool {
+    self.side(player).iter().all(|&seeds| seeds == 0)
+  }
+
+  /// Moves the seeds left on each side into that side's store.
+  fn sweep(&mut self) {
+    for player in [GamePlayer::Player1, GamePlayer::Player2] {
+      let offset = Self::side_offset(player);
+      let remaining = self.board[offset..offset + PITS]
+        .iter_mut()
+        .map(mem::take)
+        .sum::<u8>();
+      self.board[Self::store_index(player)] += remaining;
+    }
+  }
+}
+
+impl Default for Kalah {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Game for Kalah {
+  type Move = u32;
+  type MoveGenerator = KalahMoveGen;
+
+  const MAX_MOVES: usize = PITS;
+
+  fn move_generator(&self) -> KalahMoveGen {
+    KalahMoveGen { pit: 0 }
+  }
+
+  fn make_move(&mut self, pit: u32) {
+    let player = self.current_player;
+    let offset = Self::side_offset(player);
+    let own_store = Self::store_index(player);
+    let opponent_store = Self::store_index(player.opposite());
+
+    let mut idx = offset + pit as usize;
+    let mut seeds = mem::take(&mut self.board[idx]);
+    debug_assert_ne!(seeds, 0);
+    while seeds > 0 {
+      idx = (idx + 1) % BOARD_LEN;
+      if idx != opponent_store {
+        self.board[idx] += 1;
+        seeds -= 1;
+      }
+    }
+
+    // Landing in the player's own store grants them another turn.
+    if idx != own_store {
+      let opposite = Self::opposite_index(idx);
+      if (offset..own_store).contains(&idx) && self.board[idx] == 1 && self.board[opposite] != 0 {
+        let captured = mem::take(&mut self.board[idx]) + mem::take(&mut self.board[opposite]);
+        self.board[own_store] += captured;
+      }
+      self.current_player = player.opposite();
+    }
+
+    if self.side_empty(GamePlayer::Player1) || self.side_empty(GamePlayer::Player2) {
+      self.sweep();
+    }
+  }
+
+  fn current_player(&self) -> GamePlayer {
+    self.current_player
+  }
+
+  fn finished(&self) -> GameResult {
+    if !self.side_empty(GamePlayer::Player1) && !self.side_empty(GamePlayer::Player2) {
+      return GameResult::NotFinished;
+    }
+
+    let total = |player| {
+      self.store(player) as u32
+        + self
+          .side(player)
+          .iter()
+          .map(|&seeds| seeds as u32)
+          .sum::<u32>()
+    };
+    match total(GamePlayer::Player1).cmp(&total(GamePlayer::Player2)) {
+      Ordering::Greater => GameResult::Win(GamePlayer::Player1),
+      Ordering::Less => GameResult::Win(GamePlayer::Player2),
+      Ordering::Equal => GameResult::Tie,
+    }
+  }
+}
+
+/// Includes the player to move, which the board alone doesn't determine since
+/// sowing into one's own store grants an extra turn.
+impl Debug for Kalah {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{self}\n{:?} to move", self.current_player)
+  }
+}
+
+/// Draws the board from player 1's side, with player 2's pits along the top
+/// and their store on the left.
+impl Display for Kalah {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let player2_pits = self.side(GamePlayer::Player2).iter().rev();
+    let player1_pits = self.side(GamePlayer::Player1).iter();
+    writeln!(
+      f,
+      "  {}",
+      player2_pits.format_with("", |seeds, f| f(&format_args!("{seeds:>3}")))
+    )?;
+    writeln!(
+      f,
+      "{:>2}{}{:>2}",
+      self.store(GamePlayer::Player2),
+      " ".repeat(3 * PITS + 1),
+      self.store(GamePlayer::Player1)
+    )?;
+    write!(
+      f,
+      "  {}",
+      player1_pits.format_with("", |seeds, f| f(&format_args!("{seeds:>3}")))
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+
+  use crate::{test_games::Kalah, Game, GamePlayer, GameResult};
+
+  const P1: GamePlayer = GamePlayer::Player1;
+  const P2: GamePlayer = GamePlayer::Player2;
+
+  #[gtest]
+  fn test_first_moves() {
+    let game = Kalah::new();
+    expect_eq!(game.each_move().collect_vec(), (0..6).collect_vec());
+    expect_eq!(game.finished(), GameResult::NotFinished);
+    expect_eq!(
+      game.to_string(),
+      "    4  4  4  4  4  4\n \
+       0                    0\n    \
+       4  4  4  4  4  4"
+    );
+
+    let game = game.with_move(0);
+    expect_eq!(game.current_player(), P2);
+    expect_eq!(
+      (0..6).map(|pit| game.pit(P1, pit)).collect_vec(),
+      vec![0, 5, 5, 5, 5, 4]
+    );
+    expect_eq!(game.store(P1), 0);
+  }
+
+  #[gtest]
+  fn test_max_seeds() {
+    let mut game = Kalah::with_seeds(Kalah::MAX_SEEDS);
+    game.make_move(0);
+    expect_eq!(game.store(P1), 2);
+  }
+
+  #[test]
+  #[should_panic(expected = "22 seeds per pit exceeds the maximum of 21")]
+  fn test_too_many_seeds() {
+    Kalah::with_seeds(22);
+  }
+
+  #[gtest]
+  fn test_extra_turn() {
+    // The 4 seeds in pit 2 end in the store.
+    let game = Kalah::new().with_move(2);
+    expect_eq!(game.store(P1), 1);
+    expect_eq!(game.current_player(), P1);
+    expect_that!(game.each_move().collect_vec(), not(contains(eq(&2))));
+
+    // Sowing past the store passes the turn.
+    let game = game.with_move(5);
+    expect_eq!(game.store(P1), 2);
+    expect_eq!(game.current_player(), P2);
+    expect_eq!(
+      (0..6).map(|pit| game.pit(P2, pit)).collect_vec(),
+      vec![5, 5, 5, 5, 4, 4]
+    );
+  }
+
+  #[gtest]
+  fn test_position_key_includes_player() {
+    let game = Kalah::new().with_move(2);
+    let other_player = Kalah { current_player: P2, ..game.clone() };
+    expect_ne!(game.position_key(), other_player.position_key());
+  }
+
+  #[gtest]
+  fn test_opponent_store_skipped() {
+    let mut board = [0; 14];
+    board[2] = 1;
+    board[5] = 10;
+    board[8] = 1;
+    let game = Kalah { board, current_player: P1 }.with_move(5);
+    expect_eq!(game.store(P1), 1);
+    expect_eq!(game.store(P2), 0);
+    expect_eq!(
+      (0..6).map(|pit| game.pit(P2, pit)).collect_vec(),
+      vec![1, 2, 1, 1, 1, 1]
+    );
+    // The last seed wraps around past player 2's store to player 1's pit 2,
+    // which wasn't empty, so nothing is captured.
+    expect_eq!(
+      (0..6).map(|pit| game.pit(P1, pit)).collect_vec(),
+      vec![1, 1, 2, 0, 0, 0]
+    );
+  }
+
+  #[gtest]
+  fn test_capture() {
+    let mut board = [0; 14];
+    board[0] = 1;
+    board[4] = 2;
+    board[11] = 3;
+    board[9] = 2;
+    let game = Kalah { board, current_player: P1 }.with_move(0);
+
+    // The seed lands in the empty pit 1, capturing the 3 seeds opposite it.
+    expect_eq!(game.store(P1), 4);
+    expect_eq!(game.pit(P1, 1), 0);
+    expect_eq!(game.pit(P2, 4), 0);
+    expect_eq!(game.current_player(), P2);
+    expect_eq!(game.finished(), GameResult::NotFinished);
+
+    // Landing in an empty pit opposite an empty pit captures nothing.
+    let mut board = [0; 14];
+    board[0] = 1;
+    board[4] = 2;
+    board[9] = 2;
+    let game = Kalah { board, current_player: P1 }.with_move(0);
+    expect_eq!(game.store(P1), 0);
+    expect_eq!(game.pit(P1, 1), 1);
+  }
+
+  #[gtest]
+  fn test_end_game_sweep() {
+    let mut board = [0; 14];
+    board[5] = 1;
+    board[6] = 10;
+    board[7] = 3;
+    board[10] = 4;
+    board[13] = 5;
+    let game = Kalah { board, current_player: P1 }.with_move(5);
+
+    // Player 1's side is empty, so player 2 sweeps their remaining seeds.
+    expect_eq!(game.store(P1), 11);
+    expect_eq!(game.store(P2), 12);
+    expect_eq!(
+      (0..6).map(|pit| game.pit(P2, pit)).collect_vec(),
+      vec![0; 6]
+    );
+    expect_eq!(game.finished(), GameResult::Win(P2));
+    expect_that!(game.each_move().collect_vec(), is_empty());
+  }
+}
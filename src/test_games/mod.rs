@@ -1,7 +1,19 @@
 mod connect_n;
+mod dots_and_boxes;
+mod gomoku;
+mod kalah;
+mod multi_nim;
 mod nim;
+mod othello;
+mod subtraction;
 mod tic_tac_toe;
 
 pub use connect_n::*;
+pub use dots_and_boxes::*;
+pub use gomoku::*;
+pub use kalah::*;
+pub use multi_nim::*;
 pub use nim::*;
+pub use othello::*;
+pub use subtraction::*;
 pub use tic_tac_toe::*;
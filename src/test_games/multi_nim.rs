@@ -0,0 +1,186 @@
+use std::fmt::Display;
+
+use itertools::Itertools;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+
+pub struct MultiNimMoveIter {
+  heap: usize,
+  sticks: u32,
+}
+
+impl GameMoveIterator for MultiNimMoveIter {
+  type Game = MultiNim;
+
+  fn next(&mut self, nim: &MultiNim) -> Option<(usize, u32)> {
+    while self.heap < nim.heaps.len() {
+      if self.sticks < nim.heaps[self.heap] {
+        self.sticks += 1;
+        return Some((self.heap, self.sticks));
+      }
+      self.heap += 1;
+      self.sticks = 0;
+    }
+    None
+  }
+}
+
+/// Nim with any number of heaps, where a move `(heap_index, sticks)` removes
+/// at least one stick from a single heap. Normally the player who takes the
+/// last stick wins, but in misère play they lose.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MultiNim {
+  heaps: Vec<u32>,
+  misere: bool,
+  player1: bool,
+}
+
+impl MultiNim {
+  pub fn new(heaps: Vec<u32>) -> Self {
+    Self { heaps, misere: false, player1: true }
+  }
+
+  /// Switches to misère play, where the player who takes the last stick
+  /// loses.
+  pub fn with_misere(mut self, misere: bool) -> Self {
+    self.misere = misere;
+    self
+  }
+
+  pub fn heaps(&self) -> &[u32] {
+    &self.heaps
+  }
+
+  pub fn is_misere(&self) -> bool {
+    self.misere
+  }
+}
+
+impl Game for MultiNim {
+  type Move = (usize, u32);
+  type MoveGenerator = MultiNimMoveIter;
+
+  fn move_generator(&self) -> MultiNimMoveIter {
+    MultiNimMoveIter { heap: 0, sticks: 0 }
+  }
+
+  fn make_move(&mut self, (heap, sticks): (usize, u32)) {
+    debug_assert!(sticks > 0 && sticks <= self.heaps[heap]);
+    self.heaps[heap] -= sticks;
+    self.player1 = !self.player1;
+  }
+
+  fn current_player(&self) -> GamePlayer {
+    if self.player1 {
+      GamePlayer::Player1
+    } else {
+      GamePlayer::Player2
+    }
+  }
+
+  fn finished(&self) -> GameResult {
+    if self.heaps.iter().any(|&sticks| sticks != 0) {
+      return GameResult::NotFinished;
+    }
+
+    // The previous player took the last stick.
+    let last_mover = self.current_player().opposite();
+    GameResult::Win(if self.misere {
+      last_mover.opposite()
+    } else {
+      last_mover
+    })
+  }
+}
+
+impl Display for MultiNim {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Heaps: {}", self.heaps.iter().format(" "))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+
+  use crate::{solvers::TablebaseSolver, test_games::MultiNim, Game, GamePlayer, GameResult};
+
+  /// True if the player to move in `game` can force a win.
+  fn first_player_wins(game: &MultiNim) -> bool {
+    TablebaseSolver::build(game)
+      .lookup(game)
+      .unwrap()
+      .into_score()
+      .is_winning()
+  }
+
+  #[gtest]
+  fn test_each_move() {
+    expect_eq!(
+      MultiNim::new(vec![2, 0, 1]).each_move().collect_vec(),
+      vec![(0, 1), (0, 2), (2, 1)]
+    );
+    expect_that!(
+      MultiNim::new(vec![0, 0]).each_move().collect_vec(),
+      is_empty()
+    );
+  }
+
+  #[gtest]
+  fn test_finished() {
+    let mut game = MultiNim::new(vec![1, 2]);
+    game.make_move((1, 2));
+    expect_eq!(game.finished(), GameResult::NotFinished);
+    game.make_move((0, 1));
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player2));
+    expect_eq!(game.to_string(), "Heaps: 0 0");
+
+    let mut game = MultiNim::new(vec![1, 2]).with_misere(true);
+    game.make_move((1, 2));
+    game.make_move((0, 1));
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+  }
+
+  #[gtest]
+  fn test_nim_sum() {
+    for heaps in [
+      vec![1],
+      vec![1, 1],
+      vec![1, 2],
+      vec![1, 2, 3],
+      vec![1, 2, 4],
+      vec![2, 3, 4],
+      vec![3, 4, 5],
+      vec![1, 4, 5],
+      vec![2, 2, 2, 2],
+    ] {
+      let nim_sum = heaps.iter().fold(0, |acc, &sticks| acc ^ sticks);
+      expect_eq!(
+        first_player_wins(&MultiNim::new(heaps.clone())),
+        nim_sum != 0,
+        "{heaps:?}"
+      );
+    }
+  }
+
+  #[gtest]
+  fn test_misere() {
+    // With every heap holding one stick, the misère outcome is flipped.
+    for heaps in [vec![1], vec![1, 1], vec![1, 1, 1]] {
+      let normal = MultiNim::new(heaps.clone());
+      let misere = normal.clone().with_misere(true);
+      expect_ne!(
+        first_player_wins(&normal),
+        first_player_wins(&misere),
+        "{heaps:?}"
+      );
+    }
+
+    // Otherwise, it matches normal play.
+    let misere = MultiNim::new(vec![2, 3, 4]).with_misere(true);
+    expect_true!(first_player_wins(&misere));
+    let misere = MultiNim::new(vec![1, 2, 3]).with_misere(true);
+    expect_false!(first_player_wins(&misere));
+  }
+}
@@ -1,6 +1,8 @@
 use std::fmt::Display;
 
-use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+use itertools::Itertools;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult, GameSerialize, UndoableGame};
 
 pub struct NimMoveIter {
   sticks: u32,
@@ -10,7 +12,7 @@ impl GameMoveIterator for NimMoveIter {
   type Game = Nim;
 
   fn next(&mut self, nim: &Nim) -> Option<u32> {
-    if self.sticks >= Nim::MAX_STICKS_PER_TURN.min(nim.sticks) {
+    if self.sticks >= nim.max_take.min(nim.sticks) {
       None
     } else {
       self.sticks += 1;
@@ -23,18 +25,46 @@ impl GameMoveIterator for NimMoveIter {
 pub struct Nim {
   sticks: u32,
   player1: bool,
+  /// The most sticks a player may take in one turn.
+  max_take: u32,
+  /// If true, the player who takes the last stick loses.
+  misere: bool,
 }
 
 impl Nim {
-  pub const MAX_STICKS_PER_TURN: u32 = 2;
+  /// The most sticks a player may take in one turn in games constructed with
+  /// `Nim::new`.
+  pub const DEFAULT_MAX_TAKE: u32 = 2;
 
   pub fn new(sticks: u32) -> Self {
-    Self { sticks, player1: true }
+    Self::with_max_take(sticks, Self::DEFAULT_MAX_TAKE)
+  }
+
+  /// Constructs a game where players may take between 1 and `max_take`
+  /// sticks per turn.
+  pub fn with_max_take(sticks: u32, max_take: u32) -> Self {
+    debug_assert!(max_take > 0);
+    Self {
+      sticks,
+      player1: true,
+      max_take,
+      misere: false,
+    }
+  }
+
+  /// Constructs a game of misère Nim, where the player who takes the last
+  /// stick loses.
+  pub fn new_misere(sticks: u32) -> Self {
+    Self { misere: true, ..Self::new(sticks) }
   }
 
   pub fn sticks(&self) -> u32 {
     self.sticks
   }
+
+  pub fn max_take(&self) -> u32 {
+    self.max_take
+  }
 }
 
 impl Game for Nim {
@@ -45,8 +75,23 @@ impl Game for Nim {
     NimMoveIter { sticks: 0 }
   }
 
+  fn format_move(&self, sticks: u32) -> String {
+    format!("take {sticks}")
+  }
+
+  fn is_legal_move(&self, sticks: u32) -> bool {
+    (1..=self.max_take.min(self.sticks)).contains(&sticks)
+  }
+
+  fn position_key(&self) -> u64 {
+    ((self.max_take as u64) << 34)
+      | ((self.sticks as u64) << 2)
+      | ((self.misere as u64) << 1)
+      | self.player1 as u64
+  }
+
   fn make_move(&mut self, sticks: u32) {
-    debug_assert!(sticks <= self.sticks);
+    debug_assert!(sticks <= self.sticks.min(self.max_take));
     self.sticks -= sticks;
     self.player1 = !self.player1;
   }
@@ -61,10 +106,11 @@ impl Game for Nim {
 
   fn finished(&self) -> GameResult {
     if self.sticks == 0 {
-      GameResult::Win(if self.player1 {
-        GamePlayer::Player2
+      let last_mover = self.current_player().opposite();
+      GameResult::Win(if self.misere {
+        last_mover.opposite()
       } else {
-        GamePlayer::Player1
+        last_mover
       })
     } else {
       GameResult::NotFinished
@@ -72,8 +118,196 @@ impl Game for Nim {
   }
 }
 
+impl UndoableGame for Nim {
+  fn undo_move(&mut self, sticks: u32) {
+    self.sticks += sticks;
+    self.player1 = !self.player1;
+  }
+}
+
+/// The notation is `<sticks>:<max take>:<player to move>`, with the player as
+/// `1` or `2`, followed by `:misere` for misère games, e.g. `7:2:1`.
+impl GameSerialize for Nim {
+  fn to_notation(&self) -> String {
+    let player = if self.player1 { 1 } else { 2 };
+    let misere = if self.misere { ":misere" } else { "" };
+    format!("{}:{}:{player}{misere}", self.sticks, self.max_take)
+  }
+
+  fn from_notation(notation: &str) -> Result<Self, String> {
+    let fields = notation.split(':').collect_vec();
+    let (sticks, max_take, player, misere) = match *fields.as_slice() {
+      [sticks, max_take, player] => (sticks, max_take, player, false),
+      [sticks, max_take, player, "misere"] => (sticks, max_take, player, true),
+      _ => {
+        return Err(format!(
+          "Expected \"sticks:max_take:player[:misere]\", found \"{notation}\""
+        ))
+      }
+    };
+
+    let parse_num = |num: &str| {
+      num
+        .parse::<u32>()
+        .map_err(|err| format!("Invalid number \"{num}\": {err}"))
+    };
+    let (sticks, max_take) = (parse_num(sticks)?, parse_num(max_take)?);
+    if max_take == 0 {
+      return Err("Players must be able to take at least 1 stick".to_owned());
+    }
+    let player1 = match player {
+      "1" => true,
+      "2" => false,
+      _ => return Err(format!("Unknown player \"{player}\"")),
+    };
+    Ok(Self { sticks, player1, max_take, misere })
+  }
+}
+
 impl Display for Nim {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(f, "Sticks left: {}", self.sticks)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+  use rand::{rngs::StdRng, SeedableRng};
+
+  use crate::{
+    solvers::MinimaxSolver, test_games::Nim, test_util::make_deterministic_random_move, Game,
+    GamePlayer, GameResult, GameSerialize, Solver, UndoableGame,
+  };
+
+  #[gtest]
+  fn test_each_move() {
+    expect_eq!(Nim::new(5).each_move().collect_vec(), vec![1, 2]);
+    expect_eq!(Nim::new(1).each_move().collect_vec(), vec![1]);
+    expect_that!(Nim::new(0).each_move().collect_vec(), is_empty());
+  }
+
+  #[gtest]
+  fn test_each_move_max_take() {
+    for max_take in 1..=4 {
+      for sticks in 0..=6 {
+        expect_eq!(
+          Nim::with_max_take(sticks, max_take)
+            .each_move()
+            .collect_vec(),
+          (1..=max_take.min(sticks)).collect_vec(),
+          "{sticks} sticks, taking at most {max_take}"
+        );
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_is_legal_move() {
+    for max_take in 1..=4 {
+      for sticks in 0..=6 {
+        let game = Nim::with_max_take(sticks, max_take);
+        let legal_moves = game.each_move().collect_vec();
+        for m in 0..=6 {
+          expect_eq!(
+            game.is_legal_move(m),
+            legal_moves.contains(&m),
+            "{sticks} sticks, taking at most {max_take}, move {m}"
+          );
+        }
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_max_take_outcome() {
+    let mut solver = MinimaxSolver::new();
+    for sticks in 1..=15 {
+      // Taking 1 to 3 sticks, the first player loses on multiples of 4.
+      let (score, _) = solver.best_move(&Nim::with_max_take(sticks, 3), 20);
+      expect_eq!(score.is_losing(), sticks % 4 == 0, "{sticks} sticks");
+    }
+  }
+
+  #[gtest]
+  fn test_misere_finished() {
+    let mut game = Nim::new(2);
+    game.make_move(2);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+
+    let mut game = Nim::new_misere(2);
+    game.make_move(2);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player2));
+  }
+
+  #[gtest]
+  fn test_misere_outcome() {
+    let mut solver = MinimaxSolver::new();
+    for sticks in 1..=15 {
+      let (normal, _) = solver.best_move(&Nim::new(sticks), 20);
+      let (misere, _) = solver.best_move(&Nim::new_misere(sticks), 20);
+
+      // The first player loses normal play when the sticks are a multiple of
+      // 3, and misère play when they are one more than a multiple of 3.
+      expect_eq!(normal.is_losing(), sticks % 3 == 0, "{sticks} sticks");
+      expect_eq!(misere.is_losing(), sticks % 3 == 1, "{sticks} sticks");
+    }
+
+    // With 3 sticks, the first player loses normal play but wins misère play.
+    expect_true!(solver.best_move(&Nim::new(3), 20).0.is_losing());
+    expect_true!(solver.best_move(&Nim::new_misere(3), 20).0.is_winning());
+  }
+
+  #[gtest]
+  fn test_undo_move() {
+    let mut rng = StdRng::seed_from_u64(1267);
+    for _ in 0..20 {
+      let mut game = Nim::new(20);
+      let mut history = vec![game.clone()];
+      while let Some(m) = make_deterministic_random_move(&mut game, &mut rng) {
+        let mut undone = game.clone();
+        undone.undo_move(m);
+        expect_eq!(&undone, history.last().unwrap());
+        history.push(game.clone());
+      }
+    }
+  }
+
+  #[gtest]
+  fn test_position_key() {
+    let mut game1 = Nim::new(10);
+    game1.make_move(1);
+    game1.make_move(2);
+    let mut game2 = Nim::new(10);
+    game2.make_move(2);
+    game2.make_move(1);
+    expect_eq!(game1.position_key(), game2.position_key());
+
+    // Same number of sticks, but a different player to move.
+    let mut game3 = Nim::new(8);
+    game3.make_move(1);
+    expect_ne!(game1.position_key(), game3.position_key());
+    expect_ne!(game1.position_key(), Nim::new(6).position_key());
+    expect_ne!(
+      Nim::new(7).position_key(),
+      Nim::with_max_take(7, 3).position_key()
+    );
+  }
+
+  #[gtest]
+  fn test_notation() {
+    let game = Nim::with_max_take(9, 3).with_move(2);
+    expect_eq!(game.to_notation(), "7:3:2");
+    expect_that!(Nim::from_notation("7:3:2"), ok(eq(&game)));
+
+    let game = Nim::new_misere(4);
+    expect_eq!(game.to_notation(), "4:2:1:misere");
+    expect_that!(Nim::from_notation("4:2:1:misere"), ok(eq(&game)));
+
+    expect_that!(Nim::from_notation("4:2"), err(anything()));
+    expect_that!(Nim::from_notation("4:0:1"), err(anything()));
+    expect_that!(Nim::from_notation("4:2:3"), err(anything()));
+    expect_that!(Nim::from_notation("4:2:1:normal"), err(anything()));
+  }
+}
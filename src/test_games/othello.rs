@@ -0,0 +1,321 @@
+use std::{
+  cmp::Ordering,
+  fmt::{Debug, Display},
+};
+
+use itertools::Itertools;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+
+const SIZE: u32 = 8;
+
+const DIRECTIONS: [(i32, i32); 8] = [
+  (1, 0),
+  (1, 1),
+  (0, 1),
+  (-1, 1),
+  (-1, 0),
+  (-1, -1),
+  (0, -1),
+  (1, -1),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OthelloMove {
+  Place {
+    x: u32,
+    y: u32,
+  },
+  /// Passes the turn, which is only legal when the current player has no
+  /// placements.
+  Pass,
+}
+
+pub struct OthelloMoveGen {
+  idx: u32,
+  found_placement: bool,
+}
+
+impl GameMoveIterator for OthelloMoveGen {
+  type Game = Othello;
+
+  fn next(&mut self, game: &Othello) -> Option<OthelloMove> {
+    while self.idx < SIZE * SIZE {
+      let pos = (self.idx % SIZE, self.idx / SIZE);
+      self.idx += 1;
+      if game.flips(game.current_player, pos) != 0 {
+        self.found_placement = true;
+        return Some(OthelloMove::Place { x: pos.0, y: pos.1 });
+      }
+    }
+
+    // The player must pass if they can't place a disc, unless the opponent
+    // can't either, in which case the game is over.
+    if self.idx == SIZE * SIZE
+      && !self.found_placement
+      && game.has_placement(game.current_player.opposite())
+    {
+      self.idx += 1;
+      return Some(OthelloMove::Pass);
+    }
+    None
+  }
+}
+
+/// Reversi on the standard 8x8 board. A disc may only be placed where it
+/// flanks a line of the opponent's discs, which are then flipped.
+///
+/// A player with no legal placement must play `OthelloMove::Pass`, so turns
+/// still strictly alternate. The game ends when neither player can place a
+/// disc, and the player with more discs wins.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Othello {
+  player1_discs: u64,
+  player2_discs: u64,
+  current_player: GamePlayer,
+}
+
+impl Othello {
+  pub fn new() -> Self {
+    Self {
+      player1_discs: Self::bit((4, 3)) | Self::bit((3, 4)),
+      player2_discs: Self::bit((3, 3)) | Self::bit((4, 4)),
+      current_player: GamePlayer::Player1,
+    }
+  }
+
+  /// The player with a disc at `pos`, if any.
+  pub fn at(&self, pos: (u32, u32)) -> Option<GamePlayer> {
+    debug_assert!(pos.0 < SIZE && pos.1 < SIZE);
+    let bit = Self::bit(pos);
+    if self.player1_discs & bit != 0 {
+      Some(GamePlayer::Player1)
+    } else if self.player2_discs & bit != 0 {
+      Some(GamePlayer::Player2)
+    } else {
+      None
+    }
+  }
+
+  /// The number of discs `player` has on the board.
+  pub fn disc_count(&self, player: GamePlayer) -> u32 {
+    self.discs(player).count_ones()
+  }
+
+  const fn bit(pos: (u32, u32)) -> u64 {
+    1 << (pos.0 + pos.1 * SIZE)
+  }
+
+  fn discs(&self, player: GamePlayer) -> u64 {
+    match player {
+      GamePlayer::Player1 => self.player1_discs,
+      GamePlayer::Player2 => self.player2_discs,
+    }
+  }
+
+  fn discs_mut(&mut self, player: GamePlayer) -> &mut u64 {
+    match player {
+      GamePlayer::Player1 => &mut self.player1_discs,
+      GamePlayer::Player2 => &mut self.player2_discs,
+    }
+  }
+
+  /// Returns the mask of the opponent's discs that `player` placing a disc at
+  /// `pos` would flip, which is empty if the placement is illegal.
+  fn flips(&self, player: GamePlayer, pos: (u32, u32)) -> u64 {
+    if self.at(pos).is_some() {
+      return 0;
+    }
+
+    let own = self.discs(player);
+    let opponent = self.discs(player.opposite());
+    DIRECTIONS
+      .iter()
+      .map(|&(dx, dy)| {
+        let mut line = 0;
+        let (mut x, mut y) = (pos.0 as i32 + dx, pos.1 as i32 + dy);
+        while (0..SIZE as i32).contains(&x) && (0..SIZE as i32).contains(&y) {
+          let bit = Self::bit((x as u32, y as u32));
+          if opponent & bit != 0 {
+            line |= bit;
+          } else if own & bit != 0 {
+            return line;
+          } else {
+            return 0;
+          }
+          x += dx;
+          y += dy;
+        }
+        0
+      })
+      .fold(0, |flips, line| flips | line)
+  }
+
+  /// True if `player` has any legal placement.
+  fn has_placement(&self, player: GamePlayer) -> bool {
+    (0..SIZE)
+      .cartesian_product(0..SIZE)
+      .any(|pos| self.flips(player, pos) != 0)
+  }
+}
+
+impl Default for Othello {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl Game for Othello {
+  type Move = OthelloMove;
+  type MoveGenerator = OthelloMoveGen;
+
+  /// Every empty cell, of which there are at most 60 after the 4 starting
+  /// discs are placed.
+  const MAX_MOVES: usize = (SIZE * SIZE) as usize - 4;
+
+  fn move_generator(&self) -> OthelloMoveGen {
+    OthelloMoveGen { idx: 0, found_placement: false }
+  }
+
+  fn make_move(&mut self, m: OthelloMove) {
+    let player = self.current_player;
+    match m {
+      OthelloMove::Place { x, y } => {
+        let flips = self.flips(player, (x, y));
+        debug_assert_ne!(flips, 0);
+        *self.discs_mut(player) |= Self::bit((x, y)) | flips;
+        *self.discs_mut(player.opposite()) &= !flips;
+      }
+      OthelloMove::Pass => {
+        debug_assert!(!self.has_placement(player));
+      }
+    }
+    self.current_player = player.opposite();
+  }
+
+  fn current_player(&self) -> GamePlayer {
+    self.current_player
+  }
+
+  fn finished(&self) -> GameResult {
+    if self.has_placement(self.current_player) || self.has_placement(self.current_player.opposite())
+    {
+      return GameResult::NotFinished;
+    }
+
+    let player1_count = self.disc_count(GamePlayer::Player1);
+    let player2_count = self.disc_count(GamePlayer::Player2);
+    match player1_count.cmp(&player2_count) {
+      Ordering::Greater => GameResult::Win(GamePlayer::Player1),
+      Ordering::Less => GameResult::Win(GamePlayer::Player2),
+      Ordering::Equal => GameResult::Tie,
+    }
+  }
+}
+
+/// Includes the player to move, which the board alone doesn't determine since
+/// a player with no placements passes.
+impl Debug for Othello {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "{self}\n{:?} to move", self.current_player)
+  }
+}
+
+impl Display for Othello {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    let rows = (0..SIZE).rev().map(|y| {
+      (0..SIZE)
+        .map(|x| match self.at((x, y)) {
+          None => '.',
+          Some(GamePlayer::Player1) => 'X',
+          Some(GamePlayer::Player2) => 'O',
+        })
+        .collect::<String>()
+    });
+    write!(f, "{}", rows.format("\n"))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+
+  use crate::{
+    test_games::{Othello, OthelloMove},
+    Game, GamePlayer, GameResult,
+  };
+
+  fn place(x: u32, y: u32) -> OthelloMove {
+    OthelloMove::Place { x, y }
+  }
+
+  #[gtest]
+  fn test_opening_moves() {
+    let game = Othello::new();
+    expect_eq!(game.current_player(), GamePlayer::Player1);
+    expect_eq!(game.finished(), GameResult::NotFinished);
+    expect_that!(
+      game.each_move().collect_vec(),
+      unordered_elements_are![
+        eq(&place(3, 2)),
+        eq(&place(2, 3)),
+        eq(&place(5, 4)),
+        eq(&place(4, 5)),
+      ]
+    );
+  }
+
+  #[gtest]
+  fn test_capture_flips() {
+    let mut game = Othello::new();
+    game.make_move(place(3, 2));
+    expect_eq!(game.at((3, 3)), Some(GamePlayer::Player1));
+    expect_eq!(game.disc_count(GamePlayer::Player1), 4);
+    expect_eq!(game.disc_count(GamePlayer::Player2), 1);
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+
+    // Flanking diagonally with the disc at (4, 4) flips (3, 3) back.
+    game.make_move(place(2, 2));
+    expect_eq!(game.at((3, 3)), Some(GamePlayer::Player2));
+    expect_eq!(game.disc_count(GamePlayer::Player1), 3);
+    expect_eq!(game.disc_count(GamePlayer::Player2), 3);
+    expect_eq!(
+      game.to_string(),
+      "........\n\
+       ........\n\
+       ........\n\
+       ...XO...\n\
+       ...OX...\n\
+       ..OX....\n\
+       ........\n\
+       ........"
+    );
+  }
+
+  #[gtest]
+  fn test_pass() {
+    // Player 1 can't flank player 2's discs in the corner, but player 2 can
+    // flank player 1's disc.
+    let mut game = Othello {
+      player1_discs: Othello::bit((2, 0)),
+      player2_discs: Othello::bit((0, 0)) | Othello::bit((1, 0)),
+      current_player: GamePlayer::Player1,
+    };
+    expect_eq!(game.finished(), GameResult::NotFinished);
+    expect_eq!(game.each_move().collect_vec(), vec![OthelloMove::Pass]);
+
+    let before_pass = game.position_key();
+    game.make_move(OthelloMove::Pass);
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+    // The board is unchanged, but it's the other player's turn.
+    expect_ne!(game.position_key(), before_pass);
+    expect_eq!(game.each_move().collect_vec(), vec![place(3, 0)]);
+
+    // Player 1 has no discs left, so neither player can move.
+    game.make_move(place(3, 0));
+    expect_eq!(game.disc_count(GamePlayer::Player1), 0);
+    expect_that!(game.each_move().collect_vec(), is_empty());
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player2));
+  }
+}
@@ -0,0 +1,164 @@
+use std::fmt::Display;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+
+pub struct SubtractionMoveIter {
+  idx: usize,
+}
+
+impl GameMoveIterator for SubtractionMoveIter {
+  type Game = Subtraction;
+
+  fn next(&mut self, game: &Subtraction) -> Option<u32> {
+    while let Some(&sticks) = game.allowed.get(self.idx) {
+      self.idx += 1;
+      if sticks <= game.sticks {
+        return Some(sticks);
+      }
+    }
+    None
+  }
+}
+
+/// A subtraction game, where a move removes any number of sticks from the
+/// allowed set from a single heap. The player who takes the last stick wins.
+///
+/// `Nim` is the subtraction game whose allowed set is `1..=max_take`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Subtraction {
+  sticks: u32,
+  /// The numbers of sticks that may be removed in one move.
+  allowed: Vec<u32>,
+  player1: bool,
+}
+
+impl Subtraction {
+  pub fn new(sticks: u32, allowed: Vec<u32>) -> Self {
+    debug_assert!(!allowed.contains(&0));
+    Self { sticks, allowed, player1: true }
+  }
+
+  pub fn sticks(&self) -> u32 {
+    self.sticks
+  }
+
+  pub fn allowed(&self) -> &[u32] {
+    &self.allowed
+  }
+}
+
+impl Game for Subtraction {
+  type Move = u32;
+  type MoveGenerator = SubtractionMoveIter;
+
+  fn move_generator(&self) -> SubtractionMoveIter {
+    SubtractionMoveIter { idx: 0 }
+  }
+
+  fn make_move(&mut self, sticks: u32) {
+    debug_assert!(self.allowed.contains(&sticks) && sticks <= self.sticks);
+    self.sticks -= sticks;
+    self.player1 = !self.player1;
+  }
+
+  fn current_player(&self) -> GamePlayer {
+    if self.player1 {
+      GamePlayer::Player1
+    } else {
+      GamePlayer::Player2
+    }
+  }
+
+  fn finished(&self) -> GameResult {
+    if self.each_move().next().is_some() {
+      return GameResult::NotFinished;
+    }
+
+    // The player to move is stuck, so the previous player made the last move.
+    GameResult::Win(self.current_player().opposite())
+  }
+}
+
+impl Display for Subtraction {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Sticks left: {}", self.sticks)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use googletest::{gtest, prelude::*};
+  use itertools::Itertools;
+
+  use crate::{solvers::TablebaseSolver, test_games::Subtraction, Game, GamePlayer, GameResult};
+
+  /// The Grundy number of `game`, the smallest number which isn't the Grundy
+  /// number of any position reachable in one move.
+  fn grundy(game: &Subtraction) -> u32 {
+    let reachable = game
+      .each_move()
+      .map(|m| grundy(&game.with_move(m)))
+      .collect_vec();
+    (0..).find(|n| !reachable.contains(n)).unwrap()
+  }
+
+  #[gtest]
+  fn test_each_move() {
+    expect_eq!(
+      Subtraction::new(5, vec![1, 3, 4]).each_move().collect_vec(),
+      vec![1, 3, 4]
+    );
+    expect_eq!(
+      Subtraction::new(3, vec![1, 3, 4]).each_move().collect_vec(),
+      vec![1, 3]
+    );
+    expect_that!(
+      Subtraction::new(1, vec![2, 5]).each_move().collect_vec(),
+      is_empty()
+    );
+  }
+
+  #[gtest]
+  fn test_finished() {
+    let mut game = Subtraction::new(4, vec![1, 3, 4]);
+    game.make_move(3);
+    expect_eq!(game.finished(), GameResult::NotFinished);
+    game.make_move(1);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player2));
+    expect_eq!(game.to_string(), "Sticks left: 0");
+
+    // Sticks may be left over if no allowed move fits.
+    let game = Subtraction::new(5, vec![3]).with_move(3);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+  }
+
+  #[gtest]
+  fn test_grundy_period() {
+    // The Grundy sequence of the {1, 3, 4} game repeats with period 7.
+    let sequence = (0..28)
+      .map(|sticks| grundy(&Subtraction::new(sticks, vec![1, 3, 4])))
+      .collect_vec();
+    for (sticks, &value) in sequence.iter().enumerate() {
+      expect_eq!(value, [0, 1, 0, 1, 2, 3, 2][sticks % 7], "{sticks} sticks");
+    }
+  }
+
+  #[gtest]
+  fn test_solver_losing_positions() {
+    // The positions with Grundy number 0 are the losing positions, which for
+    // the {1, 3, 4} game are those congruent to 0 or 2 modulo 7.
+    for sticks in 1..=21 {
+      let game = Subtraction::new(sticks, vec![1, 3, 4]);
+      let score = TablebaseSolver::build(&game)
+        .lookup(&game)
+        .unwrap()
+        .into_score();
+      expect_eq!(score.is_losing(), grundy(&game) == 0, "{sticks} sticks");
+      expect_eq!(
+        score.is_losing(),
+        [0, 2].contains(&(sticks % 7)),
+        "{sticks} sticks"
+      );
+    }
+  }
+}
@@ -1,25 +1,39 @@
-use std::fmt::{Debug, Display};
+use std::{
+  fmt::{Debug, Display},
+  str::FromStr,
+};
 
-use crate::{Game, GameMoveIterator, GamePlayer, GameResult};
+use itertools::Itertools;
+
+use crate::{Game, GameMoveIterator, GamePlayer, GameResult, GameSerialize, GridSetup};
+
+/// The distance between the bits of vertically adjacent cells in a board's
+/// bitmasks, which is also the largest supported board size.
+const STRIDE: u32 = 8;
 
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct TTTMove(u32);
 
 impl TTTMove {
   pub fn new(coord: (u32, u32)) -> Self {
-    Self(0x0001_0001 << (coord.0 + coord.1 * 4))
+    debug_assert!(coord.0 < STRIDE && coord.1 < STRIDE);
+    Self(coord.0 + coord.1 * STRIDE)
   }
 
   pub fn board_index(&self) -> u32 {
-    self.0.trailing_zeros() % 16
+    self.0
   }
 
   pub fn x(&self) -> u32 {
-    self.board_index() % 4
+    self.0 % STRIDE
   }
 
   pub fn y(&self) -> u32 {
-    self.board_index() / 4
+    self.0 / STRIDE
+  }
+
+  const fn bit(&self) -> u64 {
+    1 << self.0
   }
 }
 
@@ -30,56 +44,136 @@ impl Debug for TTTMove {
 }
 
 pub struct TTTMoveGen {
-  move_mask: u32,
+  cell: u32,
 }
 
 impl GameMoveIterator for TTTMoveGen {
   type Game = TicTacToe;
 
   fn next(&mut self, game: &TicTacToe) -> Option<TTTMove> {
-    let mut move_mask = self.move_mask;
-    while move_mask != 0x1000_1000 {
-      let next_mask = move_mask << 1;
-
-      if game.board & move_mask == 0 {
-        self.move_mask = next_mask;
-        return Some(TTTMove(move_mask));
+    while self.cell < game.size * game.size {
+      let pos = (self.cell % game.size, self.cell / game.size);
+      self.cell += 1;
+      if game.is_empty(pos) {
+        return Some(TTTMove::new(pos));
       }
-
-      move_mask = next_mask;
     }
     None
   }
 }
 
+/// Tic-tac-toe on a `size` x `size` board, won by getting `in_a_row` tiles in
+/// a row, column, or diagonal. `TicTacToe::new` is the standard 3x3 game.
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub struct TicTacToe {
-  board: u32,
+  /// The tiles of each player, where the tile at `(x, y)` is bit
+  /// `x + y * STRIDE`.
+  player1_tiles: u64,
+  player2_tiles: u64,
+  size: u32,
+  in_a_row: u32,
+  /// The cells a line may start from in each of `LINE_STEPS`' directions
+  /// without running off the board.
+  line_starts: [u64; 4],
   current_player: GamePlayer,
 }
 
 impl TicTacToe {
-  /// Bits that are never in use for the board.
-  const PHONY_BITS: u32 = 0xf888_f888;
+  /// The largest supported board size.
+  pub const MAX_SIZE: u32 = STRIDE;
+
+  /// The directions lines can run in, as the distance between the bits of
+  /// adjacent cells along them: rows, columns, diagonals, and anti-diagonals.
+  const LINE_STEPS: [u32; 4] = [1, STRIDE, STRIDE + 1, STRIDE - 1];
 
   pub fn new() -> Self {
+    Self::with_size(3, 3)
+  }
+
+  /// An empty `size` x `size` board, won with `in_a_row` tiles in a row.
+  pub fn with_size(size: u32, in_a_row: u32) -> Self {
+    debug_assert!((1..=Self::MAX_SIZE).contains(&size));
+    debug_assert!((1..=size).contains(&in_a_row));
+    // The number of cells a line can start from along each axis.
+    let span = size - in_a_row + 1;
     Self {
-      board: Self::PHONY_BITS,
+      player1_tiles: 0,
+      player2_tiles: 0,
+      size,
+      in_a_row,
+      line_starts: [
+        Self::block_mask(0, span, size),
+        Self::block_mask(0, size, span),
+        Self::block_mask(0, span, span),
+        Self::block_mask(in_a_row - 1, span, span),
+      ],
       current_player: GamePlayer::Player1,
     }
   }
 
+  pub fn size(&self) -> u32 {
+    self.size
+  }
+
+  pub fn in_a_row(&self) -> u32 {
+    self.in_a_row
+  }
+
   pub fn is_empty(&self, pos: (u32, u32)) -> bool {
-    let m = TTTMove::new(pos);
-    (self.board & m.0) == 0
+    debug_assert!(pos.0 < self.size && pos.1 < self.size);
+    (self.player1_tiles | self.player2_tiles) & TTTMove::new(pos).bit() == 0
   }
 
-  fn turn_mask(&self) -> u32 {
-    if self.current_player.is_p1() {
-      0x0000_ffff
+  /// The player with a tile at `pos`, if any.
+  fn at(&self, pos: (u32, u32)) -> Option<GamePlayer> {
+    let bit = TTTMove::new(pos).bit();
+    if self.player1_tiles & bit != 0 {
+      Some(GamePlayer::Player1)
+    } else if self.player2_tiles & bit != 0 {
+      Some(GamePlayer::Player2)
     } else {
-      0xffff_0000
+      None
+    }
+  }
+
+  fn tiles_mut(&mut self, player: GamePlayer) -> &mut u64 {
+    match player {
+      GamePlayer::Player1 => &mut self.player1_tiles,
+      GamePlayer::Player2 => &mut self.player2_tiles,
+    }
+  }
+
+  /// Returns this board with every tile moved by `transform`, which must map
+  /// the board onto itself.
+  fn transformed(&self, transform: impl Fn((u32, u32)) -> (u32, u32)) -> Self {
+    let mut game = Self {
+      player1_tiles: 0,
+      player2_tiles: 0,
+      ..self.clone()
+    };
+    for coord in (0..self.size).cartesian_product(0..self.size) {
+      if let Some(player) = self.at(coord) {
+        *game.tiles_mut(player) |= TTTMove::new(transform(coord)).bit();
+      }
     }
+    game
+  }
+
+  /// A mask of the `width` x `height` block of cells whose bottom-left corner
+  /// is `(x, 0)`.
+  fn block_mask(x: u32, width: u32, height: u32) -> u64 {
+    (0..height).fold(0, |mask, y| mask | (((1 << width) - 1) << (x + y * STRIDE)))
+  }
+
+  /// True if `tiles`, the tiles of one player, include `in_a_row` in a row,
+  /// column, or diagonal.
+  fn has_line(&self, tiles: u64) -> bool {
+    Self::LINE_STEPS
+      .into_iter()
+      .zip(self.line_starts)
+      .any(|(step, starts)| {
+        (1..self.in_a_row).fold(tiles & starts, |line, i| line & (tiles >> (i * step))) != 0
+      })
   }
 }
 
@@ -93,13 +187,21 @@ impl Game for TicTacToe {
   type Move = TTTMove;
   type MoveGenerator = TTTMoveGen;
 
+  const MAX_MOVES: usize = (Self::MAX_SIZE * Self::MAX_SIZE) as usize;
+
   fn move_generator(&self) -> TTTMoveGen {
-    TTTMoveGen { move_mask: 0x0001_0001 }
+    TTTMoveGen { cell: 0 }
+  }
+
+  /// The "X,Y" coordinates of the move counting from 1, as human players
+  /// enter them.
+  fn format_move(&self, m: TTTMove) -> String {
+    format!("{},{}", m.x() + 1, m.y() + 1)
   }
 
   fn make_move(&mut self, m: TTTMove) {
-    debug_assert_eq!(self.board & m.0, 0);
-    self.board += m.0 & self.turn_mask();
+    debug_assert!(self.is_empty((m.x(), m.y())));
+    *self.tiles_mut(self.current_player) |= m.bit();
     self.current_player = self.current_player.opposite();
   }
 
@@ -107,20 +209,59 @@ impl Game for TicTacToe {
     self.current_player
   }
 
-  fn finished(&self) -> GameResult {
-    // Check for 3 in a row, column, or diagonal.
-    let board = self.board & !Self::PHONY_BITS;
+  /// The 8 rotations and reflections of the board.
+  fn symmetries(&self) -> impl Iterator<Item = Self> {
+    type Transform = fn((u32, u32), u32) -> (u32, u32);
+    // Each transform takes a coordinate and the largest coordinate on the
+    // board.
+    const TRANSFORMS: [Transform; 8] = [
+      |(x, y), _| (x, y),
+      |(x, y), n| (n - y, x),
+      |(x, y), n| (n - x, n - y),
+      |(x, y), n| (y, n - x),
+      |(x, y), n| (n - x, y),
+      |(x, y), n| (x, n - y),
+      |(x, y), _| (y, x),
+      |(x, y), n| (n - y, n - x),
+    ];
+    let n = self.size - 1;
+    TRANSFORMS
+      .into_iter()
+      .map(move |transform| self.transformed(|coord| transform(coord, n)))
+  }
+
+  fn canonical_form(&self) -> Self {
+    self
+      .symmetries()
+      .min_by_key(|game| (game.player1_tiles, game.player2_tiles))
+      .unwrap()
+  }
 
-    let three_in_a_row = (board & (board >> 1) & (board >> 2)) != 0;
-    let three_in_a_col = (board & (board >> 4) & (board >> 8)) != 0;
+  /// X, who moves first, must have the same number of tiles as O or one more,
+  /// with the last player to move having made any line.
+  fn is_legal_state(&self) -> bool {
+    let (p1_count, p2_count) = (
+      self.player1_tiles.count_ones(),
+      self.player2_tiles.count_ones(),
+    );
+    let last_player = match p1_count.checked_sub(p2_count) {
+      Some(0) => GamePlayer::Player2,
+      Some(1) => GamePlayer::Player1,
+      _ => return false,
+    };
 
-    let contains_bits = |board: u32, bits: u32| -> bool { board & bits == bits };
-    let diag_tl_to_br = contains_bits(board, 0x0000_0421) || contains_bits(board, 0x0421_0000);
-    let diag_tr_to_bl = contains_bits(board, 0x0000_0124) || contains_bits(board, 0x0124_0000);
+    let p1_won = self.has_line(self.player1_tiles);
+    let p2_won = self.has_line(self.player2_tiles);
+    self.player1_tiles & self.player2_tiles == 0
+      && self.current_player == last_player.opposite()
+      && !(p1_won && last_player.is_p2())
+      && !(p2_won && last_player.is_p1())
+  }
 
-    if three_in_a_row || three_in_a_col || diag_tl_to_br || diag_tr_to_bl {
+  fn finished(&self) -> GameResult {
+    if self.has_line(self.player1_tiles) || self.has_line(self.player2_tiles) {
       GameResult::Win(self.current_player.opposite())
-    } else if board.count_ones() == 9 {
+    } else if (self.player1_tiles | self.player2_tiles).count_ones() == self.size * self.size {
       GameResult::Tie
     } else {
       GameResult::NotFinished
@@ -128,6 +269,102 @@ impl Game for TicTacToe {
   }
 }
 
+/// The notation lists the rows of the board from top to bottom separated by
+/// `/`, e.g. `X.O/.X./..O`, preceded by `K:` for boards won with `K` in a row
+/// when that is shorter than the side of the board, e.g. `3:..../..../..../X...`.
+/// The player to move is inferred from the number of tiles each player has.
+impl GameSerialize for TicTacToe {
+  fn to_notation(&self) -> String {
+    let rows = self.to_string().replace('\n', "/");
+    if self.in_a_row == self.size {
+      rows
+    } else {
+      format!("{}:{rows}", self.in_a_row)
+    }
+  }
+
+  fn from_notation(notation: &str) -> Result<Self, String> {
+    let (in_a_row, rows) = match notation.split_once(':') {
+      Some((in_a_row, rows)) => (
+        Some(
+          in_a_row
+            .parse::<u32>()
+            .map_err(|err| format!("Invalid number \"{in_a_row}\": {err}"))?,
+        ),
+        rows,
+      ),
+      None => (None, notation),
+    };
+
+    let rows = rows.split('/').collect_vec();
+    let size = rows.len() as u32;
+    let in_a_row = in_a_row.unwrap_or(size);
+    if size > Self::MAX_SIZE || in_a_row == 0 || in_a_row > size {
+      return Err(format!(
+        "Invalid size {size}x{size} for {in_a_row} in a row"
+      ));
+    }
+
+    let mut game = Self::with_size(size, in_a_row);
+    for (row, y) in rows.into_iter().zip((0..size).rev()) {
+      if row.chars().count() != size as usize {
+        return Err(format!("Expected {size} tiles in row \"{row}\""));
+      }
+      for (tile, x) in row.chars().zip(0..) {
+        let player = match tile {
+          '.' => continue,
+          'X' => GamePlayer::Player1,
+          'O' => GamePlayer::Player2,
+          _ => return Err(format!("Unknown tile '{tile}'")),
+        };
+        *game.tiles_mut(player) |= TTTMove::new((x, y)).bit();
+      }
+    }
+
+    if game.player1_tiles.count_ones() > game.player2_tiles.count_ones() {
+      game.current_player = GamePlayer::Player2;
+    }
+    if !game.is_legal_state() {
+      return Err(format!("Illegal position \"{notation}\""));
+    }
+    Ok(game)
+  }
+}
+
+/// Parses a board in the format it is displayed in, with the rows listed from
+/// top to bottom on separate lines, e.g. `"X.O\n.X.\n..O"`. Like
+/// `from_notation`, the player to move is inferred from the number of tiles
+/// each player has, and positions which can't arise in play are rejected.
+impl FromStr for TicTacToe {
+  type Err = String;
+
+  fn from_str(board: &str) -> Result<Self, String> {
+    Self::from_notation(&board.trim().lines().map(str::trim).join("/"))
+  }
+}
+
+impl GridSetup for TicTacToe {
+  fn as_grid(&self) -> Vec<Vec<Option<GamePlayer>>> {
+    (0..self.size)
+      .map(|y| (0..self.size).map(|x| self.at((x, y))).collect())
+      .collect()
+  }
+
+  fn set_cell(&mut self, pos: (u32, u32), piece: Option<GamePlayer>) {
+    debug_assert!(pos.0 < self.size && pos.1 < self.size);
+    let bit = TTTMove::new(pos).bit();
+    self.player1_tiles &= !bit;
+    self.player2_tiles &= !bit;
+    if let Some(player) = piece {
+      *self.tiles_mut(player) |= bit;
+    }
+  }
+
+  fn set_current_player(&mut self, player: GamePlayer) {
+    self.current_player = player;
+  }
+}
+
 impl Debug for TicTacToe {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
     write!(f, "{self}")
@@ -136,30 +373,16 @@ impl Debug for TicTacToe {
 
 impl Display for TicTacToe {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    let tile_at = |coord: (u32, u32)| {
-      let m = TTTMove::new(coord);
-      let masked = self.board & m.0;
-      if masked == 0 {
-        '.'
-      } else if masked < 0x0001_0000 {
-        'X'
-      } else {
-        'O'
-      }
-    };
-    write!(
-      f,
-      "{}{}{}\n{}{}{}\n{}{}{}",
-      tile_at((0, 2)),
-      tile_at((1, 2)),
-      tile_at((2, 2)),
-      tile_at((0, 1)),
-      tile_at((1, 1)),
-      tile_at((2, 1)),
-      tile_at((0, 0)),
-      tile_at((1, 0)),
-      tile_at((2, 0)),
-    )
+    let rows = (0..self.size).rev().map(|y| {
+      (0..self.size)
+        .map(|x| match self.at((x, y)) {
+          None => '.',
+          Some(GamePlayer::Player1) => 'X',
+          Some(GamePlayer::Player2) => 'O',
+        })
+        .collect::<String>()
+    });
+    write!(f, "{}", rows.format("\n"))
   }
 }
 
@@ -177,7 +400,10 @@ mod tests {
 
   use crate::{
     test_games::{TTTMove, TicTacToe},
-    Game, GameResult,
+    test_util::{
+      distinct_positions_modulo_symmetry, enumerate_states, enumerate_states_with_progress,
+    },
+    Game, GamePlayer, GameResult, GameSerialize,
   };
 
   #[gtest]
@@ -358,4 +584,186 @@ mod tests {
       ends_in_tie()
     );
   }
+
+  #[gtest]
+  fn test_with_size_moves() {
+    expect_eq!(TicTacToe::with_size(4, 3).each_move().count(), 16);
+    expect_eq!(TicTacToe::with_size(5, 4).each_move().count(), 25);
+    expect_eq!(
+      TicTacToe::with_size(TicTacToe::MAX_SIZE, 5)
+        .each_move()
+        .count(),
+      64
+    );
+
+    let game = TicTacToe::with_size(4, 3).with_move(TTTMove::new((3, 3)));
+    expect_eq!(game.each_move().count(), 15);
+    expect_that!(
+      game.each_move().collect_vec(),
+      not(contains(eq(&TTTMove::new((3, 3)))))
+    );
+  }
+
+  #[gtest]
+  fn test_with_size_wins() {
+    let play = |moves: &[(u32, u32)]| {
+      moves
+        .iter()
+        .fold(TicTacToe::with_size(4, 3), |game, &coord| {
+          game.with_move(TTTMove::new(coord))
+        })
+    };
+
+    // Three in a row along the right edge.
+    let game = play(&[(1, 0), (0, 0), (2, 0), (0, 3), (3, 0)]);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+    // Three in a column, not starting from the bottom.
+    let game = play(&[(0, 0), (3, 1), (1, 0), (3, 2), (0, 3), (3, 3)]);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player2));
+    // Off-center diagonals in both directions.
+    let game = play(&[(1, 0), (0, 0), (2, 1), (0, 1), (3, 2)]);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player1));
+    let game = play(&[(0, 0), (3, 1), (1, 0), (2, 2), (0, 1), (1, 3)]);
+    expect_eq!(game.finished(), GameResult::Win(GamePlayer::Player2));
+
+    // Lines don't wrap around the edges of the board.
+    let game = play(&[(2, 0), (0, 0), (3, 0), (0, 2), (0, 1)]);
+    expect_eq!(game.finished(), GameResult::NotFinished);
+    let game = play(&[(3, 0), (1, 0), (0, 1), (1, 1), (1, 2)]);
+    expect_eq!(game.finished(), GameResult::NotFinished);
+
+    expect_eq!(game.to_string(), "....\n.X..\nXO..\n.O.X");
+    expect_eq!(game.to_notation(), "3:..../.X../XO../.O.X");
+    expect_that!(
+      TicTacToe::from_notation("3:..../.X../XO../.O.X"),
+      ok(eq(&game))
+    );
+  }
+
+  #[gtest]
+  fn test_symmetries() {
+    let mut ttt = TicTacToe::new();
+    ttt.make_move(TTTMove::new((1, 0)));
+    ttt.make_move(TTTMove::new((2, 2)));
+
+    let symmetries = ttt.symmetries().collect_vec();
+    expect_eq!(symmetries.len(), 8);
+    expect_eq!(symmetries.iter().unique().count(), 8);
+    for game in &symmetries {
+      expect_eq!(game.canonical_form(), ttt.canonical_form());
+      expect_eq!(game.current_player(), ttt.current_player());
+    }
+
+    // Playing the moves of a rotated game reaches the rotated position.
+    let mut rotated = TicTacToe::new();
+    rotated.make_move(TTTMove::new((2, 1)));
+    rotated.make_move(TTTMove::new((0, 2)));
+    expect_that!(symmetries, contains(eq(&rotated)));
+    expect_eq!(rotated.canonical_form(), ttt.canonical_form());
+  }
+
+  #[gtest]
+  fn test_symmetric_canonical_form() {
+    let mut ttt = TicTacToe::new();
+    ttt.make_move(TTTMove::new((1, 1)));
+    expect_eq!(ttt.canonical_form(), ttt);
+    expect_that!(ttt.symmetries().collect_vec(), each(eq(&ttt)));
+
+    let mut corner = TicTacToe::new();
+    corner.make_move(TTTMove::new((0, 0)));
+    let mut other_corner = TicTacToe::new();
+    other_corner.make_move(TTTMove::new((2, 0)));
+    expect_ne!(corner, other_corner);
+    expect_eq!(corner.canonical_form(), other_corner.canonical_form());
+  }
+
+  #[gtest]
+  fn test_notation() {
+    let mut game = TicTacToe::new();
+    for coord in [(0, 2), (1, 1), (2, 0)] {
+      game.make_move(TTTMove::new(coord));
+    }
+    expect_eq!(game.to_notation(), "X../.O./..X");
+    expect_that!(TicTacToe::from_notation("X../.O./..X"), ok(eq(&game)));
+    expect_that!(
+      TicTacToe::from_notation(".../.../..."),
+      ok(eq(&TicTacToe::new()))
+    );
+
+    expect_that!(TicTacToe::from_notation("X../.O."), err(anything()));
+    expect_that!(TicTacToe::from_notation("X../.O./..Z"), err(anything()));
+  }
+
+  #[gtest]
+  fn test_from_str() {
+    let game: TicTacToe = "XX.\n.O.\n..O".parse().unwrap();
+    expect_eq!(game.current_player(), GamePlayer::Player1);
+    expect_eq!(game.to_string().parse::<TicTacToe>(), Ok(game.clone()));
+    expect_eq!(game.search_immediate_win(), Some(TTTMove::new((2, 2))));
+
+    let game: TicTacToe = "XX.\nOO.\n..X".parse().unwrap();
+    expect_eq!(game.current_player(), GamePlayer::Player2);
+    expect_eq!(game.search_immediate_win(), Some(TTTMove::new((2, 1))));
+
+    expect_that!("XX.\n.O.".parse::<TicTacToe>(), err(anything()));
+    // Too many X's.
+    expect_that!("XX.\nXO.\n...".parse::<TicTacToe>(), err(anything()));
+    // Both players have three in a row.
+    expect_that!("XXX\nOOO\nX.O".parse::<TicTacToe>(), err(anything()));
+  }
+
+  #[gtest]
+  fn test_illegal_notation() {
+    // Too many X's.
+    expect_that!(TicTacToe::from_notation("XXX/XO./.OX"), err(anything()));
+    // O moved first.
+    expect_that!(TicTacToe::from_notation("O../.../..."), err(anything()));
+    // Both players won.
+    expect_that!(TicTacToe::from_notation("XXX/OOO/..."), err(anything()));
+    // O won, but X moved after.
+    expect_that!(TicTacToe::from_notation("OOO/XX./X.X"), err(anything()));
+
+    // X won on the last move.
+    expect_that!(TicTacToe::from_notation("XXX/OO./..."), ok(anything()));
+  }
+
+  #[gtest]
+  fn test_reachable_states_are_legal() {
+    let mut game = TicTacToe::new();
+    for coord in [(0, 0), (0, 1), (1, 0), (1, 1), (2, 0)] {
+      expect_true!(game.is_legal_state(), "{game:?}");
+      game.make_move(TTTMove::new(coord));
+    }
+    expect_true!(game.is_legal_state(), "{game:?}");
+  }
+
+  #[gtest]
+  fn test_enumerate_states() {
+    expect_eq!(enumerate_states(&TicTacToe::new(), 0).len(), 1);
+    expect_eq!(enumerate_states(&TicTacToe::new(), 1).len(), 10);
+    expect_eq!(enumerate_states(&TicTacToe::new(), 9).len(), 5478);
+  }
+
+  #[gtest]
+  fn test_enumerate_states_with_progress() {
+    let mut reports = vec![];
+    let states = enumerate_states_with_progress(&TicTacToe::new(), 9, |discovered, frontier| {
+      reports.push((discovered, frontier))
+    });
+    expect_eq!(states.len(), enumerate_states(&TicTacToe::new(), 9).len());
+    expect_that!(reports, not(is_empty()));
+    expect_true!(reports
+      .iter()
+      .all(|&(discovered, _)| discovered <= states.len()));
+    expect_true!(reports.is_sorted());
+  }
+
+  #[gtest]
+  fn test_distinct_positions_modulo_symmetry() {
+    expect_eq!(distinct_positions_modulo_symmetry(&TicTacToe::new(), 1), 4);
+    expect_eq!(
+      distinct_positions_modulo_symmetry(&TicTacToe::new(), 9),
+      765
+    );
+  }
 }
@@ -1,7 +1,15 @@
+use std::{
+  collections::{HashSet, VecDeque},
+  marker::PhantomData,
+};
+
 use itertools::Itertools;
 use rand::Rng;
 
-use crate::Game;
+use crate::{
+  complete_solver::CompleteSolver, solver::move_score, Game, GameMoveIterator, GamePlayer,
+  GameResult, Score, SearchStats, Solver,
+};
 
 pub type AbstractGameResult<T = ()> = Result<T, String>;
 
@@ -9,6 +17,7 @@ pub fn make_deterministic_random_move<G: Game, R: Rng>(game: &mut G, rng: &mut R
 where
   G::Move: Ord,
 {
+  game.resolve_chance(rng);
   let mut moves = game.each_move().collect_vec();
   if moves.is_empty() {
     return None;
@@ -121,3 +130,197 @@ where
     })
     .collect()
 }
+
+/// Returns every distinct position reachable from `initial_state` within
+/// `max_depth` moves, including `initial_state`, in breadth-first order.
+/// Positions are distinguished by `Game::position_key`.
+pub fn enumerate_states<G: Game>(initial_state: &G, max_depth: u32) -> Vec<G> {
+  enumerate_states_with_progress(initial_state, max_depth, |_, _| {})
+}
+
+/// How many positions `enumerate_states_with_progress` discovers between
+/// progress reports.
+pub const ENUMERATE_PROGRESS_INTERVAL: usize = 1024;
+
+/// Like `enumerate_states`, but calls `on_progress` with the number of
+/// positions discovered so far and the number waiting to be expanded after
+/// every `ENUMERATE_PROGRESS_INTERVAL` positions discovered, for reporting
+/// progress on large games.
+pub fn enumerate_states_with_progress<G: Game>(
+  initial_state: &G,
+  max_depth: u32,
+  mut on_progress: impl FnMut(usize, usize),
+) -> Vec<G> {
+  let mut seen = HashSet::from([initial_state.position_key()]);
+  let mut states = vec![initial_state.clone()];
+  let mut frontier = VecDeque::from([(0, 0)]);
+  while let Some((idx, depth)) = frontier.pop_front() {
+    let state = states[idx].clone();
+    if depth == max_depth || state.finished().is_finished() {
+      continue;
+    }
+
+    for (_, child) in state.successors() {
+      if seen.insert(child.position_key()) {
+        frontier.push_back((states.len(), depth + 1));
+        states.push(child);
+        if states.len() % ENUMERATE_PROGRESS_INTERVAL == 0 {
+          on_progress(states.len(), frontier.len());
+        }
+      }
+    }
+  }
+  states
+}
+
+/// Counts the positions reachable from `initial_state` within `max_depth`
+/// moves, treating positions related by one of the game's `symmetries` as the
+/// same position.
+pub fn distinct_positions_modulo_symmetry<G: Game>(initial_state: &G, max_depth: u32) -> u64 {
+  enumerate_states(initial_state, max_depth)
+    .iter()
+    .map(|state| state.canonical_form().position_key())
+    .collect::<HashSet<_>>()
+    .len() as u64
+}
+
+/// Scores every move from `game` with both `s1` and `s2`, searching `depth`
+/// moves deep, for comparing two solvers' full move rankings. Returns each
+/// move with the scores from `s1` and `s2`, with the moves the solvers
+/// disagree on the outcome of (i.e. whose scores aren't `same_outcome`) listed
+/// first, otherwise in move generation order.
+pub fn diff_rankings<G, S1, S2>(
+  s1: &mut S1,
+  s2: &mut S2,
+  game: &G,
+  depth: u32,
+) -> Vec<(G::Move, Score, Score)>
+where
+  G: Game,
+  S1: Solver<Game = G>,
+  S2: Solver<Game = G>,
+{
+  let mut diff = game
+    .each_move()
+    .map(|m| {
+      (
+        m,
+        move_score(s1, game, m, depth),
+        move_score(s2, game, m, depth),
+      )
+    })
+    .collect_vec();
+  diff.sort_by_key(|(_, score1, score2)| score1.same_outcome(*score2));
+  diff
+}
+
+/// A wrapper around a game which generates the game's moves in reverse order,
+/// for checking that results don't depend on move generation order.
+#[derive(Clone, Debug)]
+pub struct ReversedMoveOrder<G>(pub G);
+
+pub struct ReversedMoveGen<G: Game> {
+  moves: Vec<G::Move>,
+}
+
+impl<G: Game> GameMoveIterator for ReversedMoveGen<G> {
+  type Game = ReversedMoveOrder<G>;
+
+  fn next(&mut self, _game: &ReversedMoveOrder<G>) -> Option<G::Move> {
+    self.moves.pop()
+  }
+}
+
+impl<G: Game> Game for ReversedMoveOrder<G> {
+  type Move = G::Move;
+  type MoveGenerator = ReversedMoveGen<G>;
+
+  const MAX_MOVES: usize = G::MAX_MOVES;
+
+  fn move_generator(&self) -> ReversedMoveGen<G> {
+    ReversedMoveGen { moves: self.0.each_move().collect() }
+  }
+
+  fn make_move(&mut self, m: G::Move) {
+    self.0.make_move(m);
+  }
+
+  fn current_player(&self) -> GamePlayer {
+    self.0.current_player()
+  }
+
+  fn finished(&self) -> GameResult {
+    self.0.finished()
+  }
+}
+
+/// A brute-force negamax solver which explores every line of play out to the
+/// search depth. This is far too slow for real use, but is simple enough to be
+/// used as a reference to test other solvers against.
+///
+/// Among equally scored moves, the smallest move is chosen, so the result
+/// doesn't depend on the order moves are generated in.
+pub struct NegamaxSolver<G> {
+  stats: SearchStats,
+  _game: PhantomData<G>,
+}
+
+impl<G> NegamaxSolver<G> {
+  pub fn new() -> Self {
+    Self {
+      stats: SearchStats::default(),
+      _game: PhantomData,
+    }
+  }
+
+  /// The stats accumulated over all searches since construction or the last
+  /// call to `reset_stats`.
+  pub fn stats(&self) -> SearchStats {
+    self.stats.clone()
+  }
+
+  pub fn reset_stats(&mut self) {
+    self.stats = SearchStats::default();
+  }
+}
+
+impl<G> Default for NegamaxSolver<G> {
+  fn default() -> Self {
+    Self::new()
+  }
+}
+
+impl<G: Game> Solver for NegamaxSolver<G>
+where
+  G::Move: Ord,
+{
+  type Game = G;
+
+  fn best_move(&mut self, game: &G, depth: u32) -> (Score, Option<G::Move>) {
+    if depth == 0 {
+      return (Score::NO_INFO, None);
+    }
+    self.stats.nodes_expanded += 1;
+    self.stats.depth = self.stats.depth.max(depth);
+
+    game
+      .each_move()
+      .collect_vec()
+      .into_iter()
+      .map(|m| (move_score(self, game, m, depth), m))
+      .fold(None, |acc, (score, m)| match acc {
+        None => Some((score, score, m)),
+        Some((total, best_score, best_move)) => {
+          let total = total.accumulate(score);
+          if score.better(best_score) || (score == best_score && m < best_move) {
+            Some((total, score, m))
+          } else {
+            Some((total, best_score, best_move))
+          }
+        }
+      })
+      .map_or((Score::NO_INFO, None), |(total, _, m)| (total, Some(m)))
+  }
+}
+
+impl<G: Game> CompleteSolver for NegamaxSolver<G> where G::Move: Ord {}